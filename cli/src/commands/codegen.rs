@@ -4,7 +4,10 @@
 
 use crate::utils::FileOrUrl;
 use clap::Parser as ClapParser;
+use codec::Decode;
 use color_eyre::eyre;
+use quote::ToTokens;
+use std::{fs, path::PathBuf};
 use subxt_codegen::{DerivesRegistry, TypeSubstitutes, TypeSubstitutionError};
 
 /// Generate runtime API client code from metadata.
@@ -61,6 +64,98 @@ pub struct Opts {
     /// Defaults to `false` (default substitutions are provided).
     #[clap(long)]
     no_default_substitutions: bool,
+    /// Emit a test module which checks that generated calls round-trip through
+    /// SCALE encoding and decoding.
+    ///
+    /// Defaults to `false` (no tests are generated).
+    #[clap(long)]
+    emit_tests: bool,
+    /// Mark calls whose documentation contains a `# Deprecated` section as `#[deprecated]`.
+    ///
+    /// Defaults to `false`.
+    #[clap(long)]
+    deprecations_from_docs: bool,
+    /// Also emit an async `<call>_submit` function for each call, which signs, submits
+    /// and waits for the call to be finalized.
+    ///
+    /// Defaults to `false`.
+    #[clap(long)]
+    emit_submit_fns: bool,
+    /// Also emit a `call_info()` function on each call struct, describing its arguments.
+    ///
+    /// Defaults to `false`.
+    #[clap(long)]
+    emit_call_info: bool,
+    /// Pair calls with the event they emit on success where this is clear from their names,
+    /// so that the event can be waited for with `sign_submit_and_watch_for`.
+    ///
+    /// Defaults to `false`.
+    #[clap(long)]
+    emit_success_events: bool,
+    /// Have call functions take non-numeric arguments as `impl Into<..>`, so that references
+    /// and other convertible values can be passed without cloning them first.
+    ///
+    /// Defaults to `false`.
+    #[clap(long)]
+    impl_into_args: bool,
+    /// Add an `Unknown` variant to the outer `Call` enum, which `decode_runtime_call` hands
+    /// back for calls to pallets that aren't in the metadata rather than failing.
+    ///
+    /// Defaults to `false`.
+    #[clap(long)]
+    unknown_call_variant: bool,
+    /// Generate newtypes for integer call arguments that the metadata gives a type name to
+    /// (like `BalanceOf<T>`), and take those arguments as the newtypes.
+    ///
+    /// Defaults to `false`.
+    #[clap(long)]
+    type_alias_newtypes: bool,
+    /// Emit a compile time assertion per call that the number of fields it's given matches
+    /// the number of fields in the metadata.
+    ///
+    /// Defaults to `false`.
+    #[clap(long)]
+    emit_asserts: bool,
+    /// Emit a `<call>_with_decimals` function per call taking balances, which takes them as
+    /// decimal amounts (like `"1.5"`) and converts them into base units.
+    ///
+    /// Defaults to `false`.
+    #[clap(long)]
+    decimal_amount_fns: bool,
+    /// Lints to allow at the top of the generated module, replacing the defaults.
+    ///
+    /// Example `--allow-lint dead_code --allow-lint clippy::all`.
+    #[clap(long = "allow-lint")]
+    allow_lints: Vec<String>,
+    /// The name of the top level module that everything is generated into. Generate code for
+    /// each chain with a different module name to use the APIs of several chains in one crate.
+    ///
+    /// Defaults to `api`.
+    #[clap(long, default_value = "api")]
+    mod_name: String,
+    /// The name of the module that runtime types are generated into.
+    ///
+    /// Defaults to `runtime_types`.
+    #[clap(long)]
+    types_mod_name: Option<String>,
+    /// Only generate code for the given pallets, along with the types they reference.
+    ///
+    /// Example `--pallets Balances,Staking`.
+    #[clap(long, use_value_delimiter = true, value_parser)]
+    pallets: Option<Vec<String>>,
+    /// Also make a pallet available under another name (for instance its name before it
+    /// was renamed), given as `alias=pallet` where `pallet` is its name in the metadata.
+    ///
+    /// Example `--pallet-alias Democracy=ConvictionVoting`.
+    #[clap(long = "pallet-alias", value_parser = pallet_alias_parser)]
+    pallet_aliases: Vec<(String, String)>,
+    /// Write one file per pallet (and per other top level module) into `--out-dir`,
+    /// along with a `mod.rs` which declares them, instead of printing a single file.
+    #[clap(long, requires = "out_dir")]
+    split_by_pallet: bool,
+    /// The directory to write the generated files to when using `--split-by-pallet`.
+    #[clap(long, requires = "split_by_pallet")]
+    out_dir: Option<PathBuf>,
 }
 
 fn derive_for_type_parser(src: &str) -> Result<(String, String), String> {
@@ -87,22 +182,18 @@ fn substitute_type_parser(src: &str) -> Result<(String, String), String> {
     Ok((from.to_string(), to.to_string()))
 }
 
+fn pallet_alias_parser(src: &str) -> Result<(String, String), String> {
+    let (alias, pallet) = src
+        .split_once('=')
+        .ok_or_else(|| String::from("Invalid pattern for `pallet-alias`. It should be `alias=pallet`, like `Democracy=ConvictionVoting`"))?;
+
+    Ok((alias.to_string(), pallet.to_string()))
+}
+
 pub async fn run(opts: Opts) -> color_eyre::Result<()> {
     let bytes = opts.file_or_url.fetch().await?;
 
-    codegen(
-        &bytes,
-        opts.derives,
-        opts.attributes,
-        opts.derives_for_type,
-        opts.attributes_for_type,
-        opts.substitute_types,
-        opts.crate_path,
-        opts.no_docs,
-        opts.runtime_types_only,
-        opts.no_default_derives,
-        opts.no_default_substitutions,
-    )?;
+    codegen(&bytes, opts)?;
     Ok(())
 }
 
@@ -115,60 +206,52 @@ impl syn::parse::Parse for OuterAttribute {
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-fn codegen(
-    metadata_bytes: &[u8],
-    raw_derives: Vec<String>,
-    raw_attributes: Vec<String>,
-    derives_for_type: Vec<(String, String)>,
-    attributes_for_type: Vec<(String, String)>,
-    substitute_types: Vec<(String, String)>,
-    crate_path: Option<String>,
-    no_docs: bool,
-    runtime_types_only: bool,
-    no_default_derives: bool,
-    no_default_substitutions: bool,
-) -> color_eyre::Result<()> {
+fn codegen(metadata_bytes: &[u8], opts: Opts) -> color_eyre::Result<()> {
+    let mod_name = &opts.mod_name;
+    let mod_ident: syn::Ident = syn::parse_str(mod_name)
+        .map_err(|e| eyre::eyre!("Invalid module name '{mod_name}': {e}"))?;
     let item_mod = syn::parse_quote!(
-        pub mod api {}
+        pub mod #mod_ident {}
     );
 
-    let universal_derives = raw_derives
+    let universal_derives = opts
+        .derives
         .iter()
         .map(|raw| syn::parse_str(raw))
         .collect::<Result<Vec<_>, _>>()?;
-    let universal_attributes = raw_attributes
+    let universal_attributes = opts
+        .attributes
         .iter()
         .map(|raw| syn::parse_str(raw))
         .map(|attr: syn::Result<OuterAttribute>| attr.map(|attr| attr.0))
         .collect::<Result<Vec<_>, _>>()?;
 
-    let crate_path = crate_path.map(Into::into).unwrap_or_default();
-    let mut derives = if no_default_derives {
+    let crate_path = opts.crate_path.map(Into::into).unwrap_or_default();
+    let mut derives = if opts.no_default_derives {
         DerivesRegistry::new()
     } else {
         DerivesRegistry::with_default_derives(&crate_path)
     };
     derives.extend_for_all(universal_derives, universal_attributes);
 
-    for (ty, derive) in derives_for_type {
+    for (ty, derive) in opts.derives_for_type {
         let ty = syn::parse_str(&ty)?;
         let derive = syn::parse_str(&derive)?;
         derives.extend_for_type(ty, std::iter::once(derive), vec![]);
     }
-    for (ty, attr) in attributes_for_type {
+    for (ty, attr) in opts.attributes_for_type {
         let ty = syn::parse_str(&ty)?;
         let attribute: OuterAttribute = syn::parse_str(&attr)?;
         derives.extend_for_type(ty, vec![], std::iter::once(attribute.0));
     }
 
-    let mut type_substitutes = if no_default_substitutions {
+    let mut type_substitutes = if opts.no_default_substitutions {
         TypeSubstitutes::new()
     } else {
         TypeSubstitutes::with_default_substitutes(&crate_path)
     };
 
-    for (from_str, to_str) in substitute_types {
+    for (from_str, to_str) in opts.substitute_types {
         let from: syn::Path = syn::parse_str(&from_str)?;
         let to: syn::Path = syn::parse_str(&to_str)?;
         let to = to.try_into().map_err(|e: TypeSubstitutionError| {
@@ -181,18 +264,61 @@ fn codegen(
             })?;
     }
 
-    let should_gen_docs = !no_docs;
-    let runtime_api = subxt_codegen::generate_runtime_api_from_bytes(
-        item_mod,
-        metadata_bytes,
-        derives,
-        type_substitutes,
-        crate_path,
-        should_gen_docs,
-        runtime_types_only,
-    );
+    let should_gen_docs = !opts.no_docs;
+    let metadata_bytes = subxt_codegen::utils::decompress_metadata_bytes(metadata_bytes)
+        .map_err(|e| eyre::eyre!("{e}"))?;
+    let mut metadata = subxt_metadata::Metadata::decode(&mut &*metadata_bytes)?;
+    if let Some(pallets) = &opts.pallets {
+        retain_pallets(&mut metadata, pallets)?;
+    }
+
+    let allow_lints = opts
+        .allow_lints
+        .iter()
+        .map(|raw| syn::parse_str(raw))
+        .collect::<Result<Vec<syn::Path>, _>>()?;
+    let mut generator = subxt_codegen::RuntimeGenerator::new(metadata)
+        .emit_tests(opts.emit_tests)
+        .deprecations_from_docs(opts.deprecations_from_docs)
+        .emit_submit_fns(opts.emit_submit_fns)
+        .emit_call_info(opts.emit_call_info)
+        .emit_success_events(opts.emit_success_events)
+        .impl_into_args(opts.impl_into_args)
+        .emit_unknown_call_variant(opts.unknown_call_variant)
+        .emit_type_alias_newtypes(opts.type_alias_newtypes)
+        .emit_asserts(opts.emit_asserts)
+        .emit_decimal_amount_fns(opts.decimal_amount_fns)
+        .pallet_aliases(opts.pallet_aliases);
+    if !allow_lints.is_empty() {
+        generator = generator.allow_lints(allow_lints);
+    }
+    if let Some(types_mod_name) = opts.types_mod_name {
+        generator = generator.types_mod_name(types_mod_name);
+    }
+
+    let runtime_api = if opts.runtime_types_only {
+        generator.generate_runtime_types(
+            item_mod,
+            derives,
+            type_substitutes,
+            crate_path,
+            should_gen_docs,
+        )
+    } else {
+        generator.generate_runtime(
+            item_mod,
+            derives,
+            type_substitutes,
+            crate_path,
+            should_gen_docs,
+        )
+    };
+    let split_out_dir = opts.out_dir.filter(|_| opts.split_by_pallet);
     match runtime_api {
-        Ok(runtime_api) => println!("{runtime_api}"),
+        Ok(runtime_api) => match split_out_dir {
+            Some(out_dir) => write_split_modules(runtime_api, &out_dir)?,
+            None => println!("{runtime_api}"),
+        },
         Err(e) => {
             // Print the error directly to avoid implementing `Send + Sync` on `CodegenError`.
             use color_eyre::owo_colors::OwoColorize;
@@ -202,3 +328,121 @@ fn codegen(
 
     Ok(())
 }
+
+/// Strip every pallet not named in `pallets` from the metadata, matching names regardless of
+/// their case. Returns an error listing any names which don't match a pallet.
+fn retain_pallets(
+    metadata: &mut subxt_metadata::Metadata,
+    pallets: &[String],
+) -> color_eyre::Result<()> {
+    let mut keep = Vec::with_capacity(pallets.len());
+    let mut unknown = Vec::new();
+    for requested in pallets {
+        match metadata
+            .pallets()
+            .find(|pallet| pallet.name().eq_ignore_ascii_case(requested))
+        {
+            Some(pallet) => keep.push(pallet.name().to_owned()),
+            None => unknown.push(requested.as_str()),
+        }
+    }
+    if !unknown.is_empty() {
+        eyre::bail!(
+            "No pallets found with the names given to `--pallets`: {}",
+            unknown.join(", ")
+        );
+    }
+
+    metadata.retain(|name| keep.iter().any(|p| p == name), |_| true);
+    Ok(())
+}
+
+/// Write the generated API module to `out_dir` as a `mod.rs`, moving each public top level
+/// module with inline content (ie each pallet, `runtime_types` and so on) into its own file.
+///
+/// The resulting `mod.rs` is the generated API module itself, so its attributes become inner
+/// attributes. Paths such as `super::root_mod` keep resolving, because the module hierarchy
+/// is unchanged; only where each module lives on disk differs.
+fn write_split_modules(
+    runtime_api: proc_macro2::TokenStream,
+    out_dir: &std::path::Path,
+) -> color_eyre::Result<()> {
+    let api_mod: syn::ItemMod = syn::parse2(runtime_api)?;
+    let Some((_, items)) = api_mod.content else {
+        eyre::bail!("Generated API module has no content");
+    };
+
+    fs::create_dir_all(out_dir)?;
+
+    let mut mod_rs = proc_macro2::TokenStream::new();
+    for mut attr in api_mod.attrs {
+        attr.style = syn::AttrStyle::Inner(Default::default());
+        attr.to_tokens(&mut mod_rs);
+    }
+
+    for item in items {
+        match item {
+            syn::Item::Mod(
+                item_mod @ syn::ItemMod {
+                    vis: syn::Visibility::Public(_),
+                    content: Some(_),
+                    ..
+                },
+            ) => {
+                let file_name = format!("{}.rs", item_mod.ident);
+                let (_, inner_items) = item_mod.content.expect("content checked above; qed");
+                let mut file = proc_macro2::TokenStream::new();
+                for inner_item in inner_items {
+                    inner_item.to_tokens(&mut file);
+                }
+                fs::write(out_dir.join(file_name), file.to_string())?;
+
+                let syn::ItemMod {
+                    attrs, vis, ident, ..
+                } = item_mod;
+                quote::quote!(#( #attrs )* #vis mod #ident;).to_tokens(&mut mod_rs);
+            }
+            item => item.to_tokens(&mut mod_rs),
+        }
+    }
+
+    fs::write(out_dir.join("mod.rs"), mod_rs.to_string())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load_metadata() -> subxt_metadata::Metadata {
+        let bytes = fs::read("../artifacts/polkadot_metadata_small.scale").unwrap();
+        subxt_metadata::Metadata::decode(&mut &*bytes).unwrap()
+    }
+
+    #[test]
+    fn pallets_are_retained_regardless_of_case() {
+        let mut metadata = load_metadata();
+        let pallets = vec!["balances".to_owned(), "SYSTEM".to_owned()];
+        retain_pallets(&mut metadata, &pallets).unwrap();
+
+        let mut names: Vec<_> = metadata.pallets().map(|p| p.name()).collect();
+        names.sort();
+        assert_eq!(names, vec!["Balances", "System"]);
+    }
+
+    #[test]
+    fn unknown_pallets_are_reported() {
+        let mut metadata = load_metadata();
+        let pallets = vec![
+            "Balances".to_owned(),
+            "System".to_owned(),
+            "NotAPallet".to_owned(),
+        ];
+        let err = retain_pallets(&mut metadata, &pallets).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "No pallets found with the names given to `--pallets`: NotAPallet"
+        );
+    }
+}
@@ -21,9 +21,9 @@ use crate::utils::with_indent;
 
 #[derive(Debug, Clone, Args)]
 pub struct CallsSubcommand {
-    call: Option<String>,
+    pub(crate) call: Option<String>,
     #[clap(required = false)]
-    trailing_args: Vec<String>,
+    pub(crate) trailing_args: Vec<String>,
 }
 
 pub(crate) fn explore_calls(
@@ -9,7 +9,7 @@ use crate::utils::{print_docs_with_indent, with_indent};
 
 #[derive(Debug, Clone, Args)]
 pub struct ConstantsSubcommand {
-    constant: Option<String>,
+    pub(crate) constant: Option<String>,
 }
 
 pub(crate) fn explore_constants(
@@ -0,0 +1,74 @@
+use clap::Args;
+use color_eyre::eyre::eyre;
+use scale_info::{form::PortableForm, Variant};
+use std::fmt::Write;
+use std::write;
+use subxt::metadata::{types::PalletMetadata, Metadata};
+
+use crate::utils::type_description::print_type_description;
+use crate::utils::{print_docs_with_indent, with_indent};
+
+#[derive(Debug, Clone, Args)]
+pub struct EventsSubcommand {
+    pub(crate) event: Option<String>,
+}
+
+pub(crate) fn explore_events(
+    command: EventsSubcommand,
+    metadata: &Metadata,
+    pallet_metadata: PalletMetadata,
+) -> color_eyre::Result<()> {
+    let pallet_name = pallet_metadata.name();
+    let events = pallet_metadata.event_variants().unwrap_or_default();
+
+    // if no event specified, show user the events to choose from:
+    let Some(event_name) = command.event else {
+        let available_events = print_available_events(events, pallet_name);
+        println!("Usage:\n    subxt explore {pallet_name} events <EVENT>\n        explore a specific event within this pallet\n\n{available_events}", );
+        return Ok(());
+    };
+
+    // if specified event is wrong, show user the events to choose from (but this time as an error):
+    let Some(event) = events
+        .iter()
+        .find(|event| event.name.to_lowercase() == event_name.to_lowercase())
+    else {
+        let available_events = print_available_events(events, pallet_name);
+        let description = format!("Usage:\n    subxt explore {pallet_name} events <EVENT>\n        explore a specific event within this pallet\n\n{available_events}", );
+        return Err(eyre!(
+            "event \"{event_name}\" not found in \"{pallet_name}\" pallet!\n\n{description}"
+        ));
+    };
+
+    // docs
+    let mut output = String::new();
+    let doc_string = print_docs_with_indent(&event.docs, 4);
+    if !doc_string.is_empty() {
+        write!(output, "Description:\n{doc_string}")?;
+    }
+
+    // shape
+    let mut type_description = print_type_description(&event.fields, metadata.types())?;
+    type_description = with_indent(type_description, 4);
+    write!(
+        output,
+        "\n\nThe event has the following shape:\n{type_description}"
+    )?;
+
+    println!("{output}");
+    Ok(())
+}
+
+fn print_available_events(events: &[Variant<PortableForm>], pallet_name: &str) -> String {
+    if events.is_empty() {
+        return format!("No <EVENT>'s available in the \"{pallet_name}\" pallet.");
+    }
+    let mut output = format!("Available <EVENT>'s in the \"{pallet_name}\" pallet:");
+    let mut strings: Vec<_> = events.iter().map(|e| &e.name).collect();
+    strings.sort();
+    for event in strings {
+        output.push_str("\n    ");
+        output.push_str(event);
+    }
+    output
+}
@@ -0,0 +1,178 @@
+//! Machine readable (JSON) descriptions of the metadata, for `subxt explore --json`.
+
+use color_eyre::eyre::eyre;
+use scale_info::{form::PortableForm, Field, PortableRegistry, Variant};
+use serde_json::{json, Value};
+use subxt::metadata::{
+    types::{
+        ConstantMetadata, PalletMetadata, StorageEntryMetadata, StorageEntryModifier,
+        StorageEntryType,
+    },
+    Metadata,
+};
+
+use crate::utils::type_description::TypeDescription;
+
+/// The name and index of each pallet.
+pub fn pallets(metadata: &Metadata) -> Value {
+    let pallets: Vec<_> = metadata
+        .pallets()
+        .map(|p| json!({ "name": p.name(), "index": p.index() }))
+        .collect();
+    json!({ "pallets": pallets })
+}
+
+/// Everything we know about a pallet.
+pub fn pallet(metadata: &Metadata, pallet: PalletMetadata) -> color_eyre::Result<Value> {
+    Ok(json!({
+        "name": pallet.name(),
+        "index": pallet.index(),
+        "docs": pallet.docs(),
+        "calls": calls(metadata, pallet, None)?,
+        "events": events(metadata, pallet, None)?,
+        "constants": constants(metadata, pallet, None)?,
+        "storage": storage(metadata, pallet, None)?,
+    }))
+}
+
+/// Describe the calls in a pallet, or just the call with the given name.
+pub fn calls(
+    metadata: &Metadata,
+    pallet: PalletMetadata,
+    name: Option<&str>,
+) -> color_eyre::Result<Value> {
+    let calls = pallet.call_variants().unwrap_or_default();
+    variants(calls, metadata.types(), name, "call", pallet.name())
+}
+
+/// Describe the events in a pallet, or just the event with the given name.
+pub fn events(
+    metadata: &Metadata,
+    pallet: PalletMetadata,
+    name: Option<&str>,
+) -> color_eyre::Result<Value> {
+    let events = pallet.event_variants().unwrap_or_default();
+    variants(events, metadata.types(), name, "event", pallet.name())
+}
+
+/// Describe the constants in a pallet, or just the constant with the given name.
+pub fn constants(
+    metadata: &Metadata,
+    pallet: PalletMetadata,
+    name: Option<&str>,
+) -> color_eyre::Result<Value> {
+    let describe = |constant: &ConstantMetadata| -> color_eyre::Result<Value> {
+        let value = scale_value::scale::decode_as_type(
+            &mut constant.value(),
+            constant.ty(),
+            metadata.types(),
+        )?;
+        Ok(json!({
+            "name": constant.name(),
+            "docs": constant.docs(),
+            "type": constant.ty().type_description(metadata.types())?,
+            "value": scale_value::stringify::to_string(&value),
+        }))
+    };
+
+    match name {
+        None => pallet.constants().map(describe).collect(),
+        Some(name) => {
+            let constant = pallet
+                .constants()
+                .find(|c| c.name().eq_ignore_ascii_case(name))
+                .ok_or_else(|| not_found("constant", name, pallet.name()))?;
+            describe(constant)
+        }
+    }
+}
+
+/// Describe the storage entries in a pallet, or just the entry with the given name.
+pub fn storage(
+    metadata: &Metadata,
+    pallet: PalletMetadata,
+    name: Option<&str>,
+) -> color_eyre::Result<Value> {
+    let describe = |entry: &StorageEntryMetadata| -> color_eyre::Result<Value> {
+        let (key_ty, value_ty) = match entry.entry_type() {
+            StorageEntryType::Plain(value_ty) => (None, *value_ty),
+            StorageEntryType::Map {
+                key_ty, value_ty, ..
+            } => (Some(*key_ty), *value_ty),
+        };
+        let key = key_ty
+            .map(|ty| ty.type_description(metadata.types()))
+            .transpose()?;
+        let modifier = match entry.modifier() {
+            StorageEntryModifier::Optional => "optional",
+            StorageEntryModifier::Default => "default",
+        };
+        Ok(json!({
+            "name": entry.name(),
+            "docs": entry.docs(),
+            "modifier": modifier,
+            "key": key,
+            "value": value_ty.type_description(metadata.types())?,
+        }))
+    };
+
+    let entries: Vec<_> = pallet
+        .storage()
+        .map(|storage| storage.entries().collect())
+        .unwrap_or_default();
+    match name {
+        None => entries.into_iter().map(describe).collect(),
+        Some(name) => {
+            let entry = entries
+                .into_iter()
+                .find(|e| e.name().eq_ignore_ascii_case(name))
+                .ok_or_else(|| not_found("storage entry", name, pallet.name()))?;
+            describe(entry)
+        }
+    }
+}
+
+fn variants(
+    variants: &[Variant<PortableForm>],
+    types: &PortableRegistry,
+    name: Option<&str>,
+    kind: &str,
+    pallet_name: &str,
+) -> color_eyre::Result<Value> {
+    let describe = |variant: &Variant<PortableForm>| -> color_eyre::Result<Value> {
+        let fields = variant
+            .fields
+            .iter()
+            .map(|field| field_json(field, types))
+            .collect::<color_eyre::Result<Vec<_>>>()?;
+        Ok(json!({
+            "name": variant.name,
+            "index": variant.index,
+            "docs": variant.docs,
+            "fields": fields,
+        }))
+    };
+
+    match name {
+        None => variants.iter().map(describe).collect(),
+        Some(name) => {
+            let variant = variants
+                .iter()
+                .find(|v| v.name.eq_ignore_ascii_case(name))
+                .ok_or_else(|| not_found(kind, name, pallet_name))?;
+            describe(variant)
+        }
+    }
+}
+
+fn field_json(field: &Field<PortableForm>, types: &PortableRegistry) -> color_eyre::Result<Value> {
+    Ok(json!({
+        "name": field.name,
+        "type_name": field.type_name,
+        "type": field.ty.id.type_description(types)?,
+    }))
+}
+
+fn not_found(kind: &str, name: &str, pallet_name: &str) -> color_eyre::Report {
+    eyre!("{kind} \"{name}\" not found in \"{pallet_name}\" pallet!")
+}
@@ -10,12 +10,15 @@ use color_eyre::eyre::eyre;
 
 use crate::commands::explore::calls::{explore_calls, CallsSubcommand};
 use crate::commands::explore::constants::{explore_constants, ConstantsSubcommand};
+use crate::commands::explore::events::{explore_events, EventsSubcommand};
 use crate::commands::explore::storage::{explore_storage, StorageSubcommand};
 
-use subxt::Metadata;
+use subxt::{metadata::types::PalletMetadata, Metadata};
 
 mod calls;
 mod constants;
+mod events;
+mod json;
 mod storage;
 
 /// Explore pallets, calls, call parameters, storage entries and constants. Also allows for creating (unsigned) extrinsics.
@@ -54,6 +57,13 @@ mod storage;
 /// ```
 /// subxt explore Balances constants
 /// ```
+/// ## Events
+///
+/// Show the events in a pallet, or the shape of a specific event:
+/// ```
+/// subxt explore Balances events
+/// subxt explore Balances events Transfer
+/// ```
 /// ## Storage
 ///
 /// Show the storage entries in a pallet
@@ -64,11 +74,21 @@ mod storage;
 /// ```
 /// subxt explore Alliance storage Announcements [KEY_SCALE_VALUE]
 /// ```
+/// ## JSON
+///
+/// Describe the metadata as JSON instead, for instance everything about a pallet:
+/// ```
+/// subxt explore --json Balances
+/// ```
 ///
 #[derive(Debug, ClapParser)]
 pub struct Opts {
     #[command(flatten)]
     file_or_url: FileOrUrl,
+    /// Print a description of the pallets, calls, events, constants or storage entries asked
+    /// for as JSON. Values are not encoded or fetched from storage in this mode.
+    #[clap(long)]
+    json: bool,
     pallet: Option<String>,
     #[command(subcommand)]
     pallet_subcommand: Option<PalletSubcommand>,
@@ -78,6 +98,7 @@ pub struct Opts {
 pub enum PalletSubcommand {
     Calls(CallsSubcommand),
     Constants(ConstantsSubcommand),
+    Events(EventsSubcommand),
     Storage(StorageSubcommand),
 }
 
@@ -87,6 +108,16 @@ pub async fn run(opts: Opts) -> color_eyre::Result<()> {
     let bytes = opts.file_or_url.fetch().await?;
     let metadata = Metadata::decode(&mut &bytes[..])?;
 
+    if opts.json {
+        let output = explore_json(
+            &metadata,
+            opts.pallet.as_deref(),
+            opts.pallet_subcommand.as_ref(),
+        )?;
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
     // if no pallet specified, show user the pallets to choose from:
     let Some(pallet_name) = opts.pallet else {
         let available_pallets = print_available_pallets(&metadata);
@@ -109,6 +140,7 @@ pub async fn run(opts: Opts) -> color_eyre::Result<()> {
         write!(output, "Usage:")?;
         write!(output, "\n    subxt explore {pallet_name} calls\n        explore the calls that can be made into this pallet")?;
         write!(output, "\n    subxt explore {pallet_name} constants\n        explore the constants held in this pallet")?;
+        write!(output, "\n    subxt explore {pallet_name} events\n        explore the events that this pallet emits")?;
         write!(output, "\n    subxt explore {pallet_name} storage\n        explore the storage values held in this pallet")?;
         println!("{output}");
         return Ok(());
@@ -119,6 +151,7 @@ pub async fn run(opts: Opts) -> color_eyre::Result<()> {
         PalletSubcommand::Constants(command) => {
             explore_constants(command, &metadata, pallet_metadata)
         }
+        PalletSubcommand::Events(command) => explore_events(command, &metadata, pallet_metadata),
         PalletSubcommand::Storage(command) => {
             // if the metadata came from some url, we use that same url to make storage calls against.
             let node_url = opts.file_or_url.url.map(|url| url.to_string());
@@ -127,6 +160,43 @@ pub async fn run(opts: Opts) -> color_eyre::Result<()> {
     }
 }
 
+/// Describe whatever was asked for as JSON.
+fn explore_json(
+    metadata: &Metadata,
+    pallet_name: Option<&str>,
+    pallet_subcommand: Option<&PalletSubcommand>,
+) -> color_eyre::Result<serde_json::Value> {
+    let Some(pallet_name) = pallet_name else {
+        return Ok(json::pallets(metadata));
+    };
+    let pallet_metadata: PalletMetadata = metadata
+        .pallets()
+        .find(|pallet| pallet.name().eq_ignore_ascii_case(pallet_name))
+        .ok_or_else(|| eyre!("pallet \"{pallet_name}\" not found in metadata!"))?;
+
+    match pallet_subcommand {
+        None => json::pallet(metadata, pallet_metadata),
+        Some(PalletSubcommand::Calls(command)) => {
+            if !command.trailing_args.is_empty() {
+                return Err(eyre!("calls can't be constructed when using --json"));
+            }
+            json::calls(metadata, pallet_metadata, command.call.as_deref())
+        }
+        Some(PalletSubcommand::Constants(command)) => {
+            json::constants(metadata, pallet_metadata, command.constant.as_deref())
+        }
+        Some(PalletSubcommand::Events(command)) => {
+            json::events(metadata, pallet_metadata, command.event.as_deref())
+        }
+        Some(PalletSubcommand::Storage(command)) => {
+            if !command.trailing_args.is_empty() {
+                return Err(eyre!("storage values can't be fetched when using --json"));
+            }
+            json::storage(metadata, pallet_metadata, command.storage_entry.as_deref())
+        }
+    }
+}
+
 fn print_available_pallets(metadata: &Metadata) -> String {
     if metadata.pallets().len() == 0 {
         "There are no <PALLET> values available.".to_string()
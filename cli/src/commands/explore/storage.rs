@@ -18,9 +18,9 @@ use crate::utils::{print_docs_with_indent, with_indent};
 
 #[derive(Debug, Clone, Args)]
 pub struct StorageSubcommand {
-    storage_entry: Option<String>,
+    pub(crate) storage_entry: Option<String>,
     #[clap(required = false)]
-    trailing_args: Vec<String>,
+    pub(crate) trailing_args: Vec<String>,
 }
 
 pub(crate) async fn explore_storage(
@@ -2,15 +2,19 @@
 // This file is dual-licensed as Apache-2.0 or GPL-3.0.
 // see LICENSE for license details.
 
-use super::CodegenError;
+use super::{
+    type_aliases::{self, TypeAliases},
+    CallGenOptions, CodegenError,
+};
 use crate::{
-    types::{CompositeDefFields, TypeGenerator},
+    types::{CompositeDefFieldType, CompositeDefFields, CompositeDefKind, TypeGenerator},
     CratePath,
 };
 use heck::{ToSnakeCase as _, ToUpperCamelCase as _};
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{format_ident, quote};
-use subxt_metadata::PalletMetadata;
+use quote::{format_ident, quote, ToTokens};
+use scale_info::{form::PortableForm, TypeDef, TypeDefVariant};
+use subxt_metadata::{Metadata, PalletMetadata};
 
 /// Generate calls from the provided pallet's metadata. Each call returns a `StaticTxPayload`
 /// that can be passed to the subxt client to submit/sign/encode.
@@ -21,18 +25,40 @@ use subxt_metadata::PalletMetadata;
 /// - `type_gen` - The type generator containing all types defined by metadata.
 /// - `pallet` - Pallet metadata from which the calls are generated.
 /// - `types_mod_ident` - The ident of the base module that we can use to access the generated types from.
+/// - `options` - Which optional extras to generate alongside the calls.
+/// - `type_aliases` - The newtypes to take integer call arguments as.
+/// - `runtime_call_ty` - The type ID of the runtime's outer call enum, if it could be found.
+#[allow(clippy::too_many_arguments)]
 pub fn generate_calls(
     type_gen: &TypeGenerator,
     pallet: &PalletMetadata,
     types_mod_ident: &syn::Ident,
     crate_path: &CratePath,
     should_gen_docs: bool,
+    options: &CallGenOptions,
+    type_aliases: &TypeAliases,
+    runtime_call_ty: Option<u32>,
 ) -> Result<TokenStream2, CodegenError> {
     // Early return if the pallet has no calls.
     let Some(call_ty) = pallet.call_ty_id() else {
         return Ok(quote!());
     };
 
+    // The outer call enum, and the variant of it which wraps this pallet's calls.
+    let runtime_call = runtime_call_ty.and_then(|id| {
+        let TypeDef::Variant(def) = type_gen.resolve_type(id).type_def else {
+            return None;
+        };
+        let variant = def
+            .variants
+            .iter()
+            .find(|variant| variant.fields.first().map(|field| field.ty.id) == Some(call_ty))?;
+        Some((
+            type_gen.resolve_type_path(id),
+            format_ident!("{}", variant.name),
+        ))
+    });
+
     let mut struct_defs = super::generate_structs_from_variants(
         type_gen,
         call_ty,
@@ -41,20 +67,55 @@ pub fn generate_calls(
         crate_path,
         should_gen_docs,
     )?;
+    let event_names: Vec<&str> = pallet
+        .event_variants()
+        .map(|variants| variants.iter().map(|v| v.name.as_str()).collect())
+        .unwrap_or_default();
     let (call_structs, call_fns): (Vec<_>, Vec<_>) = struct_defs
         .iter_mut()
         .map(|(variant_name, struct_def)| {
+            let call_arg_names: Vec<_> = match struct_def.fields {
+                CompositeDefFields::Named(ref named_fields) => {
+                    named_fields.iter().map(|(name, _)| name.clone()).collect()
+                }
+                _ => Vec::new(),
+            };
             let (call_fn_args, call_args): (Vec<_>, Vec<_>) = match struct_def.fields {
                 CompositeDefFields::Named(ref named_fields) => named_fields
                     .iter()
                     .map(|(name, field)| {
+                        // Integers named in the metadata are unwrapped from their newtype.
+                        if let Some(newtype) = type_aliases.newtype_for(field) {
+                            let fn_arg = quote!( #name: root_mod::type_aliases::#newtype );
+                            return (fn_arg, quote!( #name: #name.0 ));
+                        }
+
+                        // Optional boxed values are taken unboxed, and boxed if given.
+                        if let Some(inner) = field.boxed_option_type() {
+                            let fn_arg = quote!( #name: ::core::option::Option<#inner> );
+                            return (fn_arg, quote!( #name: #name.map(::std::boxed::Box::new) ));
+                        }
+
                         let fn_arg_type = &field.type_path;
+                        let impl_into = options.impl_into_args && fn_arg_type.can_be_impl_into();
+                        let value = if impl_into {
+                            quote! { #name.into() }
+                        } else {
+                            quote! { #name }
+                        };
                         let call_arg = if field.is_boxed() {
-                            quote! { #name: ::std::boxed::Box::new(#name) }
+                            quote! { #name: ::std::boxed::Box::new(#value) }
+                        } else if impl_into {
+                            quote! { #name: #value }
                         } else {
                             quote! { #name }
                         };
-                        (quote!( #name: #fn_arg_type ), call_arg)
+                        let fn_arg = if impl_into {
+                            quote!( #name: impl ::core::convert::Into<#fn_arg_type> )
+                        } else {
+                            quote!( #name: #fn_arg_type )
+                        };
+                        (fn_arg, call_arg)
                     })
                     .unzip(),
                 CompositeDefFields::NoFields => Default::default(),
@@ -77,6 +138,113 @@ pub fn generate_calls(
             // draining the documentation of inner call structures.
             let docs = should_gen_docs.then_some(struct_def.docs.take()).flatten();
 
+            // Each call struct can be converted into the corresponding variant of the
+            // pallet `Call` enum, which shares the same fields.
+            let variant_ident = format_ident!("{}", variant_name);
+            let into_call_enum = quote! {
+                impl ::core::convert::From<#struct_name> for super::super::Call {
+                    fn from(call: #struct_name) -> Self {
+                        super::super::Call::#variant_ident { #( #call_arg_names: call.#call_arg_names, )* }
+                    }
+                }
+            };
+
+            // Calls can also be converted into the outer call enum, so that they can be handed
+            // to calls like `Utility::batch` which take a `Vec` of them.
+            let into_runtime_call = runtime_call.as_ref().map(|(runtime_call, variant)| {
+                quote! {
+                    impl ::core::convert::From<#struct_name> for #runtime_call {
+                        fn from(call: #struct_name) -> Self {
+                            #runtime_call::#variant(call.into())
+                        }
+                    }
+
+                    impl ::core::convert::From<#crate_path::tx::Payload<#struct_name>> for #runtime_call {
+                        fn from(payload: #crate_path::tx::Payload<#struct_name>) -> Self {
+                            payload.into_call_data().into()
+                        }
+                    }
+                }
+            });
+
+            // Optionally describe the call arguments, so that they can be inspected at runtime.
+            let call_info = options.call_info.then(|| {
+                let arg_infos = match &struct_def.fields {
+                    CompositeDefFields::Named(named_fields) => named_fields
+                        .iter()
+                        .map(|(name, field)| {
+                            let name = name.to_string();
+                            let type_display = field.type_name.clone().unwrap_or_else(|| {
+                                field.type_path.to_token_stream().to_string().replace(' ', "")
+                            });
+                            quote!( (#name, #type_display) )
+                        })
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                quote! {
+                    impl #struct_name {
+                        /// The names of this call's arguments, alongside a description of their types.
+                        pub fn call_info() -> &'static [(&'static str, &'static str)] {
+                            &[ #( #arg_infos, )* ]
+                        }
+                    }
+                }
+            });
+
+            // Optionally point at the event which this call emits on success.
+            let success_event = options
+                .success_events
+                .then(|| success_event_name(&struct_name.to_string(), &event_names))
+                .flatten()
+                .map(|event_name| {
+                    let event_ident = format_ident!("{}", event_name);
+                    quote! {
+                        impl #crate_path::tx::CallSuccessEvent for #struct_name {
+                            type SuccessEvent = super::super::events::#event_ident;
+                        }
+                    }
+                });
+
+            // Where every field has a default value, so does the call (which is handy for
+            // building partial calls in tests). We are conservative about which fields
+            // count; see `TypePath::has_default()`. Nothing is generated if `Default` is
+            // already derived for the call, since the impls would conflict.
+            let derives_default = matches!(
+                &struct_def.kind,
+                CompositeDefKind::Struct { derives, .. } if derives.has_derive_named("Default")
+            );
+            let all_fields_have_default = struct_def
+                .fields
+                .field_types()
+                .all(|field| field.type_path.has_default());
+            let default_self = default_constructor(&struct_def.fields);
+            let default_impl = (all_fields_have_default && !derives_default).then(|| {
+                quote! {
+                    impl ::core::default::Default for #struct_name {
+                        fn default() -> Self {
+                            #default_self
+                        }
+                    }
+                }
+            });
+
+            // Optionally check that no fields have been lost or duplicated along the way.
+            let field_count_assert = options.asserts.then(|| {
+                let expected = pallet
+                    .call_variant_by_name(call_name)
+                    .map_or(0, |variant| variant.fields.len());
+                let message = format!(
+                    "{pallet_name}::{call_name} should have the same number of fields as in the metadata ({expected})"
+                );
+                quote! {
+                    const _: () = ::core::assert!(
+                        <[&str]>::len(&[ #( ::core::stringify!(#call_args), )* ]) == #expected,
+                        #message
+                    );
+                }
+            });
+
             // The call structure's documentation was stripped above.
             let call_struct = quote! {
                 #struct_def
@@ -85,28 +253,205 @@ pub fn generate_calls(
                     const PALLET: &'static str = #pallet_name;
                     const CALL: &'static str = #call_name;
                 }
+
+                #into_call_enum
+                #into_runtime_call
+                #call_info
+                #default_impl
+                #success_event
+                #field_count_assert
+            };
+
+            // A round-trip test is only possible if we can construct a value of the call.
+            let call_test = (options.tests && all_fields_have_default).then(|| {
+                let test_fn_name = format_ident!("{}_encode_decode_roundtrip", fn_name);
+                quote! {
+                    #[test]
+                    fn #test_fn_name() {
+                        use #crate_path::ext::codec::{Decode, Encode};
+                        let call = <super::types::#struct_name as ::core::default::Default>::default();
+                        let encoded = call.encode();
+                        let decoded = super::types::#struct_name::decode(&mut &*encoded)
+                            .expect("call should decode from its own encoding");
+                        assert_eq!(decoded.encode(), encoded);
+                    }
+                }
+            });
+
+            // V14/V15 metadata has no explicit deprecation flag, so look for the doc convention.
+            let deprecated = options.deprecations
+                .then(|| pallet.call_variant_by_name(call_name))
+                .flatten()
+                .and_then(|variant| deprecation_note(&variant.docs))
+                .map(|note| quote!( #[deprecated(note = #note)] ));
+
+            // The bound of a `BoundedVec` isn't a part of its type, but the type name given in
+            // the metadata tells us what it is, so that we can check arguments against it.
+            let length_bounds: Vec<_> = match &struct_def.fields {
+                CompositeDefFields::Named(named_fields) => named_fields
+                    .iter()
+                    .filter(|(_, field)| field.type_path.is_bounded_vec())
+                    .filter_map(|(name, field)| {
+                        let max = match bounded_vec_max(field.type_name.as_deref()?)? {
+                            MaxLength::Fixed(max) => quote!( #crate_path::tx::MaxLength::Fixed(#max) ),
+                            MaxLength::Constant(constant) => {
+                                quote!( #crate_path::tx::MaxLength::constant(#constant) )
+                            }
+                        };
+                        Some((name, max))
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            };
+            let payload = |call_data: TokenStream2| {
+                quote! {
+                    #crate_path::tx::Payload::new_static(
+                        #pallet_name,
+                        #call_name,
+                        #call_data,
+                        [#(#call_hash,)*]
+                    )
+                }
+            };
+            let body = if length_bounds.is_empty() {
+                payload(quote!( types::#struct_name { #( #call_args, )* } ))
+            } else {
+                let len_idents: Vec<_> = (0..length_bounds.len())
+                    .map(|idx| format_ident!("__subxt_len_{idx}"))
+                    .collect();
+                let lens = length_bounds.iter().zip(&len_idents).map(|((name, _), len)| {
+                    quote!( let #len = __subxt_call.#name.0.len(); )
+                });
+                let bounds = length_bounds.iter().zip(&len_idents).map(|((name, max), len)| {
+                    let arg_name = name.to_string();
+                    let arg_name = arg_name.trim_start_matches("r#");
+                    quote!( .with_length_bound(#arg_name, #len, #max) )
+                });
+                let payload = payload(quote!(__subxt_call));
+                quote! {
+                    let __subxt_call = types::#struct_name { #( #call_args, )* };
+                    #( #lens )*
+                    #payload #( #bounds )*
+                }
             };
 
             let client_fn = quote! {
                 #docs
+                #deprecated
                 pub fn #fn_name(
                     &self,
                     #( #call_fn_args, )*
                 ) -> #crate_path::tx::Payload<types::#struct_name> {
-                    #crate_path::tx::Payload::new_static(
-                        #pallet_name,
-                        #call_name,
-                        types::#struct_name { #( #call_args, )* },
-                        [#(#call_hash,)*]
-                    )
+                    #body
                 }
             };
 
-            Ok((call_struct, client_fn))
+            // A convenience wrapper to sign, submit and wait for the call in one go.
+            let submit_fn = options.submit_fns.then(|| {
+                let submit_fn_name = format_ident!("{}_submit", fn_name);
+                let submit_docs = format!(
+                    "Sign and submit a [`types::{struct_name}`] call, and wait for it to be \
+                     finalized successfully. The account nonce is fetched from the chain \
+                     and default values are used for any other extrinsic params."
+                );
+                quote! {
+                    #[doc = #submit_docs]
+                    pub async fn #submit_fn_name<T, C, Signer>(
+                        &self,
+                        __subxt_client: &C,
+                        __subxt_signer: &Signer,
+                        #( #call_fn_args, )*
+                    ) -> ::core::result::Result<#crate_path::blocks::ExtrinsicEvents<T>, #crate_path::Error>
+                    where
+                        T: #crate_path::Config,
+                        C: #crate_path::client::OnlineClientT<T>,
+                        Signer: #crate_path::tx::Signer<T>,
+                        <T::ExtrinsicParams as #crate_path::config::ExtrinsicParams<T::Index, T::Hash>>::OtherParams: ::core::default::Default,
+                    {
+                        #[allow(deprecated)]
+                        let call = self.#fn_name( #( #call_arg_names, )* );
+                        __subxt_client
+                            .tx()
+                            .sign_and_submit_then_watch_default(&call, __subxt_signer)
+                            .await?
+                            .wait_for_finalized_success()
+                            .await
+                    }
+                }
+            });
+
+            // Optionally take balances as decimal amounts, converting them into base units.
+            let decimal_amount_fn = match &struct_def.fields {
+                CompositeDefFields::Named(named_fields)
+                    if options.decimal_amounts
+                        && named_fields.iter().any(|(_, field)| type_aliases::is_balance(field)) =>
+                {
+                    let decimal_amount_fn_name = format_ident!("{}_with_decimals", fn_name);
+                    let (fn_args, conversions): (Vec<_>, Vec<_>) = named_fields
+                        .iter()
+                        .zip(&call_fn_args)
+                        .map(|((name, field), fn_arg)| {
+                            if !type_aliases::is_balance(field) {
+                                return (fn_arg.clone(), None);
+                            }
+                            let integer = field.type_path.integer_type().expect("balances are integers");
+                            let amount = quote! {
+                                #crate_path::utils::parse_amount::<#integer>(#name, __subxt_decimals)?
+                            };
+                            let value = match type_aliases.newtype_for(field) {
+                                Some(newtype) => quote!( root_mod::type_aliases::#newtype(#amount) ),
+                                None => amount,
+                            };
+                            (quote!( #name: &str ), Some(quote!( let #name = #value; )))
+                        })
+                        .unzip();
+                    let conversions = conversions.into_iter().flatten();
+                    let decimal_amount_docs = format!(
+                        "Build a [`types::{struct_name}`] call, taking its balances as decimal \
+                         amounts of a token with the given number of decimals (which can be found \
+                         via `OnlineClient::properties()`), for instance `\"1.5\"`. An error is \
+                         returned if a balance isn't a valid amount, would need rounding to fit \
+                         into base units, or is too large."
+                    );
+                    Some(quote! {
+                        #[doc = #decimal_amount_docs]
+                        #deprecated
+                        pub fn #decimal_amount_fn_name(
+                            &self,
+                            #( #fn_args, )*
+                            __subxt_decimals: u32,
+                        ) -> ::core::result::Result<#crate_path::tx::Payload<types::#struct_name>, #crate_path::utils::AmountError> {
+                            #( #conversions )*
+                            #[allow(deprecated)]
+                            ::core::result::Result::Ok(self.#fn_name( #( #call_arg_names, )* ))
+                        }
+                    })
+                }
+                _ => None,
+            };
+
+            let client_fn = quote! {
+                #client_fn
+                #submit_fn
+                #decimal_amount_fn
+            };
+
+            Ok((call_struct, (client_fn, call_test)))
         })
         .collect::<Result<Vec<_>, _>>()?
         .into_iter()
         .unzip();
+    let (call_fns, call_tests): (Vec<_>, Vec<_>) = call_fns.into_iter().unzip();
+    let call_tests: Vec<_> = call_tests.into_iter().flatten().collect();
+
+    let tests_mod = (!call_tests.is_empty()).then(|| {
+        quote! {
+            #[cfg(test)]
+            mod tests {
+                #( #call_tests )*
+            }
+        }
+    });
 
     let call_type = type_gen.resolve_type_path(call_ty);
     let call_ty = type_gen.resolve_type(call_ty);
@@ -115,9 +460,24 @@ pub fn generate_calls(
         .then_some(quote! { #( #[doc = #docs ] )* })
         .unwrap_or_default();
 
+    let pallet_index = proc_macro2::Literal::u8_unsuffixed(pallet.index());
+
     Ok(quote! {
         #docs
         pub type Call = #call_type;
+
+        /// Decode the SCALE encoded bytes of a call to this pallet, including the leading
+        /// pallet index, into a [`Call`]. All of the bytes must be consumed.
+        pub fn decode_call(bytes: &[u8]) -> ::core::result::Result<Call, #crate_path::ext::codec::Error> {
+            match bytes.split_first() {
+                Some((&#pallet_index, mut call_bytes)) => {
+                    <Call as #crate_path::ext::codec::DecodeAll>::decode_all(&mut call_bytes)
+                }
+                Some(_) => Err("Call bytes do not start with this pallet's index".into()),
+                None => Err("No call bytes given".into()),
+            }
+        }
+
         pub mod calls {
             use super::root_mod;
             use super::#types_mod_ident;
@@ -135,6 +495,235 @@ pub fn generate_calls(
             impl TransactionApi {
                 #( #call_fns )*
             }
+
+            #tests_mod
         }
     })
 }
+
+/// Find the runtime's outer call enum (`RuntimeCall`), which has a variant for each pallet with
+/// calls, wrapping the call type of that pallet.
+pub fn runtime_call_ty(metadata: &Metadata) -> Option<u32> {
+    let pallet_calls: Vec<_> = metadata
+        .pallets()
+        .filter_map(|pallet| Some((pallet.name(), pallet.call_ty_id()?)))
+        .collect();
+    if pallet_calls.is_empty() {
+        return None;
+    }
+
+    let wraps_pallet_calls = |def: &TypeDefVariant<PortableForm>| {
+        pallet_calls.iter().all(|(name, call_ty)| {
+            def.variants.iter().any(|variant| {
+                variant.name == *name
+                    && matches!(variant.fields.as_slice(), [field] if field.ty.id == *call_ty)
+            })
+        })
+    };
+    metadata
+        .types()
+        .types
+        .iter()
+        .find(|ty| matches!(&ty.ty.type_def, TypeDef::Variant(def) if wraps_pallet_calls(def)))
+        .map(|ty| ty.id)
+}
+
+/// The most items that a `BoundedVec` call argument can hold.
+#[derive(Debug, PartialEq, Eq)]
+enum MaxLength {
+    /// A fixed maximum, from a `ConstU32<N>` bound.
+    Fixed(u32),
+    /// The maximum is the value of the pallet constant with this name.
+    Constant(String),
+}
+
+/// Work out the bound of a `BoundedVec` from its type name in the metadata, which is its
+/// last generic param, so `BoundedVec<u8, T::MaxItems>` is bounded by the `MaxItems` pallet
+/// constant and `BoundedVec<u8, ConstU32<32>>` holds at most 32 items.
+fn bounded_vec_max(type_name: &str) -> Option<MaxLength> {
+    let (_, params) = type_name.trim().strip_suffix('>')?.split_once('<')?;
+
+    let mut depth = 0;
+    let start = params.char_indices().rev().find_map(|(idx, c)| {
+        match c {
+            '>' | ')' | ']' => depth += 1,
+            '<' | '(' | '[' => depth -= 1,
+            ',' if depth == 0 => return Some(idx + 1),
+            _ => {}
+        }
+        None
+    })?;
+    let bound = params[start..].trim();
+
+    if let Some((path, max)) = bound
+        .strip_suffix('>')
+        .and_then(|bound| bound.split_once('<'))
+    {
+        let is_const = path.trim().rsplit("::").next()? == "ConstU32";
+        return is_const
+            .then(|| max.trim().parse().ok())
+            .flatten()
+            .map(MaxLength::Fixed);
+    }
+
+    let name = bound.rsplit("::").next()?.trim();
+    let is_constant = name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    is_constant.then(|| MaxLength::Constant(name.to_owned()))
+}
+
+/// An expression constructing a call struct with the given fields, each set to its default.
+fn default_constructor(fields: &CompositeDefFields) -> TokenStream2 {
+    let default_value = |field: &CompositeDefFieldType| {
+        if field.is_boxed() {
+            quote!(::std::boxed::Box::new(::core::default::Default::default()))
+        } else {
+            quote!(::core::default::Default::default())
+        }
+    };
+    match fields {
+        CompositeDefFields::Named(named_fields) => {
+            let fields = named_fields.iter().map(|(name, field)| {
+                let value = default_value(field);
+                quote!( #name: #value )
+            });
+            quote!( Self { #( #fields, )* } )
+        }
+        CompositeDefFields::Unnamed(unnamed_fields) => {
+            let fields = unnamed_fields.iter().map(default_value);
+            quote!( Self( #( #fields, )* ) )
+        }
+        CompositeDefFields::NoFields => quote!(Self {}),
+    }
+}
+
+/// Look for a `# Deprecated` heading in some call docs, returning the text following it
+/// (up to the next heading) as the deprecation note.
+fn deprecation_note(docs: &[String]) -> Option<String> {
+    let mut lines = docs.iter().map(|line| line.trim());
+    lines
+        .by_ref()
+        .find(|line| line.eq_ignore_ascii_case("# deprecated"))?;
+
+    let note = lines
+        .take_while(|line| !line.starts_with('#'))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    if note.is_empty() {
+        Some("This call is deprecated".to_string())
+    } else {
+        Some(note)
+    }
+}
+
+/// Guess which of the given event names is emitted when the call with the given (upper camel
+/// case) name succeeds, returning `None` unless exactly one event looks like a match. An event
+/// matches if it has the same name as the call (`Transfer`), is the past tense of it (`Bond`
+/// and `Bonded`, `Remark` and `Remarked`), or swaps a leading `Set` to the end (`SetBalance`
+/// and `BalanceSet`).
+fn success_event_name<'a>(call_name: &str, event_names: &[&'a str]) -> Option<&'a str> {
+    let mut candidates = vec![call_name.to_owned()];
+    if call_name.ends_with('e') {
+        candidates.push(format!("{call_name}d"));
+    } else {
+        candidates.push(format!("{call_name}ed"));
+    }
+    if let Some(rest) = call_name
+        .strip_prefix("Set")
+        .filter(|rest| !rest.is_empty())
+    {
+        candidates.push(format!("{rest}Set"));
+    }
+
+    let mut matches = event_names
+        .iter()
+        .filter(|event_name| candidates.iter().any(|c| c == *event_name));
+    match (matches.next(), matches.next()) {
+        (Some(event_name), None) => Some(*event_name),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bounded_vec_max, default_constructor, success_event_name, MaxLength};
+    use crate::types::{CompositeDefFieldType, CompositeDefFields, TypePath};
+
+    #[test]
+    fn success_events_are_paired_by_name() {
+        let events = ["Transfer", "BalanceSet", "Remarked", "Bonded", "Unbonded"];
+
+        assert_eq!(success_event_name("Transfer", &events), Some("Transfer"));
+        assert_eq!(
+            success_event_name("SetBalance", &events),
+            Some("BalanceSet")
+        );
+        assert_eq!(success_event_name("Remark", &events), Some("Remarked"));
+        assert_eq!(success_event_name("Bond", &events), Some("Bonded"));
+        assert_eq!(success_event_name("TransferKeepAlive", &events), None);
+        assert_eq!(success_event_name("Set", &events), None);
+    }
+
+    #[test]
+    fn ambiguous_success_events_are_left_unset() {
+        let events = ["Remark", "Remarked"];
+        assert_eq!(success_event_name("Remark", &events), None);
+    }
+
+    #[test]
+    fn bounded_vec_maximums_are_found_from_type_names() {
+        let constant = |name: &str| Some(MaxLength::Constant(name.to_owned()));
+
+        assert_eq!(
+            bounded_vec_max("BoundedVec<u8, T::MaxItems>"),
+            constant("MaxItems")
+        );
+        assert_eq!(
+            bounded_vec_max("BoundedVec<(T::AccountId, u32), <T as Config>::MaxMembers>"),
+            constant("MaxMembers")
+        );
+        assert_eq!(
+            bounded_vec_max("WeakBoundedVec<Vec<u8>, MaxKeys>"),
+            constant("MaxKeys")
+        );
+        assert_eq!(
+            bounded_vec_max("BoundedVec<u8, ConstU32<32>>"),
+            Some(MaxLength::Fixed(32))
+        );
+        assert_eq!(
+            bounded_vec_max("BoundedVec<u8, frame_support::traits::ConstU32<32>>"),
+            Some(MaxLength::Fixed(32))
+        );
+    }
+
+    #[test]
+    fn unknown_bounds_are_ignored() {
+        assert_eq!(bounded_vec_max("BoundedVec<u8>"), None);
+        assert_eq!(bounded_vec_max("Vec<u8>"), None);
+        assert_eq!(bounded_vec_max("BoundedVec<u8, ConstU64<32>>"), None);
+        assert_eq!(bounded_vec_max("BoundedVec<u8, Get<u32>>"), None);
+    }
+
+    #[test]
+    fn unnamed_fields_are_defaulted_in_order() {
+        let field = |ty: syn::Path, type_name: Option<&str>| {
+            let type_name = type_name.map(ToOwned::to_owned);
+            CompositeDefFieldType::new(0, TypePath::from_syn_path(ty), type_name)
+        };
+        let fields = CompositeDefFields::Unnamed(vec![
+            field(syn::parse_quote!(::core::primitive::u8), None),
+            field(syn::parse_quote!(::core::primitive::u32), Some("Box<u32>")),
+        ]);
+
+        let expected = quote::quote!(Self(
+            ::core::default::Default::default(),
+            ::std::boxed::Box::new(::core::default::Default::default()),
+        ));
+        assert_eq!(
+            default_constructor(&fields).to_string(),
+            expected.to_string()
+        );
+    }
+}
@@ -71,6 +71,17 @@ pub fn generate_constants(
         })
     }).collect::<Result<Vec<_>, _>>()?;
 
+    let constant_checks = pallet.constants().map(|constant| {
+        let fn_name = format_ident!("{}", constant.name().to_snake_case());
+        let pallet_name = pallet.name();
+        let constant_name = constant.name();
+        quote! {
+            if constants.validate(&self.#fn_name()).is_err() {
+                mismatched.push((#pallet_name, #constant_name));
+            }
+        }
+    });
+
     Ok(quote! {
         pub mod constants {
             use super::#types_mod_ident;
@@ -79,6 +90,19 @@ pub fn generate_constants(
 
             impl ConstantsApi {
                 #(#constant_fns)*
+
+                /// Validate every constant in this pallet against the metadata of the given client,
+                /// returning the `(pallet, constant)` names of any which don't match.
+                pub fn validate<T, Client>(&self, client: &Client) -> ::std::vec::Vec<(&'static str, &'static str)>
+                where
+                    T: #crate_path::Config,
+                    Client: #crate_path::client::OfflineClientT<T>,
+                {
+                    let constants = client.constants();
+                    let mut mismatched = ::std::vec::Vec::new();
+                    #(#constant_checks)*
+                    mismatched
+                }
             }
         }
     })
@@ -5,7 +5,8 @@
 use crate::{types::TypeGenerator, CratePath};
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use subxt_metadata::PalletMetadata;
+use scale_info::TypeDef;
+use subxt_metadata::{Metadata, PalletMetadata, StorageEntryType};
 
 use super::CodegenError;
 
@@ -80,12 +81,43 @@ pub fn generate_events(
         .then_some(quote! { #( #[doc = #docs ] )* })
         .unwrap_or_default();
 
+    // An event enum with no variants has no structs to put in an `events` module.
+    let events_mod = (!struct_defs.is_empty()).then(|| {
+        quote! {
+            pub mod events {
+                use super::#types_mod_ident;
+                #( #event_structs )*
+            }
+        }
+    });
+
     Ok(quote! {
         #docs
         pub type Event = #event_type;
-        pub mod events {
-            use super::#types_mod_ident;
-            #( #event_structs )*
-        }
+        #events_mod
     })
 }
+
+/// Find the type of the topics in each record of the `System::Events` storage entry, which is a
+/// list of `EventRecord`s each containing a phase, the event itself, and some topics.
+pub fn event_topics_ty(metadata: &Metadata) -> Option<u32> {
+    let entry = metadata
+        .pallet_by_name("System")?
+        .storage()?
+        .entry_by_name("Events")?;
+    let StorageEntryType::Plain(events_ty) = entry.entry_type() else {
+        return None;
+    };
+    let TypeDef::Sequence(events) = &metadata.types().resolve(*events_ty)?.type_def else {
+        return None;
+    };
+    let TypeDef::Composite(record) = &metadata.types().resolve(events.type_param.id)?.type_def
+    else {
+        return None;
+    };
+    record
+        .fields
+        .iter()
+        .find(|field| field.name.as_deref() == Some("topics"))
+        .map(|field| field.ty.id)
+}
@@ -8,17 +8,23 @@ mod calls;
 mod constants;
 mod errors;
 mod events;
+mod rpc_methods;
 mod runtime_apis;
 mod storage;
+mod type_aliases;
 
 use subxt_metadata::Metadata;
 
+pub use rpc_methods::{generate_rpc_methods, RpcMethod};
+
+use type_aliases::TypeAliases;
+
 use super::DerivesRegistry;
 use crate::error::CodegenError;
 use crate::{
     ir,
     types::{CompositeDef, CompositeDefFields, TypeGenerator, TypeSubstitutes},
-    utils::{fetch_metadata_bytes_blocking, MetadataVersion, Uri},
+    utils::{decompress_metadata_bytes, fetch_metadata_bytes_blocking, MetadataVersion, Uri},
     CratePath,
 };
 use codec::Decode;
@@ -133,6 +139,7 @@ pub fn generate_runtime_api_from_bytes(
     should_gen_docs: bool,
     runtime_types_only: bool,
 ) -> Result<TokenStream2, CodegenError> {
+    let bytes = decompress_metadata_bytes(bytes)?;
     let metadata = Metadata::decode(&mut &bytes[..])?;
 
     let generator = RuntimeGenerator::new(metadata);
@@ -158,6 +165,37 @@ pub fn generate_runtime_api_from_bytes(
 /// Create the API for interacting with a Substrate runtime.
 pub struct RuntimeGenerator {
     metadata: Metadata,
+    call_gen_options: CallGenOptions,
+    allowed_lints: Option<Vec<syn::Path>>,
+    types_mod_name: String,
+    rpc_methods: Vec<RpcMethod>,
+    unknown_call_variant: bool,
+    pallet_aliases: Vec<(String, String)>,
+}
+
+/// Optional extras to generate alongside the calls of each pallet.
+#[derive(Debug, Default, Clone, Copy)]
+struct CallGenOptions {
+    /// Mark calls whose docs contain a `# Deprecated` section as `#[deprecated]`.
+    deprecations: bool,
+    /// Emit a `#[cfg(test)]` module which checks that each call struct whose fields
+    /// can all be defaulted round-trips through SCALE encoding and decoding.
+    tests: bool,
+    /// Emit an async `<call>_submit` function per call to sign, submit and wait for it.
+    submit_fns: bool,
+    /// Emit a `call_info()` function on each call struct describing its arguments.
+    call_info: bool,
+    /// Pair calls with the event they emit on success by name, where that's unambiguous.
+    success_events: bool,
+    /// Take call function arguments as `impl Into<..>` where that won't hurt type inference.
+    impl_into_args: bool,
+    /// Take integer call function arguments as newtypes named after their type in the metadata.
+    type_alias_newtypes: bool,
+    /// Emit a compile time assertion per call that it has as many fields as the metadata says.
+    asserts: bool,
+    /// Emit a `<call>_with_decimals` function per call taking balances, which takes them as
+    /// decimal amounts.
+    decimal_amounts: bool,
 }
 
 impl RuntimeGenerator {
@@ -174,7 +212,165 @@ impl RuntimeGenerator {
     /// Supported versions: v14 and v15.
     pub fn new(mut metadata: Metadata) -> Self {
         Self::ensure_unique_type_paths(&mut metadata);
-        RuntimeGenerator { metadata }
+        RuntimeGenerator {
+            metadata,
+            call_gen_options: CallGenOptions::default(),
+            allowed_lints: None,
+            types_mod_name: "runtime_types".to_string(),
+            rpc_methods: Vec::new(),
+            unknown_call_variant: false,
+            pallet_aliases: Vec::new(),
+        }
+    }
+
+    /// Set the name of the module that the runtime types are generated into.
+    /// Defaults to `runtime_types`.
+    pub fn types_mod_name(mut self, name: impl Into<String>) -> Self {
+        self.types_mod_name = name.into();
+        self
+    }
+
+    /// Replace the lints which are allowed at the top of the generated module.
+    ///
+    /// By default, `dead_code`, `unused_imports`, `non_camel_case_types` and `clippy::all`
+    /// are allowed. Passing an empty list emits no `#[allow(..)]` attribute at all.
+    pub fn allow_lints(mut self, lints: impl IntoIterator<Item = syn::Path>) -> Self {
+        self.allowed_lints = Some(lints.into_iter().collect());
+        self
+    }
+
+    /// The `#[allow(..)]` attribute to place on the top level generated module.
+    fn allow_lints_attr(&self) -> TokenStream2 {
+        match &self.allowed_lints {
+            None => quote! {
+                #[allow(dead_code, unused_imports, non_camel_case_types)]
+                #[allow(clippy::all)]
+            },
+            Some(lints) if lints.is_empty() => quote!(),
+            Some(lints) => quote! { #[allow( #( #lints ),* )] },
+        }
+    }
+
+    /// Mark generated call functions as `#[deprecated]` if the docs for the call contain a
+    /// `# Deprecated` heading; the text below the heading is used as the deprecation note.
+    /// Off by default.
+    pub fn deprecations_from_docs(mut self, should_gen_deprecations: bool) -> Self {
+        self.call_gen_options.deprecations = should_gen_deprecations;
+        self
+    }
+
+    /// Emit a `#[cfg(test)]` module alongside the generated calls, which checks that
+    /// call structs round-trip through SCALE encoding and decoding. Off by default.
+    ///
+    /// Only calls whose fields can all be constructed via `Default` are tested.
+    pub fn emit_tests(mut self, should_gen_tests: bool) -> Self {
+        self.call_gen_options.tests = should_gen_tests;
+        self
+    }
+
+    /// Alongside each call function on the generated `TransactionApi`, also emit an async
+    /// `<call>_submit` function which fetches the nonce, signs and submits the call, and then
+    /// waits for it to be finalized successfully. Off by default.
+    pub fn emit_submit_fns(mut self, should_gen_submit_fns: bool) -> Self {
+        self.call_gen_options.submit_fns = should_gen_submit_fns;
+        self
+    }
+
+    /// Emit a `call_info()` function on each generated call struct, which returns the name
+    /// of each argument alongside a description of its type (as given in the metadata where
+    /// possible). Off by default.
+    pub fn emit_call_info(mut self, should_gen_call_info: bool) -> Self {
+        self.call_gen_options.call_info = should_gen_call_info;
+        self
+    }
+
+    /// Implement `CallSuccessEvent` for call structs whose success event can be guessed from
+    /// the names of the call and the pallet's events (for instance `Balances::transfer` and
+    /// `Balances::Transfer`). Calls where the guess is ambiguous are left alone. Off by default.
+    pub fn emit_success_events(mut self, should_gen_success_events: bool) -> Self {
+        self.call_gen_options.success_events = should_gen_success_events;
+        self
+    }
+
+    /// Have the generated call functions take their arguments as `impl Into<FieldType>` and
+    /// call `.into()` on them, so that references or other convertible values can be passed
+    /// without cloning them first. Numeric arguments are left as they are so that integer
+    /// literals still infer their type. Off by default; arguments are taken by value.
+    pub fn impl_into_args(mut self, should_gen_impl_into_args: bool) -> Self {
+        self.call_gen_options.impl_into_args = should_gen_impl_into_args;
+        self
+    }
+
+    /// Generate a `type_aliases` module containing a newtype for each type name (for instance
+    /// `BalanceOf<T>` or `T::BlockNumber`) given to integer call arguments in the metadata,
+    /// and have the generated call functions take those arguments as the newtypes, so that
+    /// a balance can't be passed where a block number is expected. The newtypes are encoded
+    /// exactly like the integers they wrap. Off by default, since it changes the signatures
+    /// of the call functions.
+    pub fn emit_type_alias_newtypes(mut self, should_gen_type_alias_newtypes: bool) -> Self {
+        self.call_gen_options.type_alias_newtypes = should_gen_type_alias_newtypes;
+        self
+    }
+
+    /// Emit a `const` assertion alongside each call struct which checks, at compile time, that
+    /// the number of fields passed to it matches the number of fields that the call had in the
+    /// metadata that the code was generated from. This guards against codegen bugs which drop
+    /// or duplicate fields. Off by default.
+    pub fn emit_asserts(mut self, should_gen_asserts: bool) -> Self {
+        self.call_gen_options.asserts = should_gen_asserts;
+        self
+    }
+
+    /// Alongside each call function which takes balances (call arguments whose type is named
+    /// `BalanceOf<T>`, `T::Balance` or similar in the metadata), also emit a
+    /// `<call>_with_decimals` function which takes those balances as human readable decimal
+    /// amounts like `"1.5"`, along with the number of decimals of the token, and converts them
+    /// into base units. This guards against passing whole tokens where base units are expected.
+    /// Off by default.
+    pub fn emit_decimal_amount_fns(mut self, should_gen_decimal_amount_fns: bool) -> Self {
+        self.call_gen_options.decimal_amounts = should_gen_decimal_amount_fns;
+        self
+    }
+
+    /// Generate a `rpc` module containing a typed async function for each of the given
+    /// custom RPC methods. See [`RpcMethod::new()`] for how these are described.
+    pub fn rpc_methods(mut self, methods: impl IntoIterator<Item = RpcMethod>) -> Self {
+        self.rpc_methods = methods.into_iter().collect();
+        self
+    }
+
+    /// Add an `Unknown { pallet_index, bytes }` variant to the generated outer `Call` enum,
+    /// which `decode_runtime_call` falls back to for calls to pallets that are not in the
+    /// metadata the code was generated from (for instance because a pallet has since been
+    /// added to the runtime), rather than failing to decode them. Off by default.
+    pub fn emit_unknown_call_variant(mut self, should_gen_unknown_call_variant: bool) -> Self {
+        self.unknown_call_variant = should_gen_unknown_call_variant;
+        self
+    }
+
+    /// Also make the generated code for some pallets available under other names, which is
+    /// handy when a runtime renames a pallet and downstream code still refers to it by its
+    /// old name. Each alias is given as `(alias, pallet)`, where `pallet` is the name of the
+    /// pallet in the metadata; for instance `("Democracy", "ConvictionVoting")`.
+    ///
+    /// For each alias, a module named after it (`democracy`) re-exports everything in the
+    /// pallet's module (`conviction_voting`), and `tx()`, `storage()` and `constants()` gain
+    /// a function named after it too. The calls, events and storage entries themselves are
+    /// unchanged, and so they are still validated against the pallet's metadata under its
+    /// current name.
+    pub fn pallet_aliases<Alias, Pallet>(
+        mut self,
+        aliases: impl IntoIterator<Item = (Alias, Pallet)>,
+    ) -> Self
+    where
+        Alias: Into<String>,
+        Pallet: Into<String>,
+    {
+        self.pallet_aliases = aliases
+            .into_iter()
+            .map(|(alias, pallet)| (alias.into(), pallet.into()))
+            .collect();
+        self
     }
 
     /// Ensure that every unique type we'll be generating or referring to also has a
@@ -241,18 +437,18 @@ impl RuntimeGenerator {
 
         let type_gen = TypeGenerator::new(
             self.metadata.types(),
-            "runtime_types",
+            &self.types_mod_name,
             type_substitutes,
             derives,
             crate_path,
             should_gen_docs,
         );
         let types_mod = type_gen.generate_types_mod()?;
+        let allow_lints_attr = self.allow_lints_attr();
 
         Ok(quote! {
             #( #item_mod_attrs )*
-            #[allow(dead_code, unused_imports, non_camel_case_types)]
-            #[allow(clippy::all)]
+            #allow_lints_attr
             pub mod #mod_ident {
                 // Preserve any Rust items that were previously defined in the adorned module
                 #( #rust_items ) *
@@ -292,7 +488,7 @@ impl RuntimeGenerator {
 
         let type_gen = TypeGenerator::new(
             self.metadata.types(),
-            "runtime_types",
+            &self.types_mod_name,
             type_substitutes,
             derives.clone(),
             crate_path.clone(),
@@ -327,6 +523,14 @@ impl RuntimeGenerator {
             .only_these_pallets(&pallet_names)
             .hash();
 
+        let type_aliases = if self.call_gen_options.type_alias_newtypes {
+            TypeAliases::from_calls(&self.metadata, &type_gen)
+        } else {
+            TypeAliases::default()
+        };
+
+        let runtime_call_ty = calls::runtime_call_ty(&self.metadata);
+
         let modules = pallets_with_mod_names
             .iter()
             .map(|(pallet, mod_name)| {
@@ -336,6 +540,9 @@ impl RuntimeGenerator {
                     types_mod_ident,
                     &crate_path,
                     should_gen_docs,
+                    &self.call_gen_options,
+                    &type_aliases,
+                    runtime_call_ty,
                 )?;
 
                 let event = events::generate_events(
@@ -398,6 +605,27 @@ impl RuntimeGenerator {
             }
         };
 
+        let event_records = events::event_topics_ty(&self.metadata).map(|topics_ty| {
+            let topics = type_gen.resolve_type_path(topics_ty);
+            quote! {
+                /// An [`Event`], along with the phase of the block that it was emitted in and its
+                /// topics, as stored in the `System::Events` storage entry.
+                #[derive(#crate_path::ext::codec::Decode, #crate_path::ext::codec::Encode, Debug)]
+                #[codec(crate = #crate_path::ext::codec)]
+                pub struct EventRecord {
+                    pub phase: #crate_path::events::Phase,
+                    pub event: Event,
+                    pub topics: #topics,
+                }
+
+                /// Decode the SCALE encoded bytes of the `System::Events` storage entry into an
+                /// [`EventRecord`] for each event. All of the bytes must be consumed.
+                pub fn decode_runtime_events(bytes: &[u8]) -> ::core::result::Result<::std::vec::Vec<EventRecord>, #crate_path::ext::codec::Error> {
+                    <::std::vec::Vec<EventRecord> as #crate_path::ext::codec::DecodeAll>::decode_all(&mut &*bytes)
+                }
+            }
+        });
+
         let outer_extrinsic_variants = self.metadata.pallets().filter_map(|p| {
             let variant_name = format_ident!("{}", p.name());
             let mod_name = format_ident!("{}", p.name().to_string().to_snake_case());
@@ -411,10 +639,94 @@ impl RuntimeGenerator {
             })
         });
 
-        let outer_extrinsic = quote! {
-            #default_derives
-            pub enum Call {
-                #( #outer_extrinsic_variants )*
+        let outer_extrinsic = if self.unknown_call_variant {
+            let known_pallet_indices: Vec<_> = self
+                .metadata
+                .pallets()
+                .filter(|p| p.call_ty_id().is_some())
+                .map(|p| p.index())
+                .collect();
+            let known_pallets_len = known_pallet_indices.len();
+
+            // The derived `Encode` would encode `Unknown` as nothing at all, so it's written by
+            // hand instead, to encode `Unknown` back into the bytes that it was decoded from.
+            let mut call_derives = default_derives.clone();
+            let encode_impl = call_derives.remove_derive_named("Encode").then(|| {
+                let encode_arms = self.metadata.pallets().filter_map(|p| {
+                    let variant_name = format_ident!("{}", p.name());
+                    let index = proc_macro2::Literal::u8_unsuffixed(p.index());
+                    p.call_ty_id().map(|_| {
+                        quote! {
+                            Call::#variant_name(call) => {
+                                #crate_path::ext::codec::Output::push_byte(dest, #index);
+                                #crate_path::ext::codec::Encode::encode_to(call, dest);
+                            }
+                        }
+                    })
+                });
+                quote! {
+                    impl #crate_path::ext::codec::Encode for Call {
+                        fn encode_to<T: #crate_path::ext::codec::Output + ?Sized>(&self, dest: &mut T) {
+                            match self {
+                                #( #encode_arms )*
+                                Call::Unknown { pallet_index, bytes } => {
+                                    #crate_path::ext::codec::Output::push_byte(dest, *pallet_index);
+                                    #crate_path::ext::codec::Output::write(dest, bytes);
+                                }
+                            }
+                        }
+                    }
+                    impl #crate_path::ext::codec::EncodeLike for Call {}
+                }
+            });
+
+            quote! {
+                #call_derives
+                pub enum Call {
+                    #( #outer_extrinsic_variants )*
+                    /// A call to a pallet that isn't known about. This is only handed back from
+                    /// [`decode_runtime_call`], and encodes as the pallet index followed by the bytes.
+                    #[codec(skip)]
+                    Unknown {
+                        /// The index of the pallet.
+                        pallet_index: ::core::primitive::u8,
+                        /// The SCALE encoded call, not including the pallet index.
+                        bytes: ::std::vec::Vec<::core::primitive::u8>,
+                    },
+                }
+
+                #encode_impl
+
+                /// The indices of the pallets with calls that [`Call`] knows about.
+                pub static CALL_PALLET_INDICES: [::core::primitive::u8; #known_pallets_len] = [ #( #known_pallet_indices, )* ];
+
+                /// Decode the SCALE encoded bytes of a call to any pallet into a [`Call`].
+                /// All of the bytes must be consumed. Calls to pallets that aren't known about
+                /// are handed back as [`Call::Unknown`].
+                pub fn decode_runtime_call(bytes: &[u8]) -> ::core::result::Result<Call, #crate_path::ext::codec::Error> {
+                    match bytes.split_first() {
+                        Some((pallet_index, call_bytes)) if !CALL_PALLET_INDICES.contains(pallet_index) => {
+                            Ok(Call::Unknown {
+                                pallet_index: *pallet_index,
+                                bytes: call_bytes.to_vec(),
+                            })
+                        }
+                        _ => <Call as #crate_path::ext::codec::DecodeAll>::decode_all(&mut &*bytes),
+                    }
+                }
+            }
+        } else {
+            quote! {
+                #default_derives
+                pub enum Call {
+                    #( #outer_extrinsic_variants )*
+                }
+
+                /// Decode the SCALE encoded bytes of a call to any pallet into a [`Call`].
+                /// All of the bytes must be consumed.
+                pub fn decode_runtime_call(bytes: &[u8]) -> ::core::result::Result<Call, #crate_path::ext::codec::Error> {
+                    <Call as #crate_path::ext::codec::DecodeAll>::decode_all(&mut &*bytes)
+                }
             }
         };
 
@@ -504,7 +816,12 @@ impl RuntimeGenerator {
 
         let pallets_with_storage: Vec<_> = pallets_with_mod_names
             .iter()
-            .filter_map(|(pallet, pallet_mod_name)| pallet.storage().map(|_| pallet_mod_name))
+            .filter_map(|(pallet, pallet_mod_name)| {
+                pallet
+                    .storage()
+                    .filter(|storage| storage.entries().next().is_some())
+                    .map(|_| pallet_mod_name)
+            })
             .collect();
 
         let pallets_with_calls: Vec<_> = pallets_with_mod_names
@@ -512,8 +829,79 @@ impl RuntimeGenerator {
             .filter_map(|(pallet, pallet_mod_name)| pallet.call_ty_id().map(|_| pallet_mod_name))
             .collect();
 
+        // Aliased pallets re-export the pallet's module under another name, and have
+        // accessors named after the alias which hand back the pallet's APIs.
+        let mut alias_mods = Vec::new();
+        let mut alias_constants = Vec::new();
+        let mut alias_storage = Vec::new();
+        let mut alias_calls = Vec::new();
+        for (alias, pallet_name) in &self.pallet_aliases {
+            let invalid = |reason: &str| {
+                CodegenError::InvalidPalletAlias(
+                    alias.clone(),
+                    pallet_name.clone(),
+                    reason.to_owned(),
+                )
+            };
+            let alias_mod = syn::parse_str::<syn::Ident>(&alias.to_snake_case())
+                .map_err(|_| invalid("the alias isn't a valid module name"))?;
+            let Some((pallet, pallet_mod)) = pallets_with_mod_names
+                .iter()
+                .find(|(pallet, _)| pallet.name() == pallet_name.as_str())
+            else {
+                return Err(invalid("there is no pallet with that name in the metadata"));
+            };
+            let clashes = pallets_with_mod_names
+                .iter()
+                .map(|(_, mod_name)| mod_name)
+                .chain(alias_mods.iter().map(|(alias_mod, _)| alias_mod))
+                .any(|mod_name| *mod_name == alias_mod);
+            if clashes {
+                return Err(invalid(
+                    "the alias is already the name of another pallet or alias",
+                ));
+            }
+
+            if pallet.constants().next().is_some() {
+                alias_constants.push(quote! {
+                    pub fn #alias_mod(&self) -> #pallet_mod::constants::ConstantsApi {
+                        #pallet_mod::constants::ConstantsApi
+                    }
+                });
+            }
+            if pallet
+                .storage()
+                .map_or(false, |storage| storage.entries().next().is_some())
+            {
+                alias_storage.push(quote! {
+                    pub fn #alias_mod(&self) -> #pallet_mod::storage::StorageApi {
+                        #pallet_mod::storage::StorageApi
+                    }
+                });
+            }
+            if pallet.call_ty_id().is_some() {
+                alias_calls.push(quote! {
+                    pub fn #alias_mod(&self) -> #pallet_mod::calls::TransactionApi {
+                        #pallet_mod::calls::TransactionApi
+                    }
+                });
+            }
+            alias_mods.push((alias_mod, pallet_mod));
+        }
+        let alias_mods = alias_mods.iter().map(|(alias_mod, pallet_mod)| {
+            let docs = format!(" An alias for [`{pallet_mod}`].");
+            quote! {
+                #[doc = #docs]
+                pub mod #alias_mod {
+                    pub use super::#pallet_mod::*;
+                }
+            }
+        });
+
         let rust_items = item_mod_ir.rust_items();
 
+        let allow_lints_attr = self.allow_lints_attr();
+
         let apis_mod = runtime_apis::generate_runtime_apis(
             &self.metadata,
             &type_gen,
@@ -522,10 +910,32 @@ impl RuntimeGenerator {
             should_gen_docs,
         )?;
 
+        // Map pallet and call indices back to their names, for decoding and logging.
+        let pallet_name_arms = self.metadata.pallets().map(|pallet| {
+            let index = pallet.index();
+            let name = pallet.name();
+            quote!( #index => ::core::option::Option::Some(#name), )
+        });
+        let call_name_arms = self.metadata.pallets().flat_map(|pallet| {
+            let pallet_index = pallet.index();
+            pallet.call_variants().unwrap_or_default().iter().map(move |variant| {
+                let call_index = variant.index;
+                let name = &variant.name;
+                quote!( (#pallet_index, #call_index) => ::core::option::Option::Some(#name), )
+            })
+        });
+
+        let rpc_mod = if self.rpc_methods.is_empty() {
+            quote!()
+        } else {
+            generate_rpc_methods(&self.rpc_methods, &crate_path)
+        };
+
+        let type_aliases_mod = type_aliases.generate(&crate_path);
+
         Ok(quote! {
             #( #item_mod_attrs )*
-            #[allow(dead_code, unused_imports, non_camel_case_types)]
-            #[allow(clippy::all)]
+            #allow_lints_attr
             pub mod #mod_ident {
                 // Preserve any Rust items that were previously defined in the adorned module.
                 #( #rust_items ) *
@@ -543,8 +953,27 @@ impl RuntimeGenerator {
                 /// The error type returned when there is a runtime issue.
                 pub type DispatchError = #types_mod_ident::sp_runtime::DispatchError;
 
+                /// The name of the pallet with the given index, if there is one.
+                pub fn pallet_name(index: ::core::primitive::u8) -> ::core::option::Option<&'static str> {
+                    match index {
+                        #( #pallet_name_arms )*
+                        _ => ::core::option::Option::None,
+                    }
+                }
+
+                /// The name of the call with the given index in the pallet with the given index,
+                /// if there is one.
+                pub fn call_name(pallet: ::core::primitive::u8, call: ::core::primitive::u8) -> ::core::option::Option<&'static str> {
+                    match (pallet, call) {
+                        #( #call_name_arms )*
+                        _ => ::core::option::Option::None,
+                    }
+                }
+
                 #outer_event
 
+                #event_records
+
                 impl #crate_path::events::RootEvent for Event {
                     fn root_event(pallet_bytes: &[u8], pallet_name: &str, pallet_ty: u32, metadata: &#crate_path::Metadata) -> Result<Self, #crate_path::Error> {
                         use #crate_path::metadata::DecodeWithMetadata;
@@ -592,6 +1021,10 @@ impl RuntimeGenerator {
 
                 #apis_mod
 
+                #rpc_mod
+
+                #type_aliases_mod
+
                 pub struct ConstantsApi;
                 impl ConstantsApi {
                     #(
@@ -599,6 +1032,21 @@ impl RuntimeGenerator {
                             #pallets_with_constants::constants::ConstantsApi
                         }
                     )*
+                    #( #alias_constants )*
+
+                    /// Validate every constant against the metadata of the given client, returning
+                    /// the `(pallet, constant)` names of any which don't match.
+                    pub fn validate<T, Client>(&self, client: &Client) -> ::std::vec::Vec<(&'static str, &'static str)>
+                    where
+                        T: #crate_path::Config,
+                        Client: #crate_path::client::OfflineClientT<T>,
+                    {
+                        let mut mismatched = ::std::vec::Vec::new();
+                        #(
+                            mismatched.extend(self.#pallets_with_constants().validate(client));
+                        )*
+                        mismatched
+                    }
                 }
 
                 pub struct StorageApi;
@@ -608,6 +1056,7 @@ impl RuntimeGenerator {
                             #pallets_with_storage::storage::StorageApi
                         }
                     )*
+                    #( #alias_storage )*
                 }
 
                 pub struct TransactionApi;
@@ -617,6 +1066,7 @@ impl RuntimeGenerator {
                             #pallets_with_calls::calls::TransactionApi
                         }
                     )*
+                    #( #alias_calls )*
                 }
 
                 /// check whether the Client you are using is aligned with the statically generated codegen.
@@ -630,6 +1080,7 @@ impl RuntimeGenerator {
                 }
 
                 #( #modules )*
+                #( #alias_mods )*
                 #types_mod
             }
         })
@@ -0,0 +1,185 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use crate::{error::CodegenError, CratePath};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::spanned::Spanned;
+
+/// A custom RPC method (for instance one added by a node or a pallet, which Subxt
+/// knows nothing about) that a typed wrapper function should be generated for.
+#[derive(Debug, Clone)]
+pub struct RpcMethod {
+    name: String,
+    fn_name: syn::Ident,
+    params: Vec<(syn::Ident, syn::Type)>,
+    returns: syn::Type,
+}
+
+impl RpcMethod {
+    /// Describe an RPC method by its name (ie `author_hasKey`) and the signature of the
+    /// function which should be generated to call it, for instance:
+    ///
+    /// ```text
+    /// fn has_key(public_key: Bytes, key_type: String) -> bool
+    /// ```
+    ///
+    /// Each argument is serialized to JSON and sent as a param, in order, and the response
+    /// is deserialized into the return type (`()` if none is given). The generated function
+    /// is always `async`, takes the RPC client as its first argument, and returns a
+    /// `Result` wrapping the return type.
+    pub fn new(name: impl Into<String>, signature: &syn::Signature) -> Result<Self, CodegenError> {
+        let name = name.into();
+        let invalid = |reason: &str, span| {
+            CodegenError::InvalidRpcMethod(name.clone(), reason.to_owned(), span)
+        };
+
+        if signature.asyncness.is_some() {
+            return Err(invalid(
+                "the function is always async, so don't mark it as such",
+                signature.span(),
+            ));
+        }
+        if !signature.generics.params.is_empty() || signature.generics.where_clause.is_some() {
+            return Err(invalid(
+                "generic functions are not supported",
+                signature.generics.span(),
+            ));
+        }
+
+        let params = signature
+            .inputs
+            .iter()
+            .map(|input| {
+                let syn::FnArg::Typed(arg) = input else {
+                    return Err(invalid("methods cannot take `self`", input.span()));
+                };
+                let syn::Pat::Ident(pat) = &*arg.pat else {
+                    return Err(invalid(
+                        "arguments must be plain identifiers",
+                        arg.pat.span(),
+                    ));
+                };
+                Ok((pat.ident.clone(), (*arg.ty).clone()))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let returns = match &signature.output {
+            syn::ReturnType::Default => syn::parse_quote!(()),
+            syn::ReturnType::Type(_, ty) => (**ty).clone(),
+        };
+
+        Ok(RpcMethod {
+            name,
+            fn_name: signature.ident.clone(),
+            params,
+            returns,
+        })
+    }
+
+    /// The name of the RPC method that will be called.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Generate a `pub mod rpc` containing an async function per RPC method given. Each
+/// function takes an `RpcClient` (which `OnlineClient::rpc()` derefs to) followed by
+/// the method's params.
+///
+/// Types in the signatures are resolved from within the generated module, where
+/// `root_mod` refers to the root of the generated API.
+pub fn generate_rpc_methods(methods: &[RpcMethod], crate_path: &CratePath) -> TokenStream2 {
+    let fns = methods.iter().map(|method| {
+        let RpcMethod {
+            name,
+            fn_name,
+            params,
+            returns,
+        } = method;
+        let param_names = params.iter().map(|(name, _)| name);
+        let param_tys = params.iter().map(|(_, ty)| ty);
+        let pushes = params
+            .iter()
+            .map(|(name, _)| quote!( params.push(#name)?; ));
+        let docs = format!(" Call the `{name}` RPC method.");
+
+        quote! {
+            #[doc = #docs]
+            pub async fn #fn_name(
+                rpc: &#crate_path::rpc::RpcClient,
+                #( #param_names: #param_tys, )*
+            ) -> ::core::result::Result<#returns, #crate_path::Error> {
+                #[allow(unused_mut)]
+                let mut params = #crate_path::rpc::RpcParams::new();
+                #( #pushes )*
+                rpc.request(#name, params).await
+            }
+        }
+    });
+
+    quote! {
+        /// Typed wrappers for calling custom RPC methods.
+        pub mod rpc {
+            #[allow(unused_imports)]
+            use super::root_mod;
+
+            #( #fns )*
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn method(name: &str, signature: &str) -> Result<RpcMethod, CodegenError> {
+        RpcMethod::new(name, &syn::parse_str(signature).unwrap())
+    }
+
+    #[test]
+    fn params_are_pushed_in_order() {
+        let has_key = method(
+            "author_hasKey",
+            "fn has_key(public_key: Bytes, key_type: String) -> bool",
+        )
+        .unwrap();
+        let rotate_keys = method("author_rotateKeys", "fn rotate_keys() -> Bytes").unwrap();
+
+        let code = generate_rpc_methods(&[has_key, rotate_keys], &CratePath::default()).to_string();
+
+        let expected = quote! {
+            pub async fn has_key(
+                rpc: &::subxt::rpc::RpcClient,
+                public_key: Bytes,
+                key_type: String,
+            ) -> ::core::result::Result<bool, ::subxt::Error> {
+                #[allow(unused_mut)]
+                let mut params = ::subxt::rpc::RpcParams::new();
+                params.push(public_key)?;
+                params.push(key_type)?;
+                rpc.request("author_hasKey", params).await
+            }
+        }
+        .to_string();
+        assert!(code.contains(&expected), "{code}");
+        assert!(code.contains("pub async fn rotate_keys"), "{code}");
+    }
+
+    #[test]
+    fn unsupported_signatures_are_rejected() {
+        for signature in [
+            "async fn foo() -> bool",
+            "fn foo<T>(a: T) -> bool",
+            "fn foo(&self) -> bool",
+            "fn foo((a, b): (u8, u8)) -> bool",
+        ] {
+            let err = method("foo_bar", signature).unwrap_err();
+            assert!(
+                matches!(err, CodegenError::InvalidRpcMethod(ref name, ..) if name == "foo_bar"),
+                "{signature}: {err}"
+            );
+        }
+    }
+}
@@ -3,10 +3,10 @@
 // see LICENSE for license details.
 
 use crate::{types::TypeGenerator, CratePath};
-use heck::ToSnakeCase as _;
-use proc_macro2::TokenStream as TokenStream2;
+use heck::{ToShoutySnakeCase as _, ToSnakeCase as _};
+use proc_macro2::{Literal, TokenStream as TokenStream2};
 use quote::{format_ident, quote};
-use scale_info::TypeDef;
+use scale_info::{TypeDef, TypeDefPrimitive};
 use subxt_metadata::{
     PalletMetadata, StorageEntryMetadata, StorageEntryModifier, StorageEntryType,
 };
@@ -32,6 +32,10 @@ pub fn generate_storage(
     let Some(storage) = pallet.storage() else {
         return Ok(quote!())
     };
+    // Don't emit an empty `StorageApi` for pallets which declare storage with no entries.
+    if storage.entries().next().is_none() {
+        return Ok(quote!());
+    }
 
     let storage_fns = storage
         .entries()
@@ -40,19 +44,134 @@ pub fn generate_storage(
         })
         .collect::<Result<Vec<_>, CodegenError>>()?;
 
+    let storage_defaults = storage
+        .entries()
+        .filter_map(|entry| generate_storage_entry_default(type_gen, entry, crate_path));
+
+    let pallet_name = pallet.name();
+
+    // Some pallets (Staking, for instance) have a storage entry of their own which would be
+    // given the same name; that entry can be fetched instead.
+    let has_storage_version_entry = storage
+        .entries()
+        .any(|entry| entry.name().to_snake_case() == "storage_version");
+    let storage_version_fn = (!has_storage_version_entry).then(|| {
+        quote! {
+            /// Fetch the on-chain storage version of this pallet at the given block,
+            /// or `None` if the pallet doesn't track one.
+            pub async fn storage_version<T, Client>(
+                &self,
+                storage: &#crate_path::storage::Storage<T, Client>,
+            ) -> ::core::result::Result<::core::option::Option<u16>, #crate_path::Error>
+            where
+                T: #crate_path::Config,
+                Client: #crate_path::client::OnlineClientT<T>,
+            {
+                storage.storage_version(#pallet_name).await
+            }
+        }
+    });
+
     Ok(quote! {
         pub mod storage {
             use super::#types_mod_ident;
 
+            #( #storage_defaults )*
+
             pub struct StorageApi;
 
             impl StorageApi {
                 #( #storage_fns )*
+
+                #storage_version_fn
             }
         }
     })
 }
 
+/// Expose the default value of a storage entry as provided in the metadata, so that it can be
+/// used without fetching anything from a node. The default is decoded here, and entries whose
+/// default doesn't decode as their value type are skipped, as are those with no default.
+///
+/// Defaults of primitive types are given as a `<NAME>_DEFAULT` const. Defaults of any other type
+/// are given by a `<name>_default()` function, which decodes them.
+fn generate_storage_entry_default(
+    type_gen: &TypeGenerator,
+    storage_entry: &StorageEntryMetadata,
+    crate_path: &CratePath,
+) -> Option<TokenStream2> {
+    if matches!(storage_entry.modifier(), StorageEntryModifier::Optional)
+        || storage_entry.default_bytes().is_empty()
+    {
+        return None;
+    }
+
+    let storage_entry_ty = match storage_entry.entry_type() {
+        StorageEntryType::Plain(ty) => *ty,
+        StorageEntryType::Map { value_ty, .. } => *value_ty,
+    };
+    let storage_entry_value_ty = type_gen.resolve_type_path(storage_entry_ty);
+
+    let storage_name = storage_entry.name();
+    let default_bytes = storage_entry.default_bytes();
+
+    if let TypeDef::Primitive(primitive) = &type_gen.resolve_type(storage_entry_ty).type_def {
+        let value = primitive_literal(primitive, default_bytes)?;
+        let const_name = format_ident!("{}_DEFAULT", storage_name.to_shouty_snake_case());
+        return Some(quote! {
+            #[doc = concat!("The default value of the `", #storage_name, "` storage entry.")]
+            pub const #const_name: #storage_entry_value_ty = #value;
+        });
+    }
+
+    let mut cursor = default_bytes;
+    let decoded = scale_decode::visitor::decode_with_visitor(
+        &mut cursor,
+        storage_entry_ty,
+        type_gen.type_registry(),
+        scale_decode::visitor::IgnoreVisitor,
+    );
+    if decoded.is_err() || !cursor.is_empty() {
+        return None;
+    }
+
+    let fn_name = format_ident!("{}_default", storage_name.to_snake_case());
+    Some(quote! {
+        #[doc = concat!("The default value of the `", #storage_name, "` storage entry.")]
+        pub fn #fn_name() -> #storage_entry_value_ty {
+            <#storage_entry_value_ty as #crate_path::ext::codec::Decode>::decode(&mut &[ #( #default_bytes, )* ][..])
+                .expect("the default was checked to decode when generating this code; qed")
+        }
+    })
+}
+
+/// Decode the SCALE encoded bytes of a value of the given primitive type into a literal, if
+/// they are a valid encoding of one. Strings and chars aren't handled.
+fn primitive_literal(primitive: &TypeDefPrimitive, bytes: &[u8]) -> Option<TokenStream2> {
+    fn decode<T: codec::Decode>(bytes: &[u8]) -> Option<T> {
+        codec::DecodeAll::decode_all(&mut &*bytes).ok()
+    }
+
+    let literal = match primitive {
+        TypeDefPrimitive::Bool => return decode::<bool>(bytes).map(|b| quote!(#b)),
+        TypeDefPrimitive::U8 => Literal::u8_unsuffixed(decode(bytes)?),
+        TypeDefPrimitive::U16 => Literal::u16_unsuffixed(decode(bytes)?),
+        TypeDefPrimitive::U32 => Literal::u32_unsuffixed(decode(bytes)?),
+        TypeDefPrimitive::U64 => Literal::u64_unsuffixed(decode(bytes)?),
+        TypeDefPrimitive::U128 => Literal::u128_unsuffixed(decode(bytes)?),
+        TypeDefPrimitive::I8 => Literal::i8_unsuffixed(decode(bytes)?),
+        TypeDefPrimitive::I16 => Literal::i16_unsuffixed(decode(bytes)?),
+        TypeDefPrimitive::I32 => Literal::i32_unsuffixed(decode(bytes)?),
+        TypeDefPrimitive::I64 => Literal::i64_unsuffixed(decode(bytes)?),
+        TypeDefPrimitive::I128 => Literal::i128_unsuffixed(decode(bytes)?),
+        TypeDefPrimitive::Str
+        | TypeDefPrimitive::Char
+        | TypeDefPrimitive::U256
+        | TypeDefPrimitive::I256 => return None,
+    };
+    Some(quote!(#literal))
+}
+
 fn generate_storage_entry_fns(
     type_gen: &TypeGenerator,
     pallet: &PalletMetadata,
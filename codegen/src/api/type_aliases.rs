@@ -0,0 +1,199 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use crate::{
+    types::{CompositeDefFieldType, TypeGenerator},
+    CratePath,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote, ToTokens};
+use std::collections::BTreeMap;
+use subxt_metadata::Metadata;
+
+/// Newtype wrappers for the integer call arguments whose type names in the metadata (for
+/// instance `BalanceOf<T>` or `T::BlockNumber`) say what they represent.
+#[derive(Debug, Default)]
+pub struct TypeAliases {
+    /// Each alias name, and the integer type it wraps. Names which are used for more than
+    /// one integer type are `None`, and aren't wrapped.
+    aliases: BTreeMap<String, Option<String>>,
+}
+
+impl TypeAliases {
+    /// Find the aliases used by the call arguments of every pallet in the metadata.
+    pub fn from_calls(metadata: &Metadata, type_gen: &TypeGenerator) -> Self {
+        let mut aliases = BTreeMap::new();
+        let fields = metadata
+            .pallets()
+            .flat_map(|pallet| pallet.call_variants().unwrap_or_default())
+            .flat_map(|variant| &variant.fields);
+
+        for field in fields {
+            let Some(name) = field.type_name.as_deref().and_then(alias_name) else {
+                continue;
+            };
+            let type_path = type_gen.resolve_type_path(field.ty.id);
+            let Some(integer) = type_path.integer_type() else {
+                continue;
+            };
+            let integer = integer.to_token_stream().to_string();
+            aliases
+                .entry(name)
+                .and_modify(|existing: &mut Option<String>| {
+                    if existing.as_ref() != Some(&integer) {
+                        *existing = None;
+                    }
+                })
+                .or_insert(Some(integer));
+        }
+
+        TypeAliases { aliases }
+    }
+
+    /// The newtype that a call argument of the given type should be taken as, if any.
+    pub fn newtype_for(&self, field: &CompositeDefFieldType) -> Option<syn::Ident> {
+        let name = field.type_name.as_deref().and_then(alias_name)?;
+        let integer = field
+            .type_path
+            .integer_type()?
+            .to_token_stream()
+            .to_string();
+        match self.aliases.get(&name)? {
+            Some(existing) if *existing == integer => Some(format_ident!("{name}")),
+            _ => None,
+        }
+    }
+
+    /// Generate a `type_aliases` module containing each newtype. Nothing is generated if
+    /// there are no aliases.
+    pub fn generate(&self, crate_path: &CratePath) -> TokenStream2 {
+        let newtypes = self
+            .aliases
+            .iter()
+            .filter_map(|(name, integer)| Some((format_ident!("{name}"), integer.as_ref()?)))
+            .map(|(name, integer)| {
+                let integer: syn::Type = syn::parse_str(integer).expect("integer type is valid");
+                let docs = format!(
+                    " A `{name}`, as named in the metadata. This is encoded in exactly \
+                     the same way as the `{}` it wraps.",
+                    integer.to_token_stream().to_string().replace(' ', "")
+                );
+                quote! {
+                    #[doc = #docs]
+                    #[derive(
+                        #crate_path::ext::codec::Encode,
+                        #crate_path::ext::codec::Decode,
+                        Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default,
+                    )]
+                    #[codec(crate = #crate_path::ext::codec)]
+                    pub struct #name(pub #integer);
+
+                    impl ::core::convert::From<#integer> for #name {
+                        fn from(value: #integer) -> Self {
+                            #name(value)
+                        }
+                    }
+
+                    impl ::core::convert::From<#name> for #integer {
+                        fn from(value: #name) -> Self {
+                            value.0
+                        }
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if newtypes.is_empty() {
+            return quote!();
+        }
+        quote! {
+            /// Newtype wrappers for call arguments which the metadata gives a more meaningful
+            /// type name than the integer they are, so that they can't be mixed up.
+            pub mod type_aliases {
+                #( #newtypes )*
+            }
+        }
+    }
+}
+
+/// Is the field an integer which the metadata names a balance (for instance `BalanceOf<T>`
+/// or `T::Balance`)?
+pub fn is_balance(field: &CompositeDefFieldType) -> bool {
+    field.type_path.integer_type().is_some()
+        && field.type_name.as_deref().and_then(alias_name).as_deref() == Some("Balance")
+}
+
+/// Work out the name of the alias from the type name given in the metadata, if it looks like
+/// one, so that `BalanceOf<T>`, `T::Balance` and `<T as Config>::Balance` are all `Balance`.
+fn alias_name(type_name: &str) -> Option<String> {
+    let mut name = type_name.trim();
+    if let Some(inner) = name
+        .strip_prefix("Compact<")
+        .and_then(|n| n.strip_suffix('>'))
+    {
+        name = inner.trim();
+    }
+
+    // Drop any generic params, and then the path leading up to the name.
+    let is_generic = name.ends_with('>');
+    if is_generic {
+        let mut depth = 0;
+        let start = name.char_indices().rev().find_map(|(idx, c)| {
+            match c {
+                '>' => depth += 1,
+                '<' => depth -= 1,
+                _ => {}
+            }
+            (depth == 0).then_some(idx)
+        })?;
+        name = &name[..start];
+    }
+    let name = name.rsplit("::").next()?.trim();
+
+    // Generic types are only aliases by convention (`BalanceOf<T>`), and not `Vec<T>` and co.
+    let stripped = ["Of", "For"]
+        .iter()
+        .find_map(|suffix| name.strip_suffix(suffix).filter(|n| !n.is_empty()));
+    if is_generic && stripped.is_none() {
+        return None;
+    }
+    let name = stripped.unwrap_or(name);
+
+    let is_alias = name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric());
+    is_alias.then(|| name.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::alias_name;
+
+    #[test]
+    fn alias_names_are_found_from_type_names() {
+        assert_eq!(alias_name("BalanceOf<T>").as_deref(), Some("Balance"));
+        assert_eq!(alias_name("BalanceOf<T, I>").as_deref(), Some("Balance"));
+        assert_eq!(alias_name("T::Balance").as_deref(), Some("Balance"));
+        assert_eq!(
+            alias_name("<T as frame_system::Config>::BlockNumber").as_deref(),
+            Some("BlockNumber")
+        );
+        assert_eq!(
+            alias_name("frame_system::pallet_prelude::BlockNumberFor<T>").as_deref(),
+            Some("BlockNumber")
+        );
+        assert_eq!(
+            alias_name("Compact<T::Balance>").as_deref(),
+            Some("Balance")
+        );
+        assert_eq!(alias_name("MemberCount").as_deref(), Some("MemberCount"));
+    }
+
+    #[test]
+    fn primitive_type_names_are_not_aliases() {
+        assert_eq!(alias_name("u128"), None);
+        assert_eq!(alias_name("Compact<u32>"), None);
+        assert_eq!(alias_name("Vec<u8>"), None);
+        assert_eq!(alias_name("BoundedVec<u8, S>"), None);
+    }
+}
@@ -18,6 +18,9 @@ pub enum CodegenError {
     /// Failed IO for the metadata file.
     #[error("Failed IO for {0}, make sure that you are providing the correct file path for metadata: {1}")]
     Io(String, std::io::Error),
+    /// Cannot decompress the metadata bytes.
+    #[error("Could not decompress metadata: {0}")]
+    Decompress(String),
     /// Cannot decode the metadata bytes.
     #[error("Could not decode metadata, only V14 metadata is supported: {0}")]
     Decode(#[from] codec::Error),
@@ -58,6 +61,12 @@ pub enum CodegenError {
         "Extrinsic call type could not be found. Make sure you are providing a valid substrate-based metadata"
     )]
     MissingCallType,
+    /// A custom RPC method was given a signature we can't generate a wrapper for.
+    #[error("Cannot generate a wrapper for the RPC method {0}: {1}")]
+    InvalidRpcMethod(String, String, Span),
+    /// A pallet alias was given which can't be generated.
+    #[error("Cannot alias the pallet {1} as {0}: {2}")]
+    InvalidPalletAlias(String, String, String),
 }
 
 impl CodegenError {
@@ -69,6 +78,7 @@ impl CodegenError {
             Self::InvalidModule(span) => *span,
             Self::TypeSubstitutionError(err) => err.get_location(),
             Self::InvalidTypePath(_, err) => err.span(),
+            Self::InvalidRpcMethod(_, _, span) => *span,
             _ => proc_macro2::Span::call_site(),
         }
     }
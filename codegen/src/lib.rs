@@ -54,8 +54,8 @@ pub mod utils;
 
 pub use self::{
     api::{
-        generate_runtime_api_from_bytes, generate_runtime_api_from_path,
-        generate_runtime_api_from_url, RuntimeGenerator,
+        generate_rpc_methods, generate_runtime_api_from_bytes, generate_runtime_api_from_path,
+        generate_runtime_api_from_url, RpcMethod, RuntimeGenerator,
     },
     error::{CodegenError, TypeSubstitutionError},
     types::{CratePath, Derives, DerivesRegistry, Module, TypeGenerator, TypeSubstitutes},
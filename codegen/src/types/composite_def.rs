@@ -32,13 +32,16 @@ impl CompositeDef {
         ty: &Type<PortableForm>,
         ident: &str,
         type_params: TypeDefParameters,
-        fields_def: CompositeDefFields,
+        mut fields_def: CompositeDefFields,
         field_visibility: Option<syn::Visibility>,
         type_gen: &TypeGenerator,
         docs: &[String],
         crate_path: &CratePath,
     ) -> Result<Self, CodegenError> {
         let mut derives = type_gen.type_derives(ty)?;
+        if derives.derives_serde() {
+            fields_def.use_serde_hex(crate_path);
+        }
         let fields: Vec<_> = fields_def.field_types().collect();
 
         if fields.len() == 1 {
@@ -206,6 +209,22 @@ impl CompositeDefFields {
         }
     }
 
+    /// Have any `Vec<u8>` or `[u8; N]` fields (de)serialize as `0x` prefixed hex strings via
+    /// serde, rather than as arrays of numbers. Only call this if serde's traits are derived.
+    pub fn use_serde_hex(&mut self, crate_path: &CratePath) {
+        let fields: Box<dyn Iterator<Item = &mut CompositeDefFieldType>> = match self {
+            Self::NoFields => return,
+            Self::Named(named_fields) => Box::new(named_fields.iter_mut().map(|(_, f)| f)),
+            Self::Unnamed(unnamed_fields) => Box::new(unnamed_fields.iter_mut()),
+        };
+        let with = quote!(#crate_path::utils::serde_hex).to_string();
+        for field in fields {
+            if field.type_path.is_byte_sequence() && !field.is_boxed() {
+                field.serde_with = Some(with.clone());
+            }
+        }
+    }
+
     /// Generate the code for fields which will compose a `struct`.
     pub fn to_struct_field_tokens(
         &self,
@@ -223,7 +242,8 @@ impl CompositeDefFields {
             Self::Named(ref fields) => {
                 let fields = fields.iter().map(|(name, ty)| {
                     let compact_attr = ty.compact_attr();
-                    quote! { #compact_attr #visibility #name: #ty }
+                    let serde_attr = ty.serde_attr();
+                    quote! { #compact_attr #serde_attr #visibility #name: #ty }
                 });
                 let marker = phantom_data.map(|phantom_data| {
                     quote!(
@@ -241,7 +261,8 @@ impl CompositeDefFields {
             Self::Unnamed(ref fields) => {
                 let fields = fields.iter().map(|ty| {
                     let compact_attr = ty.compact_attr();
-                    quote! { #compact_attr #visibility #ty }
+                    let serde_attr = ty.serde_attr();
+                    quote! { #compact_attr #serde_attr #visibility #ty }
                 });
                 let marker = phantom_data.map(|phantom_data| {
                     quote!(
@@ -266,14 +287,16 @@ impl CompositeDefFields {
             Self::Named(ref fields) => {
                 let fields = fields.iter().map(|(name, ty)| {
                     let compact_attr = ty.compact_attr();
-                    quote! { #compact_attr #name: #ty }
+                    let serde_attr = ty.serde_attr();
+                    quote! { #compact_attr #serde_attr #name: #ty }
                 });
                 quote!( { #( #fields, )* } )
             }
             Self::Unnamed(ref fields) => {
                 let fields = fields.iter().map(|ty| {
                     let compact_attr = ty.compact_attr();
-                    quote! { #compact_attr #ty }
+                    let serde_attr = ty.serde_attr();
+                    quote! { #compact_attr #serde_attr #ty }
                 });
                 quote! { ( #( #fields, )* ) }
             }
@@ -287,6 +310,8 @@ pub struct CompositeDefFieldType {
     pub type_id: u32,
     pub type_path: TypePath,
     pub type_name: Option<String>,
+    /// The module to (de)serialize this field with via `#[serde(with = "..")]`, if any.
+    pub serde_with: Option<String>,
 }
 
 impl CompositeDefFieldType {
@@ -296,6 +321,7 @@ impl CompositeDefFieldType {
             type_id,
             type_path,
             type_name,
+            serde_with: None,
         }
     }
 
@@ -305,6 +331,17 @@ impl CompositeDefFieldType {
         // Should be updated once `Box` types are no longer erased:
         // https://github.com/paritytech/scale-info/pull/82
         matches!(&self.type_name, Some(ty_name) if ty_name.contains("Box<"))
+            && self.boxed_option_type().is_none()
+    }
+
+    /// If the field is an `Option<Box<T>>`, returns the path of `T`. The metadata erases the
+    /// `Box`, so it's put back inside of the `Option` rather than around it.
+    pub fn boxed_option_type(&self) -> Option<&TypePath> {
+        let type_name = self.type_name.as_deref()?.replace(' ', "");
+        if !type_name.starts_with("Option<Box<") {
+            return None;
+        }
+        self.type_path.option_type_param()
     }
 
     /// Returns the `#[codec(compact)]` attribute if the type is compact.
@@ -313,13 +350,22 @@ impl CompositeDefFieldType {
             .is_compact()
             .then(|| quote!( #[codec(compact)] ))
     }
+
+    /// Returns the `#[serde(with = "..")]` attribute if the field should use one.
+    fn serde_attr(&self) -> Option<TokenStream> {
+        self.serde_with
+            .as_ref()
+            .map(|with| quote!( #[serde(with = #with)] ))
+    }
 }
 
 impl quote::ToTokens for CompositeDefFieldType {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let ty_path = &self.type_path;
 
-        if self.is_boxed() {
+        if let Some(inner) = self.boxed_option_type() {
+            tokens.extend(quote! { ::core::option::Option<::std::boxed::Box<#inner>> })
+        } else if self.is_boxed() {
             tokens.extend(quote! { ::std::boxed::Box<#ty_path> })
         } else {
             tokens.extend(quote! { #ty_path })
@@ -167,6 +167,30 @@ impl Derives {
         self.derives.insert(derive);
     }
 
+    /// Returns `true` if a derive (from any path) with the given name is one of the derives.
+    pub fn has_derive_named(&self, name: &str) -> bool {
+        self.derives
+            .iter()
+            .any(|derive| derive.segments.last().map_or(false, |s| s.ident == name))
+    }
+
+    /// Remove any derive (from any path) with the given name, returning `true` if there was one.
+    pub fn remove_derive_named(&mut self, name: &str) -> bool {
+        let len = self.derives.len();
+        self.derives
+            .retain(|derive| derive.segments.last().map_or(true, |s| s.ident != name));
+        self.derives.len() != len
+    }
+
+    /// Returns `true` if `Serialize` or `Deserialize` (from any path) is one of the derives.
+    pub fn derives_serde(&self) -> bool {
+        self.derives.iter().any(|derive| {
+            derive.segments.last().map_or(false, |s| {
+                s.ident == "Serialize" || s.ident == "Deserialize"
+            })
+        })
+    }
+
     /// Insert a single attribute to be applied to types.
     pub fn insert_attribute(&mut self, attribute: syn::Attribute) {
         self.attributes.insert(attribute);
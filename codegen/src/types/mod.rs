@@ -19,7 +19,7 @@ use std::collections::BTreeMap;
 use crate::error::CodegenError;
 
 pub use self::{
-    composite_def::{CompositeDef, CompositeDefFieldType, CompositeDefFields},
+    composite_def::{CompositeDef, CompositeDefFieldType, CompositeDefFields, CompositeDefKind},
     derives::{Derives, DerivesRegistry},
     substitutes::{AbsolutePath, TypeSubstitutes},
     type_def::TypeDefGen,
@@ -50,7 +50,7 @@ impl<'a> TypeGenerator<'a> {
     /// Construct a new [`TypeGenerator`].
     pub fn new(
         type_registry: &'a PortableRegistry,
-        root_mod: &'static str,
+        root_mod: &str,
         type_substitutes: TypeSubstitutes,
         derives: DerivesRegistry,
         crate_path: CratePath,
@@ -107,6 +107,11 @@ impl<'a> TypeGenerator<'a> {
         Ok(root_mod)
     }
 
+    /// The registry of type definitions being generated.
+    pub fn type_registry(&self) -> &'a PortableRegistry {
+        self.type_registry
+    }
+
     /// # Panics
     ///
     /// If no type with the given id found in the type registry.
@@ -537,6 +537,76 @@ fn generate_array_field() {
     )
 }
 
+#[test]
+fn byte_fields_serialize_as_hex_if_serde_is_derived() {
+    #[allow(unused)]
+    #[derive(TypeInfo)]
+    struct S {
+        a: [u8; 32],
+        b: Vec<u8>,
+        c: Vec<u32>,
+    }
+
+    #[allow(unused)]
+    #[derive(TypeInfo)]
+    enum E {
+        A(Vec<u8>, u8),
+    }
+
+    let mut registry = Registry::new();
+    registry.register_type(&meta_type::<S>());
+    registry.register_type(&meta_type::<E>());
+    let portable_types: PortableRegistry = registry.into();
+
+    let crate_path = "::subxt_path".into();
+    let mut derives = DerivesRegistry::with_default_derives(&crate_path);
+    derives.extend_for_all(vec![parse_quote!(serde::Serialize)], vec![]);
+
+    let type_gen = TypeGenerator::new(
+        &portable_types,
+        "root",
+        TypeSubstitutes::with_default_substitutes(&crate_path),
+        derives,
+        crate_path,
+        true,
+    );
+    let types = type_gen.generate_types_mod().expect("Valid type mod; qed");
+    let tests_mod = get_mod(&types, MOD_PATH).unwrap();
+
+    assert_eq!(
+        tests_mod.into_token_stream().to_string(),
+        quote! {
+            pub mod tests {
+                use super::root;
+                #[derive(::subxt_path::ext::codec::Decode, ::subxt_path::ext::codec::Encode, ::subxt_path::ext::scale_decode::DecodeAsType, ::subxt_path::ext::scale_encode::EncodeAsType, Debug, serde::Serialize)]
+                #[codec(crate = ::subxt_path::ext::codec)]
+                #[decode_as_type(crate_path = ":: subxt_path :: ext :: scale_decode")]
+                #[encode_as_type(crate_path = ":: subxt_path :: ext :: scale_encode")]
+                pub enum E {
+                    #[codec(index = 0)]
+                    A(
+                        #[serde(with = ":: subxt_path :: utils :: serde_hex")]
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u8,
+                    ),
+                }
+                #[derive(::subxt_path::ext::codec::Decode, ::subxt_path::ext::codec::Encode, ::subxt_path::ext::scale_decode::DecodeAsType, ::subxt_path::ext::scale_encode::EncodeAsType, Debug, serde::Serialize)]
+                #[codec(crate = ::subxt_path::ext::codec)]
+                #[decode_as_type(crate_path = ":: subxt_path :: ext :: scale_decode")]
+                #[encode_as_type(crate_path = ":: subxt_path :: ext :: scale_encode")]
+                pub struct S {
+                    #[serde(with = ":: subxt_path :: utils :: serde_hex")]
+                    pub a: [::core::primitive::u8; 32usize],
+                    #[serde(with = ":: subxt_path :: utils :: serde_hex")]
+                    pub b: ::std::vec::Vec<::core::primitive::u8>,
+                    pub c: ::std::vec::Vec<::core::primitive::u32>,
+                }
+            }
+        }
+            .to_string()
+    )
+}
+
 #[test]
 fn option_fields() {
     #[allow(unused)]
@@ -627,6 +697,52 @@ fn box_fields_struct() {
     )
 }
 
+#[test]
+fn boxed_option_fields_struct() {
+    use std::boxed::Box;
+
+    #[allow(unused)]
+    #[derive(TypeInfo)]
+    struct S {
+        a: Option<Box<bool>>,
+        b: Box<Option<u32>>,
+    }
+
+    let mut registry = Registry::new();
+    registry.register_type(&meta_type::<S>());
+    let portable_types: PortableRegistry = registry.into();
+
+    let crate_path = "::subxt_path".into();
+    let type_gen = TypeGenerator::new(
+        &portable_types,
+        "root",
+        TypeSubstitutes::with_default_substitutes(&crate_path),
+        DerivesRegistry::with_default_derives(&crate_path),
+        crate_path,
+        true,
+    );
+    let types = type_gen.generate_types_mod().expect("Valid type mod; qed");
+    let tests_mod = get_mod(&types, MOD_PATH).unwrap();
+
+    assert_eq!(
+        tests_mod.into_token_stream().to_string(),
+        quote! {
+            pub mod tests {
+                use super::root;
+                #[derive(::subxt_path::ext::codec::Decode, ::subxt_path::ext::codec::Encode, ::subxt_path::ext::scale_decode::DecodeAsType, ::subxt_path::ext::scale_encode::EncodeAsType, Debug)]
+                #[codec(crate = ::subxt_path::ext::codec)]
+                #[decode_as_type(crate_path = ":: subxt_path :: ext :: scale_decode")]
+                #[encode_as_type(crate_path = ":: subxt_path :: ext :: scale_encode")]
+                pub struct S {
+                    pub a: ::core::option::Option<::std::boxed::Box<::core::primitive::bool>>,
+                    pub b: ::std::boxed::Box<::core::option::Option<::core::primitive::u32> >,
+                }
+            }
+        }
+            .to_string()
+    )
+}
+
 #[test]
 fn box_fields_enum() {
     use std::boxed::Box;
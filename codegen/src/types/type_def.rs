@@ -88,12 +88,15 @@ impl TypeDefGen {
                     .variants
                     .iter()
                     .map(|v| {
-                        let fields = CompositeDefFields::from_scale_info_fields(
+                        let mut fields = CompositeDefFields::from_scale_info_fields(
                             &v.name,
                             &v.fields,
                             type_params.params(),
                             type_gen,
                         )?;
+                        if derives.derives_serde() {
+                            fields.use_serde_hex(crate_path);
+                        }
                         type_params.update_unused(fields.field_types());
                         let docs = should_gen_docs.then_some(&*v.docs).unwrap_or_default();
                         let variant_def = CompositeDef::enum_variant_def(&v.name, fields, docs);
@@ -79,6 +79,83 @@ impl TypePath {
         }
     }
 
+    /// Returns `true` if we know that the type this path points to implements `Default`.
+    ///
+    /// **Note:** This is conservative; generated types never derive `Default`, and so
+    /// only sequences, options and primitive-like types are considered.
+    pub(crate) fn has_default(&self) -> bool {
+        match &self.0 {
+            TypePathInner::Parameter(_) => false,
+            TypePathInner::Type(ty) => ty.has_default(),
+        }
+    }
+
+    /// Returns `true` if a function argument of this type can be taken as `impl Into<..>`
+    /// without hurting type inference; ie the type is concrete and isn't a number (for which
+    /// an integer literal would no longer infer its type from the argument).
+    pub(crate) fn can_be_impl_into(&self) -> bool {
+        let mut type_params = BTreeSet::new();
+        self.parent_type_params(&mut type_params);
+        match &self.0 {
+            TypePathInner::Parameter(_) => false,
+            TypePathInner::Type(ty) => type_params.is_empty() && ty.can_be_impl_into(),
+        }
+    }
+
+    /// Returns `true` if this is a `Vec<u8>` or a `[u8; N]`.
+    pub(crate) fn is_byte_sequence(&self) -> bool {
+        let is_u8 = |of: &TypePath| {
+            matches!(
+                &of.0,
+                TypePathInner::Type(TypePathType::Primitive {
+                    def: TypeDefPrimitive::U8
+                })
+            )
+        };
+        match &self.0 {
+            TypePathInner::Type(TypePathType::Vec { of } | TypePathType::Array { of, .. }) => {
+                is_u8(of)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if this is a generated `BoundedVec<T>` (or `WeakBoundedVec<T>`), which
+    /// wraps a `Vec<T>`. Substituted types are ignored, since we can't know their shape.
+    pub(crate) fn is_bounded_vec(&self) -> bool {
+        match &self.0 {
+            TypePathInner::Type(TypePathType::Path { path, params }) => {
+                let is_bounded_vec = path.segments.last().map_or(false, |segment| {
+                    segment.ident == "BoundedVec" || segment.ident == "WeakBoundedVec"
+                });
+                is_bounded_vec && path.leading_colon.is_none() && params.len() == 1
+            }
+            _ => false,
+        }
+    }
+
+    /// If this is an integer, or a compact integer, return the path of the integer type.
+    pub(crate) fn integer_type(&self) -> Option<&TypePath> {
+        match &self.0 {
+            TypePathInner::Type(TypePathType::Primitive { def }) => matches!(
+                def,
+                TypeDefPrimitive::U8
+                    | TypeDefPrimitive::U16
+                    | TypeDefPrimitive::U32
+                    | TypeDefPrimitive::U64
+                    | TypeDefPrimitive::U128
+                    | TypeDefPrimitive::I8
+                    | TypeDefPrimitive::I16
+                    | TypeDefPrimitive::I32
+                    | TypeDefPrimitive::I64
+                    | TypeDefPrimitive::I128
+            )
+            .then_some(self),
+            TypePathInner::Type(TypePathType::Compact { inner, .. }) => inner.integer_type(),
+            _ => None,
+        }
+    }
+
     /// Gets the vector type parameter if the data is represented as `TypeDef::Sequence`.
     ///
     /// **Note:** Utilized for transforming `std::vec::Vec<T>` into slices `&[T]` for the storage API.
@@ -93,6 +170,23 @@ impl TypePath {
             _ => None,
         }
     }
+
+    /// Gets the type parameter of an `Option<T>`.
+    pub(crate) fn option_type_param(&self) -> Option<&TypePath> {
+        match &self.0 {
+            TypePathInner::Type(TypePathType::Path { path, params }) => {
+                let is_option = path
+                    .segments
+                    .last()
+                    .map_or(false, |segment| segment.ident == "Option");
+                match params.as_slice() {
+                    [param] if is_option => Some(param),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -202,6 +296,37 @@ impl TypePathType {
         matches!(self, TypePathType::Compact { .. })
     }
 
+    fn has_default(&self) -> bool {
+        match self {
+            TypePathType::Path { path, .. } => path.segments.last().map_or(false, |segment| {
+                matches!(
+                    segment.ident.to_string().as_str(),
+                    "Option" | "Vec" | "KeyedVec"
+                )
+            }),
+            TypePathType::Vec { .. } => true,
+            TypePathType::Array { len, of } => *len <= 32 && of.has_default(),
+            TypePathType::Tuple { elements } => {
+                elements.len() <= 12 && elements.iter().all(|e| e.has_default())
+            }
+            TypePathType::Primitive { def } => {
+                !matches!(def, TypeDefPrimitive::U256 | TypeDefPrimitive::I256)
+            }
+            TypePathType::Compact {
+                inner, is_field, ..
+            } => *is_field && inner.has_default(),
+            TypePathType::BitVec { .. } => false,
+        }
+    }
+
+    fn can_be_impl_into(&self) -> bool {
+        match self {
+            TypePathType::Primitive { def } => matches!(def, TypeDefPrimitive::Str),
+            TypePathType::Compact { .. } => false,
+            _ => true,
+        }
+    }
+
     fn to_syn_type(&self) -> syn::Type {
         match &self {
             TypePathType::Path { path, params } => {
@@ -0,0 +1,63 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use crate::error::CodegenError;
+use std::borrow::Cow;
+
+/// The magic bytes at the start of a zstd frame.
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+
+/// Decompress some metadata bytes if they are zstd compressed (detected via the zstd magic
+/// bytes), returning them unchanged otherwise.
+///
+/// Decompressing zstd metadata requires the `zstd` feature; without it, an error is returned
+/// for compressed input.
+pub fn decompress_metadata_bytes(bytes: &[u8]) -> Result<Cow<'_, [u8]>, CodegenError> {
+    if !bytes.starts_with(&ZSTD_MAGIC) {
+        return Ok(Cow::Borrowed(bytes));
+    }
+
+    #[cfg(feature = "zstd")]
+    {
+        zstd::stream::decode_all(bytes)
+            .map(Cow::Owned)
+            .map_err(|e| CodegenError::Decompress(e.to_string()))
+    }
+    #[cfg(not(feature = "zstd"))]
+    {
+        Err(CodegenError::Decompress(
+            "metadata is zstd compressed; enable the `zstd` feature to support this".into(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uncompressed_bytes_are_returned_as_is() {
+        let bytes = b"meta\x0e";
+        assert_eq!(&*decompress_metadata_bytes(bytes).unwrap(), bytes);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn zstd_bytes_are_decompressed() {
+        let bytes = b"meta\x0e some metadata".to_vec();
+        let compressed = zstd::stream::encode_all(&*bytes, 0).unwrap();
+        assert!(compressed.starts_with(&ZSTD_MAGIC));
+        assert_eq!(&*decompress_metadata_bytes(&compressed).unwrap(), &*bytes);
+    }
+
+    #[cfg(not(feature = "zstd"))]
+    #[test]
+    fn zstd_bytes_need_the_feature() {
+        let compressed = [0x28, 0xB5, 0x2F, 0xFD, 0, 0];
+        assert!(matches!(
+            decompress_metadata_bytes(&compressed),
+            Err(CodegenError::Decompress(_))
+        ));
+    }
+}
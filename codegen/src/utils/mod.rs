@@ -4,11 +4,14 @@
 
 //! Utilities to help with fetching and decoding metadata.
 
+mod compression;
 mod fetch_metadata;
 
 // easy access to this type needed for fetching metadata:
 pub use jsonrpsee::client_transport::ws::Uri;
 
+pub use compression::decompress_metadata_bytes;
+
 pub use fetch_metadata::{
     fetch_metadata_bytes, fetch_metadata_bytes_blocking, fetch_metadata_hex,
     fetch_metadata_hex_blocking, MetadataVersion,
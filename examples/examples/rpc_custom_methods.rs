@@ -0,0 +1,30 @@
+use subxt::{config::PolkadotConfig, OnlineClient};
+
+// Generate typed wrappers for a couple of `author_` RPC methods alongside the usual
+// interface. Each argument is passed as a param, and the result is deserialized into
+// the return type.
+#[subxt::subxt(
+    runtime_metadata_path = "../artifacts/polkadot_metadata_small.scale",
+    rpc_method(
+        name = "author_rotateKeys",
+        signature = "fn rotate_keys() -> ::subxt::rpc::types::Bytes"
+    ),
+    rpc_method(
+        name = "author_hasSessionKeys",
+        signature = "fn has_session_keys(session_keys: ::subxt::rpc::types::Bytes) -> bool"
+    )
+)]
+pub mod polkadot {}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Create a client to use:
+    let api = OnlineClient::<PolkadotConfig>::new().await?;
+
+    // Ask the node to generate some new session keys, and then check that it has them.
+    let session_keys = polkadot::rpc::rotate_keys(api.rpc()).await?;
+    let has_keys = polkadot::rpc::has_session_keys(api.rpc(), session_keys.clone()).await?;
+
+    println!("New session keys: {session_keys:?} (stored by the node: {has_keys})");
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use sp_keyring::AccountKeyring;
+use subxt::{
+    storage::{address::Yes, StorageAddress},
+    OnlineClient, PolkadotConfig,
+};
+
+// Generate an interface that we can use from the node's metadata.
+#[subxt::subxt(runtime_metadata_path = "../artifacts/polkadot_metadata_small.scale")]
+pub mod polkadot {}
+
+// Every generated storage accessor returns something implementing `StorageAddress`, in
+// the same way that every generated call implements `TxPayload`. This lets us write
+// functions which work with any storage entry, and hand back the right type for it.
+async fn fetch_at_latest<Address>(
+    api: &OnlineClient<PolkadotConfig>,
+    address: &Address,
+) -> Result<Option<Address::Target>, subxt::Error>
+where
+    Address: StorageAddress<IsFetchable = Yes>,
+{
+    let key = api.storage().address_bytes(address)?;
+    println!(
+        "Fetching {}::{} at key 0x{}",
+        address.pallet_name(),
+        address.entry_name(),
+        hex::encode(key)
+    );
+    api.storage().at_latest().await?.fetch(address).await
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Create a new API client, configured to talk to Polkadot nodes.
+    let api = OnlineClient::<PolkadotConfig>::new().await?;
+
+    // The same function can fetch entries with entirely different keys and values.
+    let account = AccountKeyring::Alice.to_account_id().into();
+    let alice = fetch_at_latest(&api, &polkadot::storage().system().account(&account)).await?;
+    let block_number = fetch_at_latest(&api, &polkadot::storage().system().number()).await?;
+
+    println!("Alice has free balance: {}", alice.unwrap().data.free);
+    println!("Current block number: {}", block_number.unwrap());
+    Ok(())
+}
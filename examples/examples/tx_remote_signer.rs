@@ -0,0 +1,85 @@
+use futures::future::BoxFuture;
+use sp_keyring::AccountKeyring;
+use subxt::{
+    tx::{AsyncSigner, AsyncSignerFuture, PairSigner, Signer},
+    utils::{AccountId32, MultiAddress, MultiSignature},
+    OnlineClient, PolkadotConfig,
+};
+use tokio::sync::{mpsc, oneshot};
+
+#[subxt::subxt(runtime_metadata_path = "../artifacts/polkadot_metadata_small.scale")]
+pub mod polkadot {}
+
+type SignFn =
+    Box<dyn Fn(Vec<u8>) -> BoxFuture<'static, Result<MultiSignature, subxt::Error>> + Send + Sync>;
+
+/// A signer which doesn't hold any keys itself, and instead forwards the
+/// signer payload to a closure to obtain a signature.
+struct RemoteSigner {
+    account_id: AccountId32,
+    sign_fn: SignFn,
+}
+
+impl AsyncSigner<PolkadotConfig> for RemoteSigner {
+    fn account_id(&self) -> &AccountId32 {
+        &self.account_id
+    }
+
+    fn address(&self) -> MultiAddress<AccountId32, ()> {
+        self.account_id.clone().into()
+    }
+
+    fn sign<'a>(&'a self, signer_payload: &'a [u8]) -> AsyncSignerFuture<'a, PolkadotConfig> {
+        (self.sign_fn)(signer_payload.to_vec())
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let api = OnlineClient::<PolkadotConfig>::new().await?;
+
+    // Pretend that this task lives somewhere else (another process, an HSM..) and is
+    // the only thing with access to the keys. We talk to it over a channel.
+    let (tx, mut rx) = mpsc::channel::<(Vec<u8>, oneshot::Sender<MultiSignature>)>(16);
+    tokio::spawn(async move {
+        let keys: PairSigner<PolkadotConfig, _> = PairSigner::new(AccountKeyring::Alice.pair());
+        while let Some((payload, reply)) = rx.recv().await {
+            let _ = reply.send(keys.sign(&payload));
+        }
+    });
+
+    let signer = RemoteSigner {
+        account_id: AccountKeyring::Alice.to_account_id().into(),
+        sign_fn: Box::new(move |payload| {
+            let tx = tx.clone();
+            Box::pin(async move {
+                let (reply_tx, reply_rx) = oneshot::channel();
+                tx.send((payload, reply_tx))
+                    .await
+                    .map_err(|e| subxt::Error::Other(e.to_string()))?;
+                reply_rx
+                    .await
+                    .map_err(|e| subxt::Error::Other(e.to_string()))
+            })
+        }),
+    };
+
+    // Build a balance transfer extrinsic, and sign it using our remote signer.
+    let dest = AccountKeyring::Bob.to_account_id().into();
+    let balance_transfer_tx = polkadot::tx().balances().transfer(dest, 10_000);
+    let events = api
+        .tx()
+        .create_signed_async(&balance_transfer_tx, &signer, Default::default())
+        .await?
+        .submit_and_watch()
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    let transfer_event = events.find_first::<polkadot::balances::events::Transfer>()?;
+    if let Some(event) = transfer_event {
+        println!("Balance transfer success: {event:?}");
+    }
+
+    Ok(())
+}
@@ -6,10 +6,15 @@ extern crate proc_macro;
 
 use std::str::FromStr;
 
+use codec::Decode;
 use darling::{ast::NestedMeta, FromMeta};
 use proc_macro::TokenStream;
 use proc_macro_error::{abort_call_site, proc_macro_error};
-use subxt_codegen::{utils::Uri, CodegenError, DerivesRegistry, TypeSubstitutes};
+use subxt_codegen::{
+    utils::{decompress_metadata_bytes, fetch_metadata_bytes_blocking, MetadataVersion, Uri},
+    CodegenError, DerivesRegistry, RuntimeGenerator, TypeSubstitutes,
+};
+use subxt_metadata::Metadata;
 use syn::{parse_macro_input, punctuated::Punctuated};
 
 #[derive(Clone, Debug)]
@@ -37,9 +42,17 @@ struct RuntimeMetadataArgs {
     attributes_for_type: Vec<AttributesForType>,
     #[darling(multiple)]
     substitute_type: Vec<SubstituteType>,
+    #[darling(multiple)]
+    rpc_method: Vec<RpcMethodArgs>,
+    #[darling(multiple)]
+    pallet_alias: Vec<PalletAlias>,
     #[darling(default, rename = "crate")]
     crate_path: Option<String>,
     #[darling(default)]
+    types_mod_name: Option<String>,
+    #[darling(default)]
+    allow_lints: Option<darling::util::PathList>,
+    #[darling(default)]
     generate_docs: darling::util::Flag,
     #[darling(default)]
     runtime_types_only: bool,
@@ -47,6 +60,26 @@ struct RuntimeMetadataArgs {
     no_default_derives: bool,
     #[darling(default)]
     no_default_substitutions: bool,
+    #[darling(default)]
+    emit_tests: bool,
+    #[darling(default)]
+    deprecations_from_docs: bool,
+    #[darling(default)]
+    emit_submit_fns: bool,
+    #[darling(default)]
+    emit_call_info: bool,
+    #[darling(default)]
+    emit_success_events: bool,
+    #[darling(default)]
+    impl_into_args: bool,
+    #[darling(default)]
+    emit_unknown_call_variant: bool,
+    #[darling(default)]
+    emit_type_alias_newtypes: bool,
+    #[darling(default)]
+    emit_asserts: bool,
+    #[darling(default)]
+    emit_decimal_amount_fns: bool,
 }
 
 #[derive(Debug, FromMeta)]
@@ -67,6 +100,18 @@ struct SubstituteType {
     with: syn::Path,
 }
 
+#[derive(Debug, FromMeta)]
+struct RpcMethodArgs {
+    name: String,
+    signature: String,
+}
+
+#[derive(Debug, FromMeta)]
+struct PalletAlias {
+    alias: String,
+    pallet: String,
+}
+
 // Note: docs for this are in the subxt library; don't add any here as they will be appended.
 #[proc_macro_attribute]
 #[proc_macro_error]
@@ -77,7 +122,7 @@ pub fn subxt(args: TokenStream, input: TokenStream) -> TokenStream {
             return TokenStream::from(darling::Error::from(e).write_errors());
         }
     };
-    let item_mod = parse_macro_input!(input as syn::ItemMod);
+    let mut item_mod = parse_macro_input!(input as syn::ItemMod);
     let args = match RuntimeMetadataArgs::from_list(&attr_args) {
         Ok(v) => v,
         Err(e) => return TokenStream::from(e.write_errors()),
@@ -130,37 +175,51 @@ pub fn subxt(args: TokenStream, input: TokenStream) -> TokenStream {
         return CodegenError::from(err).into_compile_error().into();
     }
 
+    // Custom RPC methods don't depend on the metadata, so their wrappers are added to the
+    // module alongside any other items that it already contains.
+    if !args.rpc_method.is_empty() {
+        let rpc_methods: Result<Vec<_>, _> = args
+            .rpc_method
+            .iter()
+            .map(|method| {
+                let signature = syn::parse_str(&method.signature).map_err(|e| {
+                    CodegenError::InvalidRpcMethod(method.name.clone(), e.to_string(), e.span())
+                })?;
+                subxt_codegen::RpcMethod::new(&method.name, &signature)
+            })
+            .collect();
+        let rpc_methods = match rpc_methods {
+            Ok(rpc_methods) => rpc_methods,
+            Err(err) => return err.into_compile_error().into(),
+        };
+        let rpc_mod = subxt_codegen::generate_rpc_methods(&rpc_methods, &crate_path);
+        let Some((_, items)) = &mut item_mod.content else {
+            return syn::Error::new_spanned(
+                &item_mod,
+                "Generating `rpc_method` wrappers needs a module with a body, ie `mod polkadot {}`",
+            )
+            .into_compile_error()
+            .into();
+        };
+        items.push(syn::Item::Verbatim(rpc_mod));
+    }
+
     let should_gen_docs = args.generate_docs.is_present();
-    match (args.runtime_metadata_path, args.runtime_metadata_url) {
+    let metadata = match (args.runtime_metadata_path, args.runtime_metadata_url) {
         (Some(rest_of_path), None) => {
             let root = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".into());
             let root_path = std::path::Path::new(&root);
             let path = root_path.join(rest_of_path);
-            subxt_codegen::generate_runtime_api_from_path(
-                item_mod,
-                path,
-                derives_registry,
-                type_substitutes,
-                crate_path,
-                should_gen_docs,
-                args.runtime_types_only,
-            )
-            .map_or_else(|err| err.into_compile_error().into(), Into::into)
+            std::fs::read(&path).map_err(|err| CodegenError::Io(path.to_string_lossy().into(), err))
         }
         (None, Some(url_string)) => {
             let url = Uri::from_str(&url_string).unwrap_or_else(|_| {
                 abort_call_site!("Cannot download metadata; invalid url: {}", url_string)
             });
-            subxt_codegen::generate_runtime_api_from_url(
-                item_mod,
-                &url,
-                derives_registry,
-                type_substitutes,
-                crate_path,
-                should_gen_docs,
-                args.runtime_types_only,
-            )
-            .map_or_else(|err| err.into_compile_error().into(), Into::into)
+            // Fetch latest unstable version, if that fails fall back to the latest stable.
+            fetch_metadata_bytes_blocking(&url, MetadataVersion::Unstable)
+                .or_else(|_| fetch_metadata_bytes_blocking(&url, MetadataVersion::Latest))
+                .map_err(CodegenError::from)
         }
         (None, None) => {
             abort_call_site!(
@@ -173,4 +232,53 @@ pub fn subxt(args: TokenStream, input: TokenStream) -> TokenStream {
             )
         }
     }
+    .and_then(|bytes| {
+        let bytes = decompress_metadata_bytes(&bytes)?;
+        Ok(Metadata::decode(&mut &*bytes)?)
+    });
+    let metadata = match metadata {
+        Ok(metadata) => metadata,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    let mut generator = RuntimeGenerator::new(metadata)
+        .emit_tests(args.emit_tests)
+        .deprecations_from_docs(args.deprecations_from_docs)
+        .emit_submit_fns(args.emit_submit_fns)
+        .emit_call_info(args.emit_call_info)
+        .emit_success_events(args.emit_success_events)
+        .impl_into_args(args.impl_into_args)
+        .emit_unknown_call_variant(args.emit_unknown_call_variant)
+        .emit_type_alias_newtypes(args.emit_type_alias_newtypes)
+        .emit_asserts(args.emit_asserts)
+        .emit_decimal_amount_fns(args.emit_decimal_amount_fns)
+        .pallet_aliases(
+            args.pallet_alias
+                .into_iter()
+                .map(|alias| (alias.alias, alias.pallet)),
+        );
+    if let Some(types_mod_name) = args.types_mod_name {
+        generator = generator.types_mod_name(types_mod_name);
+    }
+    if let Some(allow_lints) = args.allow_lints {
+        generator = generator.allow_lints(allow_lints.iter().cloned());
+    }
+    let runtime_api = if args.runtime_types_only {
+        generator.generate_runtime_types(
+            item_mod,
+            derives_registry,
+            type_substitutes,
+            crate_path,
+            should_gen_docs,
+        )
+    } else {
+        generator.generate_runtime(
+            item_mod,
+            derives_registry,
+            type_substitutes,
+            crate_path,
+            should_gen_docs,
+        )
+    };
+    runtime_api.map_or_else(|err| err.into_compile_error().into(), Into::into)
 }
@@ -3,11 +3,11 @@
 // see LICENSE for license details.
 
 use crate::{
-    blocks::{extrinsic_types::ExtrinsicPartTypeIds, Extrinsics},
+    blocks::{extrinsic_types::ExtrinsicPartTypeIds, ExtrinsicDetails, Extrinsics},
     client::{OfflineClientT, OnlineClientT},
-    config::{Config, Header},
+    config::{Config, Hasher, Header},
     error::{BlockError, Error},
-    events,
+    events::{self, Phase},
     rpc::types::ChainBlockResponse,
     runtime_api::RuntimeApi,
     storage::Storage,
@@ -84,6 +84,62 @@ where
         ))
     }
 
+    /// Fetch the block body and events, and return the hash of each extrinsic in the block
+    /// alongside the events that it emitted, as well as the events emitted during block
+    /// initialization and finalization. The body and events are each fetched only once.
+    pub async fn extrinsic_events(&self) -> Result<BlockExtrinsicEvents<T>, Error> {
+        let block_hash = self.header.hash();
+        let Some(block_details) = self.client.rpc().block(Some(block_hash)).await? else {
+            return Err(BlockError::not_found(block_hash).into());
+        };
+        let events = self.events().await?;
+
+        let mut extrinsics: Vec<_> = block_details
+            .block
+            .extrinsics
+            .iter()
+            .map(|ext| (T::Hasher::hash_of(&ext.0), Vec::new()))
+            .collect();
+        let mut initialization = Vec::new();
+        let mut finalization = Vec::new();
+
+        for ev in events.iter() {
+            let ev = ev?;
+            match ev.phase() {
+                Phase::Initialization => initialization.push(ev),
+                Phase::Finalization => finalization.push(ev),
+                Phase::ApplyExtrinsic(index) => {
+                    // Every event should line up with an extrinsic in a consistent block.
+                    if let Some((_, extrinsic_events)) = extrinsics.get_mut(index as usize) {
+                        extrinsic_events.push(ev);
+                    }
+                }
+            }
+        }
+
+        Ok(BlockExtrinsicEvents {
+            extrinsics,
+            initialization,
+            finalization,
+        })
+    }
+
+    /// Fetch the block body and events, and pair each event emitted while applying an extrinsic
+    /// with that extrinsic, so that (for instance) the call which led to an event can be
+    /// decoded. The body and events are each fetched only once, but this is still heavier than
+    /// [`Self::events()`], so prefer that if the extrinsics aren't needed.
+    pub async fn events_with_extrinsics(&self) -> Result<EventsWithExtrinsics<T, C>, Error> {
+        let extrinsics = self
+            .body()
+            .await?
+            .extrinsics()
+            .iter()
+            .collect::<Result<_, _>>()?;
+        let events = self.events().await?.iter().collect::<Result<_, _>>()?;
+
+        Ok(EventsWithExtrinsics { events, extrinsics })
+    }
+
     /// Work with storage.
     pub fn storage(&self) -> Storage<T, C> {
         let block_hash = self.hash();
@@ -96,6 +152,68 @@ where
     }
 }
 
+/// The events in a block, grouped by the extrinsic that emitted them. This is obtained
+/// via [`Block::extrinsic_events()`].
+#[derive(Debug, Clone)]
+pub struct BlockExtrinsicEvents<T: Config> {
+    extrinsics: Vec<(T::Hash, Vec<events::EventDetails<T>>)>,
+    initialization: Vec<events::EventDetails<T>>,
+    finalization: Vec<events::EventDetails<T>>,
+}
+
+impl<T: Config> BlockExtrinsicEvents<T> {
+    /// The hash of each extrinsic in the block, in order, alongside the events it emitted.
+    pub fn extrinsics(&self) -> &[(T::Hash, Vec<events::EventDetails<T>>)] {
+        &self.extrinsics
+    }
+
+    /// The events emitted while initializing the block, before any extrinsics were applied.
+    pub fn initialization(&self) -> &[events::EventDetails<T>] {
+        &self.initialization
+    }
+
+    /// The events emitted while finalizing the block, after all extrinsics were applied.
+    pub fn finalization(&self) -> &[events::EventDetails<T>] {
+        &self.finalization
+    }
+}
+
+/// The events in a block, alongside the extrinsics in the block so that each event can be
+/// paired with the extrinsic that emitted it. This is obtained via
+/// [`Block::events_with_extrinsics()`].
+pub struct EventsWithExtrinsics<T: Config, C> {
+    events: Vec<events::EventDetails<T>>,
+    extrinsics: Vec<ExtrinsicDetails<T, C>>,
+}
+
+impl<T: Config, C> EventsWithExtrinsics<T, C> {
+    /// Iterate over the events in the block, in order. Each event emitted while applying an
+    /// extrinsic is handed back alongside that extrinsic; events emitted during block
+    /// initialization or finalization have no extrinsic.
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&events::EventDetails<T>, Option<&ExtrinsicDetails<T, C>>)> + '_
+    {
+        self.events.iter().map(|ev| {
+            let extrinsic = match ev.phase() {
+                Phase::ApplyExtrinsic(index) => self.extrinsics.get(index as usize),
+                Phase::Initialization | Phase::Finalization => None,
+            };
+            (ev, extrinsic)
+        })
+    }
+
+    /// The events in the block.
+    pub fn events(&self) -> &[events::EventDetails<T>] {
+        &self.events
+    }
+
+    /// The extrinsics in the block.
+    pub fn extrinsics(&self) -> &[ExtrinsicDetails<T, C>] {
+        &self.extrinsics
+    }
+}
+
 /// The body of a block.
 pub struct BlockBody<T: Config, C> {
     details: ChainBlockResponse<T>,
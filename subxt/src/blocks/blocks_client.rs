@@ -2,11 +2,12 @@
 // This file is dual-licensed as Apache-2.0 or GPL-3.0.
 // see LICENSE for license details.
 
-use super::Block;
+use super::{Block, EventsWithExtrinsics, ExtrinsicEvents};
 use crate::{
     client::OnlineClientT,
-    config::{Config, Header},
+    config::{Config, Hasher, Header},
     error::{BlockError, Error},
+    events::EventsClient,
     utils::PhantomDataSendSync,
 };
 use derivative::Derivative;
@@ -16,6 +17,9 @@ use std::{future::Future, pin::Pin};
 type BlockStream<T> = Pin<Box<dyn Stream<Item = Result<T, Error>> + Send>>;
 type BlockStreamRes<T> = Result<BlockStream<T>, Error>;
 
+/// The maximum number of blocks that [`BlocksClient::find_extrinsic()`] will search through.
+pub const MAX_FIND_EXTRINSIC_DEPTH: u32 = 256;
+
 /// A client for working with blocks.
 #[derive(Derivative)]
 #[derivative(Clone(bound = "Client: Clone"))]
@@ -88,6 +92,55 @@ where
         }
     }
 
+    /// Search backwards from the current best block through up to `search_depth` blocks (capped
+    /// at [`MAX_FIND_EXTRINSIC_DEPTH`]) for an extrinsic with the given hash, returning its events
+    /// (which also tell you the hash of the block it was found in) if it was found.
+    ///
+    /// This is useful for finding an extrinsic which was submitted without watching its progress.
+    pub fn find_extrinsic(
+        &self,
+        ext_hash: T::Hash,
+        search_depth: u32,
+    ) -> impl Future<Output = Result<Option<ExtrinsicEvents<T>>, Error>> + Send + 'static {
+        let client = self.client.clone();
+        async move {
+            let latest_hash = client
+                .rpc()
+                .block_hash(None)
+                .await?
+                .expect("didn't pass a block number; qed");
+            let latest_number: u64 = match client.rpc().header(Some(latest_hash)).await? {
+                Some(header) => header.number().into(),
+                None => return Err(BlockError::not_found(latest_hash).into()),
+            };
+
+            let search_depth = u64::from(search_depth.min(MAX_FIND_EXTRINSIC_DEPTH));
+            let oldest_number = latest_number.saturating_sub(search_depth.saturating_sub(1));
+
+            for number in (oldest_number..=latest_number).rev() {
+                let Some(block_hash) = client.rpc().block_hash(Some(number.into())).await? else {
+                    continue;
+                };
+                let Some(block) = client.rpc().block(Some(block_hash)).await? else {
+                    continue;
+                };
+                let extrinsic_idx = block
+                    .block
+                    .extrinsics
+                    .iter()
+                    .position(|ext| T::Hasher::hash_of(&ext.0) == ext_hash);
+
+                if let Some(idx) = extrinsic_idx {
+                    let events = EventsClient::new(client.clone()).at(block_hash);
+                    let events = events.await?;
+                    return Ok(Some(ExtrinsicEvents::new(ext_hash, idx as u32, events)));
+                }
+            }
+
+            Ok(None)
+        }
+    }
+
     /// Subscribe to all new blocks imported by the node.
     ///
     /// **Note:** You probably want to use [`Self::subscribe_finalized()`] most of
@@ -149,6 +202,27 @@ where
             )
         })
     }
+
+    /// Subscribe to finalized blocks, handing back the events in each block with each event
+    /// paired with the extrinsic that emitted it (see [`Block::events_with_extrinsics()`]).
+    ///
+    /// This fetches the body of every block as well as its events, so prefer
+    /// [`Self::subscribe_finalized()`] if the extrinsics aren't needed.
+    pub fn subscribe_finalized_events_with_extrinsics(
+        &self,
+    ) -> impl Future<Output = Result<BlockStream<EventsWithExtrinsics<T, Client>>, Error>> + Send + 'static
+    where
+        T: Send + Sync,
+        Client: Send + Sync + 'static,
+    {
+        let blocks = self.subscribe_finalized();
+        async move {
+            let sub = blocks
+                .await?
+                .then(|block| async move { block?.events_with_extrinsics().await });
+            BlockStreamRes::Ok(Box::pin(sub))
+        }
+    }
 }
 
 /// Take a promise that will return a subscription to some block headers,
@@ -5,7 +5,8 @@
 use crate::{
     blocks::block_types::{get_events, CachedEvents},
     client::{OfflineClientT, OnlineClientT},
-    config::{Config, Hasher},
+    config::{extrinsic_params::Era, Config, Hasher},
+    dynamic::DecodedValue,
     error::{BlockError, Error, MetadataError},
     events,
     metadata::types::PalletMetadata,
@@ -13,9 +14,9 @@ use crate::{
     Metadata,
 };
 
-use codec::Decode;
+use codec::{Compact, Decode};
 use derivative::Derivative;
-use scale_decode::DecodeAsFields;
+use scale_decode::{DecodeAsFields, DecodeAsType};
 use std::{collections::HashMap, sync::Arc};
 
 /// Trait to uniquely identify the extrinsic's identity from the runtime metadata.
@@ -121,7 +122,7 @@ where
                     index as u32,
                     extrinsics[index].0.clone().into(),
                     client.clone(),
-                    hash,
+                    Some(hash),
                     cached_events.clone(),
                     ids,
                 ) {
@@ -178,14 +179,17 @@ pub struct ExtrinsicDetails<T: Config, C> {
     address_start_idx: usize,
     /// The end index of the address in the encoded `bytes`.
     address_end_idx: usize,
+    /// The start index in the `bytes` from which the signed extensions are encoded.
+    extra_start_idx: usize,
     /// The start index in the `bytes` from which the call is encoded.
     call_start_idx: usize,
     /// The pallet index.
     pallet_index: u8,
     /// The variant index.
     variant_index: u8,
-    /// The block hash of this extrinsic (needed to fetch events).
-    block_hash: T::Hash,
+    /// The block hash of this extrinsic (needed to fetch events). Extrinsics which aren't
+    /// in a block (such as those in the transaction pool) have none.
+    block_hash: Option<T::Hash>,
     /// Subxt client.
     client: C,
     /// Cached events.
@@ -205,7 +209,7 @@ where
         index: u32,
         extrinsic_bytes: Arc<[u8]>,
         client: C,
-        block_hash: T::Hash,
+        block_hash: Option<T::Hash>,
         cached_events: CachedEvents<T>,
         ids: ExtrinsicPartTypeIds,
     ) -> Result<ExtrinsicDetails<T, C>, Error> {
@@ -233,6 +237,7 @@ where
 
         let mut address_start_idx = 0;
         let mut address_end_idx = 0;
+        let mut extra_start_idx = 0;
 
         if is_signed {
             address_start_idx = extrinsic_bytes.len() - cursor.len();
@@ -254,6 +259,7 @@ where
                 scale_decode::visitor::IgnoreVisitor,
             )
             .map_err(scale_decode::Error::from)?;
+            extra_start_idx = extrinsic_bytes.len() - cursor.len();
 
             scale_decode::visitor::decode_with_visitor(
                 cursor,
@@ -278,6 +284,7 @@ where
             is_signed,
             address_start_idx,
             address_end_idx,
+            extra_start_idx,
             call_start_idx,
             pallet_index,
             variant_index,
@@ -344,6 +351,103 @@ where
             .then(|| &self.bytes[self.address_start_idx..self.address_end_idx])
     }
 
+    /// Return only the bytes of the signature attached to this extrinsic.
+    ///
+    /// # Note
+    ///
+    /// Returns `None` if the extrinsic is not signed.
+    pub fn signature_bytes(&self) -> Option<&[u8]> {
+        self.is_signed
+            .then(|| &self.bytes[self.address_end_idx..self.extra_start_idx])
+    }
+
+    /// Return only the bytes of the signed extensions ("extra" params, like the era,
+    /// nonce and tip) attached to this extrinsic.
+    ///
+    /// # Note
+    ///
+    /// Returns `None` if the extrinsic is not signed.
+    pub fn signed_extensions_bytes(&self) -> Option<&[u8]> {
+        self.is_signed
+            .then(|| &self.bytes[self.extra_start_idx..self.call_start_idx])
+    }
+
+    /// Return the signed extensions ("extra" params, like the era, nonce and tip) attached
+    /// to this extrinsic, which can be decoded individually using the list of signed
+    /// extensions in the metadata.
+    ///
+    /// # Note
+    ///
+    /// Returns `None` if the extrinsic is not signed.
+    pub fn signed_extensions(&self) -> Option<ExtrinsicSignedExtensions<'_>> {
+        let bytes = self.signed_extensions_bytes()?;
+        Some(ExtrinsicSignedExtensions {
+            bytes,
+            metadata: &self.metadata,
+        })
+    }
+
+    /// Return the payload that was signed to produce the signature on this extrinsic.
+    ///
+    /// The "additional" params (such as the spec version, transaction version, genesis hash
+    /// and, for mortal transactions, the block hash that the era is anchored to) are not part
+    /// of the extrinsic itself, and so need to be provided. These can be obtained via
+    /// [`crate::config::ExtrinsicParams::encode_additional_to()`].
+    ///
+    /// # Note
+    ///
+    /// Returns `None` if the extrinsic is not signed.
+    pub fn signer_payload(&self, additional: &[u8]) -> Option<Vec<u8>> {
+        let extra = self.signed_extensions_bytes()?;
+
+        let mut bytes = self.call_bytes().to_vec();
+        bytes.extend_from_slice(extra);
+        bytes.extend_from_slice(additional);
+
+        // Payloads longer than 256 bytes are hashed before they are signed.
+        if bytes.len() > 256 {
+            Some(sp_core_hashing::blake2_256(&bytes).to_vec())
+        } else {
+            Some(bytes)
+        }
+    }
+
+    /// Verify the signature attached to this extrinsic against the signer payload (see
+    /// [`Self::signer_payload()`] for details on the `additional` bytes). Returns `Ok(false)`
+    /// if the extrinsic is unsigned or the signature does not match.
+    ///
+    /// This expects the address to be a `MultiAddress::Id` (or a bare `AccountId32`) and the
+    /// signature to be a `MultiSignature`, as is the case for Substrate and Polkadot.
+    #[cfg(feature = "substrate-compat")]
+    pub fn verify_signature(&self, additional: &[u8]) -> Result<bool, Error> {
+        use sp_core::crypto::AccountId32;
+        use sp_runtime::{traits::Verify, MultiSignature};
+
+        let (Some(address), Some(signature), Some(payload)) = (
+            self.address_bytes(),
+            self.signature_bytes(),
+            self.signer_payload(additional),
+        ) else {
+            return Ok(false);
+        };
+
+        let account_id = match address {
+            // `MultiAddress::Id` is the first variant.
+            [0, account_id @ ..] if account_id.len() == 32 => {
+                AccountId32::decode(&mut &*account_id)?
+            }
+            account_id if account_id.len() == 32 => AccountId32::decode(&mut &*account_id)?,
+            _ => {
+                return Err(Error::Other(
+                    "Only MultiAddress::Id and AccountId32 addresses can be verified".into(),
+                ))
+            }
+        };
+        let signature = MultiSignature::decode(&mut &*signature)?;
+
+        Ok(signature.verify(&*payload, &account_id))
+    }
+
     /// The index of the pallet that the extrinsic originated from.
     pub fn pallet_index(&self) -> u8 {
         self.pallet_index
@@ -434,13 +538,143 @@ where
     C: OnlineClientT<T>,
 {
     /// The events associated with the extrinsic.
+    ///
+    /// Returns [`BlockError::NotInBlock`] if the extrinsic isn't in a block, and so has no
+    /// events (for instance if it was obtained from the transaction pool).
     pub async fn events(&self) -> Result<ExtrinsicEvents<T>, Error> {
-        let events = get_events(&self.client, self.block_hash, &self.cached_events).await?;
+        let block_hash = self.block_hash.ok_or(BlockError::NotInBlock)?;
+        let events = get_events(&self.client, block_hash, &self.cached_events).await?;
         let ext_hash = T::Hasher::hash_of(&self.bytes);
         Ok(ExtrinsicEvents::new(ext_hash, self.index, events))
     }
 }
 
+/// The signed extensions of an extrinsic, obtained via [`ExtrinsicDetails::signed_extensions()`].
+#[derive(Debug, Clone)]
+pub struct ExtrinsicSignedExtensions<'a> {
+    bytes: &'a [u8],
+    metadata: &'a Metadata,
+}
+
+impl<'a> ExtrinsicSignedExtensions<'a> {
+    /// Return the bytes of all of the signed extensions.
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Iterate over each of the signed extensions, in the order given in the metadata.
+    pub fn iter(&self) -> impl Iterator<Item = Result<ExtrinsicSignedExtension<'a>, Error>> {
+        let metadata = self.metadata;
+        let mut cursor = self.bytes;
+        let mut failed = false;
+
+        metadata
+            .extrinsic()
+            .signed_extensions()
+            .iter()
+            .map_while(move |extension| {
+                if failed {
+                    return None;
+                }
+                let ty_id = extension.extra_ty();
+                let start = cursor;
+                let res = scale_decode::visitor::decode_with_visitor(
+                    &mut cursor,
+                    ty_id,
+                    metadata.types(),
+                    scale_decode::visitor::IgnoreVisitor,
+                )
+                .map_err(scale_decode::Error::from);
+
+                Some(match res {
+                    Ok(()) => Ok(ExtrinsicSignedExtension {
+                        identifier: extension.identifier(),
+                        ty_id,
+                        bytes: &start[..start.len() - cursor.len()],
+                        metadata,
+                    }),
+                    Err(e) => {
+                        failed = true;
+                        Err(e.into())
+                    }
+                })
+            })
+    }
+
+    /// Find the signed extension with the given identifier, for instance `"CheckNonce"`.
+    pub fn find(&self, identifier: &str) -> Result<Option<ExtrinsicSignedExtension<'a>>, Error> {
+        for extension in self.iter() {
+            let extension = extension?;
+            if extension.identifier() == identifier {
+                return Ok(Some(extension));
+            }
+        }
+        Ok(None)
+    }
+
+    /// The tip given, as found in the `ChargeTransactionPayment` or `ChargeAssetTxPayment`
+    /// signed extension. Returns `None` if neither are present.
+    pub fn tip(&self) -> Result<Option<u128>, Error> {
+        let extension = match self.find("ChargeTransactionPayment")? {
+            Some(extension) => Some(extension),
+            None => self.find("ChargeAssetTxPayment")?,
+        };
+        // The tip is the first thing encoded in both cases.
+        extension
+            .map(|e| Ok(Compact::<u128>::decode(&mut e.bytes())?.0))
+            .transpose()
+    }
+
+    /// The account nonce, as found in the `CheckNonce` signed extension. Returns `None`
+    /// if that isn't present.
+    pub fn nonce(&self) -> Result<Option<u64>, Error> {
+        self.find("CheckNonce")?
+            .map(|e| Ok(Compact::<u64>::decode(&mut e.bytes())?.0))
+            .transpose()
+    }
+
+    /// The era, as found in the `CheckMortality` signed extension. Returns `None` if
+    /// that isn't present.
+    pub fn era(&self) -> Result<Option<Era>, Error> {
+        self.find("CheckMortality")?
+            .map(|e| Ok(Era::decode(&mut e.bytes())?))
+            .transpose()
+    }
+}
+
+/// A single signed extension of an extrinsic.
+#[derive(Debug, Clone)]
+pub struct ExtrinsicSignedExtension<'a> {
+    identifier: &'a str,
+    ty_id: u32,
+    bytes: &'a [u8],
+    metadata: &'a Metadata,
+}
+
+impl<'a> ExtrinsicSignedExtension<'a> {
+    /// The identifier of this signed extension, as given in the metadata.
+    pub fn identifier(&self) -> &'a str {
+        self.identifier
+    }
+
+    /// The type ID of this signed extension's "extra" data.
+    pub fn type_id(&self) -> u32 {
+        self.ty_id
+    }
+
+    /// The bytes of this signed extension's "extra" data.
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Decode this signed extension's "extra" data into a dynamic [`DecodedValue`].
+    pub fn value(&self) -> Result<DecodedValue, Error> {
+        let value =
+            DecodedValue::decode_as_type(&mut &*self.bytes, self.ty_id, self.metadata.types())?;
+        Ok(value)
+    }
+}
+
 /// Details for the given extrinsic plucked from the metadata.
 pub struct ExtrinsicMetadataDetails<'a> {
     pub pallet: PalletMetadata<'a>,
@@ -614,7 +848,10 @@ mod tests {
     use assert_matches::assert_matches;
     use codec::{Decode, Encode};
     use frame_metadata::{
-        v15::{ExtrinsicMetadata, PalletCallMetadata, PalletMetadata, RuntimeMetadataV15},
+        v15::{
+            ExtrinsicMetadata, PalletCallMetadata, PalletMetadata, RuntimeMetadataV15,
+            SignedExtensionMetadata,
+        },
         RuntimeMetadataPrefixed,
     };
     use primitive_types::H256;
@@ -717,6 +954,19 @@ mod tests {
 
     /// Build fake metadata consisting the types needed to represent an extrinsic.
     fn metadata() -> Metadata {
+        metadata_with_extrinsic_type(meta_type::<ExtrinsicType<(), RuntimeCall, (), ()>>())
+    }
+
+    /// Build fake metadata, where extrinsics are described by the given type.
+    fn metadata_with_extrinsic_type(extrinsic_ty: scale_info::MetaType) -> Metadata {
+        metadata_with_extrinsic_type_and_extensions(extrinsic_ty, vec![])
+    }
+
+    /// Build fake metadata with the given extrinsic type and signed extensions.
+    fn metadata_with_extrinsic_type_and_extensions(
+        extrinsic_ty: scale_info::MetaType,
+        signed_extensions: Vec<SignedExtensionMetadata>,
+    ) -> Metadata {
         let pallets = vec![PalletMetadata {
             name: "Test",
             storage: None,
@@ -731,9 +981,9 @@ mod tests {
         }];
 
         let extrinsic = ExtrinsicMetadata {
-            ty: meta_type::<ExtrinsicType<(), RuntimeCall, (), ()>>(),
+            ty: extrinsic_ty,
             version: 4,
-            signed_extensions: vec![],
+            signed_extensions,
         };
 
         let meta = RuntimeMetadataV15::new(pallets, extrinsic, meta_type::<()>(), vec![]);
@@ -779,7 +1029,7 @@ mod tests {
             1,
             vec![].into(),
             client,
-            H256::random(),
+            Some(H256::random()),
             Default::default(),
             ids,
         );
@@ -797,7 +1047,7 @@ mod tests {
             1,
             3u8.encode().into(),
             client,
-            H256::random(),
+            Some(H256::random()),
             Default::default(),
             ids,
         );
@@ -836,7 +1086,7 @@ mod tests {
             1,
             tx_encoded.encoded()[1..].into(),
             client,
-            H256::random(),
+            Some(H256::random()),
             Default::default(),
             ids,
         )
@@ -891,4 +1141,141 @@ mod tests {
             }
         );
     }
+
+    #[cfg(feature = "substrate-compat")]
+    #[test]
+    fn verify_extrinsic_signature() {
+        use sp_core::Pair as _;
+        use sp_runtime::{MultiAddress, MultiSignature};
+
+        type Address = MultiAddress<sp_core::crypto::AccountId32, ()>;
+        let metadata = metadata_with_extrinsic_type(meta_type::<
+            ExtrinsicType<Address, RuntimeCall, MultiSignature, u8>,
+        >());
+        let client = client(metadata.clone());
+        let ids = ExtrinsicPartTypeIds::new(&metadata).unwrap();
+
+        let pair = sp_keyring::AccountKeyring::Alice.pair();
+        let address = Address::Id(pair.public().into());
+        let call = RuntimeCall::Test(Pallet::TestCall {
+            value: 10,
+            signed: true,
+            name: "SomeValue".into(),
+        });
+        let extra = 7u8;
+        let additional = (1u32, 4u32, H256::random()).encode();
+
+        let mut payload = call.encode();
+        extra.encode_to(&mut payload);
+        payload.extend_from_slice(&additional);
+        let signature = MultiSignature::from(pair.sign(&payload));
+
+        let mut tx_bytes = vec![0b1000_0000 + 4];
+        address.encode_to(&mut tx_bytes);
+        signature.encode_to(&mut tx_bytes);
+        extra.encode_to(&mut tx_bytes);
+        call.encode_to(&mut tx_bytes);
+
+        let decode = |bytes: Vec<u8>| {
+            ExtrinsicDetails::decode_from(
+                1,
+                bytes.into(),
+                client.clone(),
+                Some(H256::random()),
+                Default::default(),
+                ids,
+            )
+            .expect("Valid extrinsic")
+        };
+
+        let extrinsic = decode(tx_bytes.clone());
+        assert!(extrinsic.is_signed());
+        assert_eq!(extrinsic.address_bytes(), Some(&*address.encode()));
+        assert_eq!(extrinsic.signature_bytes(), Some(&*signature.encode()));
+        assert_eq!(extrinsic.signed_extensions_bytes(), Some(&[7u8][..]));
+        assert_eq!(extrinsic.signer_payload(&additional), Some(payload));
+        assert!(extrinsic.verify_signature(&additional).unwrap());
+
+        // Different "additional" params (ie a different chain) won't verify.
+        let other_additional = (2u32, 4u32, H256::random()).encode();
+        assert!(!extrinsic.verify_signature(&other_additional).unwrap());
+
+        // Tamper with the call data (the last byte of the name).
+        let mut tampered_bytes = tx_bytes;
+        *tampered_bytes.last_mut().unwrap() ^= 1;
+        let tampered = decode(tampered_bytes);
+        assert!(!tampered.verify_signature(&additional).unwrap());
+    }
+
+    #[cfg(feature = "substrate-compat")]
+    #[test]
+    fn decode_signed_extensions() {
+        use codec::Compact;
+        use sp_runtime::generic::Era as SpEra;
+
+        type Extra = (SpEra, Compact<u32>, Compact<u128>);
+        let signed_extensions = vec![
+            SignedExtensionMetadata {
+                identifier: "CheckMortality",
+                ty: meta_type::<SpEra>(),
+                additional_signed: meta_type::<H256>(),
+            },
+            SignedExtensionMetadata {
+                identifier: "CheckNonce",
+                ty: meta_type::<Compact<u32>>(),
+                additional_signed: meta_type::<()>(),
+            },
+            SignedExtensionMetadata {
+                identifier: "ChargeTransactionPayment",
+                ty: meta_type::<Compact<u128>>(),
+                additional_signed: meta_type::<()>(),
+            },
+        ];
+        let metadata = metadata_with_extrinsic_type_and_extensions(
+            meta_type::<ExtrinsicType<u8, RuntimeCall, u8, Extra>>(),
+            signed_extensions,
+        );
+        let client = client(metadata.clone());
+        let ids = ExtrinsicPartTypeIds::new(&metadata).unwrap();
+
+        let call = RuntimeCall::Test(Pallet::TestCall {
+            value: 10,
+            signed: true,
+            name: "SomeValue".into(),
+        });
+        let extra: Extra = (SpEra::mortal(64, 1000), Compact(5), Compact(1234));
+
+        let mut tx_bytes = vec![0b1000_0000 + 4];
+        1u8.encode_to(&mut tx_bytes);
+        2u8.encode_to(&mut tx_bytes);
+        extra.encode_to(&mut tx_bytes);
+        call.encode_to(&mut tx_bytes);
+
+        let extrinsic = ExtrinsicDetails::decode_from(
+            1,
+            tx_bytes.into(),
+            client,
+            Some(H256::random()),
+            Default::default(),
+            ids,
+        )
+        .expect("Valid extrinsic");
+
+        let extensions = extrinsic.signed_extensions().expect("extrinsic is signed");
+        assert_eq!(extensions.bytes(), &*extra.encode());
+
+        let identifiers: Vec<_> = extensions.iter().map(|e| e.unwrap().identifier()).collect();
+        assert_eq!(
+            identifiers,
+            vec!["CheckMortality", "CheckNonce", "ChargeTransactionPayment"]
+        );
+
+        assert_eq!(extensions.tip().unwrap(), Some(1234));
+        assert_eq!(extensions.nonce().unwrap(), Some(5));
+        assert_eq!(
+            extensions.era().unwrap(),
+            Some(crate::config::extrinsic_params::Era::mortal(64, 1000))
+        );
+        assert!(extensions.find("CheckWeight").unwrap().is_none());
+    }
 }
@@ -8,8 +8,13 @@ mod block_types;
 mod blocks_client;
 mod extrinsic_types;
 
-pub use block_types::{Block, BlockBody};
-pub use blocks_client::{subscribe_to_block_headers_filling_in_gaps, BlocksClient};
+pub use block_types::{Block, BlockBody, BlockExtrinsicEvents, EventsWithExtrinsics};
+pub use blocks_client::{
+    subscribe_to_block_headers_filling_in_gaps, BlocksClient, MAX_FIND_EXTRINSIC_DEPTH,
+};
 pub use extrinsic_types::{
-    ExtrinsicDetails, ExtrinsicEvents, Extrinsics, RootExtrinsic, StaticExtrinsic,
+    ExtrinsicDetails, ExtrinsicEvents, ExtrinsicSignedExtension, ExtrinsicSignedExtensions,
+    Extrinsics, RootExtrinsic, StaticExtrinsic,
 };
+
+pub(crate) use extrinsic_types::ExtrinsicPartTypeIds;
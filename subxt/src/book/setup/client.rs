@@ -31,6 +31,27 @@
 //! allows you to decide how Subxt will attempt to talk to a node if you'd prefer something other
 //! than the provided interfaces.
 //!
+//! ### Connecting through a proxy
+//!
+//! The default transport used by [`crate::OnlineClient::from_url()`] opens a direct WebSocket
+//! connection to the node. If outbound connections must go through a SOCKS5 or HTTP `CONNECT`
+//! proxy, enable the `proxy` feature and hand a `ProxyConfig` to `ClientBuilder::proxy()`:
+//!
+//! ```rust,ignore
+//! use subxt::client::{ClientBuilder, ProxyConfig};
+//! use subxt::PolkadotConfig;
+//!
+//! let client = ClientBuilder::<PolkadotConfig>::new()
+//!     .proxy(ProxyConfig::socks5("127.0.0.1:1080").with_credentials("user", "pass"))
+//!     .build("wss://rpc.polkadot.io:443")
+//!     .await?;
+//! ```
+//!
+//! For any other kind of proxy, establish the proxied connection yourself and wrap it in an
+//! [`crate::rpc::RpcClientT`] implementation (as in the custom RPC example below), and then hand
+//! it to [`crate::OnlineClient::from_rpc_client()`]. Everything else in Subxt works the same
+//! regardless of how the underlying connection was made.
+//!
 //! ## Examples
 //!
 //! Defining some custom config based off the default Substrate config:
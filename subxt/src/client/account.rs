@@ -0,0 +1,70 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! Convenience methods for reading common storage entries without needing
+//! to navigate the generated (or dynamic) storage APIs.
+
+use super::OnlineClient;
+use crate::{config::Config, dynamic::Value, error::Error, storage::address::Yes};
+use codec::Encode;
+
+/// The `System::Account` storage entry of an account, as returned from
+/// [`OnlineClient::account_info()`].
+///
+/// This is decoded by field name, so any other fields that a runtime stores alongside these
+/// are ignored, and the integer types needn't exactly match those of the runtime.
+#[derive(Clone, Debug, Default, PartialEq, Eq, scale_decode::DecodeAsType)]
+pub struct AccountInfo {
+    /// The number of transactions this account has sent.
+    pub nonce: u64,
+    /// The number of other modules that currently depend on this account's existence.
+    pub consumers: u32,
+    /// The number of other modules that allow this account to exist.
+    pub providers: u32,
+    /// The number of modules that allow this account to exist for their own purposes only.
+    pub sufficients: u32,
+    /// The balances of the account.
+    pub data: AccountData,
+}
+
+/// The balances of an account, as stored in the `data` field of its [`AccountInfo`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, scale_decode::DecodeAsType)]
+pub struct AccountData {
+    /// The balance which can be transferred or used to pay fees.
+    pub free: u128,
+    /// The balance which is reserved, and so can't be used until it is unreserved.
+    pub reserved: u128,
+}
+
+impl<T: Config> OnlineClient<T>
+where
+    T::AccountId: Encode,
+{
+    /// Fetch the `System::Account` storage entry for the given account at the latest block.
+    /// This contains the nonce, reference counts and balance information for the account.
+    ///
+    /// Returns an error if the chain has no `System::Account` storage entry, or if the entry
+    /// doesn't have the fields of an [`AccountInfo`].
+    pub async fn account_info(&self, account_id: &T::AccountId) -> Result<AccountInfo, Error> {
+        let address = crate::storage::Address::<Value, AccountInfo, Yes, Yes, ()>::new(
+            "System",
+            "Account",
+            vec![Value::from_bytes(account_id.encode())],
+        );
+        self.storage()
+            .at_latest()
+            .await?
+            .fetch_or_default(&address)
+            .await
+    }
+
+    /// Fetch the free balance of the given account at the latest block. This is read from
+    /// the `data.free` field of the `System::Account` storage entry.
+    ///
+    /// Returns an error if the chain has no `System::Account` storage entry, or if the
+    /// account data does not contain a free balance.
+    pub async fn free_balance(&self, account_id: &T::AccountId) -> Result<u128, Error> {
+        Ok(self.account_info(account_id).await?.data.free)
+    }
+}
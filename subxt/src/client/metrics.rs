@@ -0,0 +1,54 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use crate::error::DispatchError;
+use std::time::Duration;
+
+/// A hook which is called at RPC and transaction submission boundaries, so that metrics can be
+/// handed to whichever metrics library you like. Configure a client with one via
+/// [`crate::OnlineClient::with_metrics_recorder()`].
+///
+/// Every method does nothing by default, so only the ones of interest need implementing.
+pub trait MetricsRecorder: Send + Sync + 'static {
+    /// Called once an RPC request (or the request which starts a subscription) completes, with
+    /// the method name and whether it succeeded. `duration` is `None` on targets without a
+    /// monotonic clock (ie wasm32).
+    fn record_rpc_request(&self, _method: &str, _duration: Option<Duration>, _success: bool) {}
+
+    /// Called once an extrinsic has been submitted (however it was created), with the pallet
+    /// and call name (where they can be decoded) and whether the node accepted it.
+    fn record_submission(&self, _pallet: Option<&str>, _call: Option<&str>, _accepted: bool) {}
+
+    /// Called once the outcome of an extrinsic in a block has been checked (for instance by
+    /// [`crate::tx::TxInBlock::wait_for_success()`]), with the error if it failed to dispatch.
+    fn record_extrinsic_outcome(&self, _error: Option<&DispatchError>) {}
+}
+
+/// A [`MetricsRecorder`] which does nothing. This is the default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopMetricsRecorder;
+
+impl MetricsRecorder for NoopMetricsRecorder {}
+
+/// Measures how long something takes, where the target has a clock to do so.
+pub(crate) struct Stopwatch {
+    #[cfg(not(target_arch = "wasm32"))]
+    started: std::time::Instant,
+}
+
+impl Stopwatch {
+    pub(crate) fn start() -> Self {
+        Stopwatch {
+            #[cfg(not(target_arch = "wasm32"))]
+            started: std::time::Instant::now(),
+        }
+    }
+
+    pub(crate) fn elapsed(&self) -> Option<Duration> {
+        #[cfg(not(target_arch = "wasm32"))]
+        return Some(self.started.elapsed());
+        #[cfg(target_arch = "wasm32")]
+        return None;
+    }
+}
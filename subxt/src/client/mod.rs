@@ -8,9 +8,18 @@
 //! require network access. The [`OnlineClient`] requires network
 //! access.
 
+#[cfg(feature = "prelude")]
+mod account;
+mod metrics;
 mod offline_client;
 mod online_client;
+#[cfg(feature = "proxy")]
+mod proxy;
 
+#[cfg(feature = "prelude")]
+pub use account::{AccountData, AccountInfo};
+pub(crate) use metrics::Stopwatch;
+pub use metrics::{MetricsRecorder, NoopMetricsRecorder};
 pub use offline_client::{OfflineClient, OfflineClientT};
 pub use online_client::{
     ClientRuntimeUpdater, OnlineClient, OnlineClientT, RuntimeUpdaterStream, Update, UpgradeError,
@@ -20,4 +29,7 @@ pub use online_client::{
     feature = "jsonrpsee-ws",
     all(feature = "jsonrpsee-web", target_arch = "wasm32")
 ))]
-pub use online_client::default_rpc_client;
+pub use online_client::{default_rpc_client, ClientBuilder};
+
+#[cfg(feature = "proxy")]
+pub use proxy::{ProxyConfig, ProxyError, ProxyKind};
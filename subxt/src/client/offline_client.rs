@@ -4,8 +4,8 @@
 
 use crate::{
     blocks::BlocksClient, constants::ConstantsClient, events::EventsClient,
-    rpc::types::RuntimeVersion, runtime_api::RuntimeApiClient, storage::StorageClient,
-    tx::TxClient, Config, Metadata,
+    metadata::types::SignedExtensionMetadata, rpc::types::RuntimeVersion,
+    runtime_api::RuntimeApiClient, storage::StorageClient, tx::TxClient, Config, Metadata,
 };
 use derivative::Derivative;
 use std::sync::Arc;
@@ -20,6 +20,15 @@ pub trait OfflineClientT<T: Config>: Clone + Send + Sync + 'static {
     /// Return the provided [`RuntimeVersion`].
     fn runtime_version(&self) -> RuntimeVersion;
 
+    /// The signed extensions that the runtime expects signed extrinsics to have, in the order
+    /// that they appear. Each has an identifier, the type of the "extra" data sent along with
+    /// the extrinsic, and the type of the "additional" data which is only signed over; look the
+    /// types up in [`crate::metadata::types::Metadata::types()`]. If these don't line up with what the
+    /// [`Config::ExtrinsicParams`] encode, then signatures will be rejected as bad.
+    fn signed_extensions(&self) -> Vec<SignedExtensionMetadata> {
+        self.metadata().extrinsic().signed_extensions().to_vec()
+    }
+
     /// Work with transactions.
     fn tx(&self) -> TxClient<T, Self> {
         TxClient::new(self.clone())
@@ -102,6 +111,12 @@ impl<T: Config> OfflineClient<T> {
     // Just a copy of the most important trait methods so that people
     // don't need to import the trait for most things:
 
+    /// The signed extensions that the runtime expects signed extrinsics to have. See
+    /// [`OfflineClientT::signed_extensions()`].
+    pub fn signed_extensions(&self) -> Vec<SignedExtensionMetadata> {
+        <Self as OfflineClientT<T>>::signed_extensions(self)
+    }
+
     /// Work with transactions.
     pub fn tx(&self) -> TxClient<T, Self> {
         <Self as OfflineClientT<T>>::tx(self)
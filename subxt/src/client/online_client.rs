@@ -2,14 +2,18 @@
 // This file is dual-licensed as Apache-2.0 or GPL-3.0.
 // see LICENSE for license details.
 
-use super::{OfflineClient, OfflineClientT};
+use super::{MetricsRecorder, OfflineClient, OfflineClientT};
 use crate::{
-    blocks::BlocksClient,
+    blocks::{BlocksClient, ExtrinsicDetails, ExtrinsicPartTypeIds},
     constants::ConstantsClient,
-    error::Error,
-    events::EventsClient,
+    error::{ClientInitError, Error, RpcError},
+    events::{EventDetails, EventsClient},
+    metadata::{types::SignedExtensionMetadata, DecodeLimits},
     rpc::{
-        types::{RuntimeVersion, Subscription},
+        types::{
+            ChainProperties, ExtrinsicOrHash, GrandpaJustification, Health, NetworkState, PeerInfo,
+            RuntimeVersion, Subscription, TraceBlockResponse, GRANDPA_ENGINE_ID,
+        },
         Rpc, RpcClientT,
     },
     runtime_api::RuntimeApiClient,
@@ -17,9 +21,16 @@ use crate::{
     tx::TxClient,
     Config, Metadata,
 };
+use codec::Decode;
 use derivative::Derivative;
 use futures::future;
-use std::sync::{Arc, RwLock};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+/// The block number type of the headers of a chain.
+type HeaderNumber<T> = <<T as Config>::Header as crate::config::Header>::Number;
 
 /// A trait representing a client that can perform
 /// online actions.
@@ -30,6 +41,11 @@ pub trait OnlineClientT<T: Config>: OfflineClientT<T> {
 
 /// A client that can be used to perform API calls (that is, either those
 /// requiring an [`OfflineClientT`] or those requiring an [`OnlineClientT`]).
+///
+/// Cloning the client is cheap; there's no need to wrap it in an `Arc`. Clones share the same
+/// connection to the node, and the same metadata and runtime version, so updating these (for
+/// instance via [`Self::set_metadata()`] or a [`ClientRuntimeUpdater`]) is seen by every clone.
+/// Clones can be moved into spawned tasks.
 #[derive(Derivative)]
 #[derivative(Clone(bound = ""))]
 pub struct OnlineClient<T: Config> {
@@ -43,6 +59,11 @@ struct Inner<T: Config> {
     genesis_hash: T::Hash,
     runtime_version: RuntimeVersion,
     metadata: Metadata,
+    /// The limits applied to any metadata that the client fetches or decodes itself.
+    decode_limits: DecodeLimits,
+    chain_properties: Option<ChainProperties>,
+    /// Metadata for past runtimes, keyed by spec version.
+    historical_metadata: HashMap<u32, Metadata>,
 }
 
 impl<T: Config> std::fmt::Debug for OnlineClient<T> {
@@ -60,9 +81,9 @@ impl<T: Config> std::fmt::Debug for OnlineClient<T> {
     all(feature = "jsonrpsee-web", target_arch = "wasm32")
 ))]
 pub async fn default_rpc_client<U: AsRef<str>>(url: U) -> Result<impl RpcClientT, Error> {
-    let client = jsonrpsee_helpers::client(url.as_ref())
+    let client = jsonrpsee_helpers::client(url.as_ref(), None)
         .await
-        .map_err(|e| crate::error::RpcError::ClientError(Box::new(e)))?;
+        .map_err(|e| ClientInitError::Transport(RpcError::ClientError(Box::new(e))))?;
     Ok(client)
 }
 
@@ -84,23 +105,137 @@ impl<T: Config> OnlineClient<T> {
         let client = default_rpc_client(url).await?;
         OnlineClient::from_rpc_client(Arc::new(client)).await
     }
+
+    /// Construct a new [`OnlineClient`], providing a URL to connect to and a
+    /// [`ClientBuilder`] to configure the connection with. This is the same as
+    /// calling [`ClientBuilder::build()`].
+    pub async fn from_url_with_config(
+        url: impl AsRef<str>,
+        config: ClientBuilder<T>,
+    ) -> Result<OnlineClient<T>, Error> {
+        config.build(url).await
+    }
+}
+
+/// A builder to configure the connection of an [`OnlineClient`] which uses the default RPC
+/// client. Use [`OnlineClient::from_url()`] if the defaults are fine.
+#[cfg(any(
+    feature = "jsonrpsee-ws",
+    all(feature = "jsonrpsee-web", target_arch = "wasm32")
+))]
+#[derive(Derivative)]
+#[derivative(Debug(bound = ""), Clone(bound = ""), Default(bound = ""))]
+pub struct ClientBuilder<T> {
+    keepalive: Option<std::time::Duration>,
+    decode_limits: Option<DecodeLimits>,
+    #[cfg(feature = "proxy")]
+    proxy: Option<super::ProxyConfig>,
+    _marker: std::marker::PhantomData<T>,
+}
+
+#[cfg(any(
+    feature = "jsonrpsee-ws",
+    all(feature = "jsonrpsee-web", target_arch = "wasm32")
+))]
+impl<T: Config> ClientBuilder<T> {
+    /// Create a new [`ClientBuilder`] with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send a WebSocket ping frame whenever the connection has been idle for the given
+    /// interval, so that proxies and load balancers which close silent connections keep
+    /// it open. Off by default.
+    ///
+    /// Choose an interval comfortably below the idle timeout of anything between you and the
+    /// node; for instance a load balancer which drops connections after 60 seconds of silence
+    /// is kept happy by an interval of 30 seconds. Pings are tiny, but very short intervals
+    /// are wasteful.
+    ///
+    /// Browsers don't let pings be sent, and so this has no effect with the `jsonrpsee-web`
+    /// feature.
+    pub fn keepalive(mut self, interval: std::time::Duration) -> Self {
+        self.keepalive = Some(interval);
+        self
+    }
+
+    /// Check event and storage value bytes against the given limits before decoding them,
+    /// rather than against [`DecodeLimits::default()`]. See [`OnlineClient::set_decode_limits()`].
+    pub fn decode_limits(mut self, decode_limits: DecodeLimits) -> Self {
+        self.decode_limits = Some(decode_limits);
+        self
+    }
+
+    /// Connect to the node through the given SOCKS5 or HTTP `CONNECT` proxy, rather than
+    /// directly. Both `ws://` and `wss://` URLs can be connected to this way; the TLS
+    /// connection for the latter is established with the node, through the proxy.
+    #[cfg(feature = "proxy")]
+    pub fn proxy(mut self, proxy: super::ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Connect to the node at the given URL, and construct an [`OnlineClient`].
+    pub async fn build(self, url: impl AsRef<str>) -> Result<OnlineClient<T>, Error> {
+        #[cfg(feature = "proxy")]
+        if let Some(proxy) = &self.proxy {
+            let client = super::proxy::client(url.as_ref(), proxy, self.keepalive)
+                .await
+                .map_err(|e| ClientInitError::Transport(RpcError::ClientError(Box::new(e))))?;
+            return self.finish(client).await;
+        }
+
+        let client = jsonrpsee_helpers::client(url.as_ref(), self.keepalive)
+            .await
+            .map_err(|e| ClientInitError::Transport(RpcError::ClientError(Box::new(e))))?;
+        self.finish(client).await
+    }
+
+    async fn finish(self, client: impl RpcClientT) -> Result<OnlineClient<T>, Error> {
+        let client = OnlineClient::from_rpc_client(Arc::new(client)).await?;
+        if let Some(decode_limits) = self.decode_limits {
+            client.set_decode_limits(decode_limits);
+        }
+        Ok(client)
+    }
 }
 
 impl<T: Config> OnlineClient<T> {
     /// Construct a new [`OnlineClient`] by providing an underlying [`RpcClientT`]
     /// implementation to drive the connection.
+    ///
+    /// The genesis hash, runtime version and metadata are all requested at once rather than
+    /// one after the other, so this takes roughly one round trip to the node rather than three.
+    ///
+    /// Failures are reported as [`Error::ClientInit`], saying which of the details needed to
+    /// construct the client could not be fetched. We give up as soon as any of them fails.
     pub async fn from_rpc_client<R: RpcClientT>(
         rpc_client: Arc<R>,
     ) -> Result<OnlineClient<T>, Error> {
         let rpc = Rpc::<T>::new(rpc_client.clone());
-        let (genesis_hash, runtime_version, metadata) = future::join3(
-            rpc.genesis_hash(),
-            rpc.runtime_version(None),
-            OnlineClient::fetch_metadata(&rpc),
+        let (genesis_hash, runtime_version, metadata) = future::try_join3(
+            async {
+                rpc.genesis_hash()
+                    .await
+                    .map_err(|e| ClientInitError::GenesisHash(Box::new(e)))
+            },
+            async {
+                rpc.runtime_version(None)
+                    .await
+                    .map_err(|e| ClientInitError::RuntimeVersion(Box::new(e)))
+            },
+            async {
+                OnlineClient::fetch_metadata(&rpc)
+                    .await
+                    .map_err(|e| match e {
+                        Error::Codec(e) => ClientInitError::MetadataDecoding(e),
+                        e => ClientInitError::Metadata(Box::new(e)),
+                    })
+            },
         )
-        .await;
+        .await?;
 
-        OnlineClient::from_rpc_client_with(genesis_hash?, runtime_version?, metadata?, rpc_client)
+        OnlineClient::from_rpc_client_with(genesis_hash, runtime_version, metadata, rpc_client)
     }
 
     /// Construct a new [`OnlineClient`] by providing all of the underlying details needed
@@ -121,11 +256,15 @@ impl<T: Config> OnlineClient<T> {
         metadata: impl Into<Metadata>,
         rpc_client: Arc<R>,
     ) -> Result<OnlineClient<T>, Error> {
+        let metadata = metadata.into();
         Ok(OnlineClient {
             inner: Arc::new(RwLock::new(Inner {
                 genesis_hash,
                 runtime_version,
-                metadata: metadata.into(),
+                decode_limits: metadata.decode_limits(),
+                metadata,
+                chain_properties: None,
+                historical_metadata: HashMap::new(),
             })),
             rpc: Rpc::new(rpc_client),
         })
@@ -205,12 +344,53 @@ impl<T: Config> OnlineClient<T> {
     ///
     /// Setting custom metadata may leave Subxt unable to work with certain blocks,
     /// subscribe to latest blocks or submit valid transactions.
+    ///
+    /// The metadata given is used as is, along with its own [`Metadata::decode_limits()`].
     pub fn set_metadata(&self, metadata: impl Into<Metadata>) {
         let mut inner = self.inner.write().expect("shouldn't be poisoned");
         inner.metadata = metadata.into();
     }
 
-    /// Return the genesis hash.
+    /// Decode the given SCALE encoded metadata (as handed back by `state_getMetadata`, in
+    /// V14 or V15 format) and swap it in for the [`Metadata`] used by this client and all of
+    /// its clones, without touching the underlying connection. If the bytes can't be decoded,
+    /// an error is returned and the current metadata is left alone. The decoded metadata is
+    /// given the limits set via [`Self::set_decode_limits()`].
+    ///
+    /// # Warning
+    ///
+    /// As with [`Self::set_metadata()`], metadata which doesn't match the runtime of the node
+    /// may leave Subxt unable to work with certain blocks or submit valid transactions.
+    pub fn replace_metadata(&self, bytes: &[u8]) -> Result<(), Error> {
+        let metadata = Metadata::decode(&mut &*bytes)?;
+        let mut inner = self.inner.write().expect("shouldn't be poisoned");
+        inner.metadata = metadata.with_decode_limits(inner.decode_limits);
+        Ok(())
+    }
+
+    /// Return the [`DecodeLimits`] that event and storage value bytes are checked against
+    /// before they are decoded.
+    pub fn decode_limits(&self) -> DecodeLimits {
+        let inner = self.inner.read().expect("shouldn't be poisoned");
+        inner.decode_limits
+    }
+
+    /// Change the [`DecodeLimits`] that event and storage value bytes are checked against
+    /// before they are decoded. These apply to the current metadata, and are kept for any
+    /// metadata fetched by this client or its clones later on, for instance when a runtime
+    /// upgrade is applied via [`Self::updater()`] or metadata is swapped in via
+    /// [`Self::replace_metadata()`].
+    pub fn set_decode_limits(&self, decode_limits: DecodeLimits) {
+        let mut inner = self.inner.write().expect("shouldn't be poisoned");
+        inner.decode_limits = decode_limits;
+        inner.metadata = inner.metadata.clone().with_decode_limits(decode_limits);
+        for metadata in inner.historical_metadata.values_mut() {
+            *metadata = metadata.clone().with_decode_limits(decode_limits);
+        }
+    }
+
+    /// Return the genesis hash. This is fetched once when the client is
+    /// constructed, and so is cheap to call (no RPC request is made).
     pub fn genesis_hash(&self) -> T::Hash {
         let inner = self.inner.read().expect("shouldn't be poisoned");
         inner.genesis_hash
@@ -227,12 +407,20 @@ impl<T: Config> OnlineClient<T> {
         inner.genesis_hash = genesis_hash;
     }
 
-    /// Return the runtime version.
+    /// Return the runtime version. This is fetched when the client is constructed
+    /// and kept up to date if runtime updates are applied via [`Self::updater()`].
     pub fn runtime_version(&self) -> RuntimeVersion {
         let inner = self.inner.read().expect("shouldn't be poisoned");
         inner.runtime_version.clone()
     }
 
+    /// Return the transaction version from the cached [`RuntimeVersion`]. This is
+    /// what gets signed over, alongside the spec version, when creating extrinsics.
+    pub fn transaction_version(&self) -> u32 {
+        let inner = self.inner.read().expect("shouldn't be poisoned");
+        inner.runtime_version.transaction_version
+    }
+
     /// Change the [`RuntimeVersion`] used in this client.
     ///
     /// # Warning
@@ -244,11 +432,321 @@ impl<T: Config> OnlineClient<T> {
         inner.runtime_version = runtime_version;
     }
 
+    /// Report metrics about RPC requests and transaction submissions made via this client
+    /// (and any clones of it made afterwards) to the given [`MetricsRecorder`].
+    pub fn with_metrics_recorder(mut self, recorder: impl MetricsRecorder) -> Self {
+        self.rpc = self.rpc.with_metrics_recorder(Arc::new(recorder));
+        self
+    }
+
     /// Return an RPC client to make raw requests with.
     pub fn rpc(&self) -> &Rpc<T> {
         &self.rpc
     }
 
+    /// Check that the node is reachable by calling `system_health`, returning its peer count
+    /// and whether it is syncing. See [`Self::ensure_synced()`] to treat syncing as an error.
+    pub async fn ping(&self) -> Result<Health, Error> {
+        self.rpc.system_health().await
+    }
+
+    /// Like [`Self::ping()`], but returns [`crate::error::RpcError::NodeSyncing`] if the node
+    /// responded but is still syncing, so that callers can delay any operations until it has
+    /// caught up.
+    pub async fn ensure_synced(&self) -> Result<Health, Error> {
+        let health = self.ping().await?;
+        if health.is_syncing {
+            return Err(RpcError::NodeSyncing.into());
+        }
+        Ok(health)
+    }
+
+    /// Return `true` if the node responds to RPC requests. Like [`Self::ping()`], this
+    /// does not care whether the node is syncing.
+    pub async fn is_connected(&self) -> bool {
+        self.rpc.system_health().await.is_ok()
+    }
+
+    /// Fetch the hash of the latest finalized block once via `chain_getFinalizedHead`, which can
+    /// be handed to things like [`crate::storage::StorageClient::at()`] in order to read finalized
+    /// state without needing to subscribe to finalized blocks.
+    ///
+    /// Returns [`crate::error::RpcError::MethodNotSupported`] if the node doesn't support this.
+    pub async fn finalized_head(&self) -> Result<T::Hash, Error> {
+        const METHOD: &str = "chain_getFinalizedHead";
+        match self.rpc.finalized_head().await {
+            Ok(hash) => Ok(hash),
+            Err(e) => Err(self.method_not_supported_or(METHOD, e).await),
+        }
+    }
+
+    /// Fetch the header of the block with the given hash via `chain_getHeader`, or `None` if
+    /// the node doesn't know about the block. With [`crate::config::substrate::SubstrateHeader`],
+    /// the digest logs can then be inspected; for instance
+    /// [`crate::config::substrate::Digest::babe_pre_digest()`] says who authored the block.
+    pub async fn header(&self, block_hash: T::Hash) -> Result<Option<T::Header>, Error> {
+        self.rpc.header(Some(block_hash)).await
+    }
+
+    /// Fetch the next nonce to sign a transaction from the given account with. This accounts
+    /// for transactions which are still in the transaction pool; see
+    /// [`TxClient::account_nonce()`].
+    pub async fn account_nonce(&self, account_id: &T::AccountId) -> Result<T::Index, Error> {
+        self.tx().account_nonce(account_id).await
+    }
+
+    /// Fetch and decode the `System::Events` of the current best block. Each event is decoded
+    /// up front, and hands back the [`crate::events::Phase`] it was emitted in along with its
+    /// fields. This is shorthand for `client.events().at_latest()` followed by collecting
+    /// every event from [`crate::events::Events::iter()`].
+    pub async fn latest_events(&self) -> Result<Vec<EventDetails<T>>, Error> {
+        let events = self.events().at_latest().await?;
+        events.iter().collect()
+    }
+
+    /// Like [`Self::latest_events()`], but fetch and decode the events of the block with the
+    /// given hash.
+    pub async fn events_at(&self, block_hash: T::Hash) -> Result<Vec<EventDetails<T>>, Error> {
+        let events = self.events().at(block_hash).await?;
+        events.iter().collect()
+    }
+
+    /// Fetch and decode the GRANDPA justification of the block with the given hash, which proves
+    /// that it was finalized (for instance to a bridge on another chain). The justification is
+    /// taken from the block returned by `chain_getBlock`, so the node needs to have the block.
+    ///
+    /// Nodes only keep justifications for some blocks: those which enact an authority set
+    /// change, and every so often, as configured on the node. `None` is returned if the block
+    /// has no GRANDPA justification (or doesn't exist). To follow the state of the ongoing
+    /// voting rounds, use [`crate::rpc::Rpc::grandpa_round_state()`] (`grandpa_roundState`).
+    pub async fn grandpa_justification(
+        &self,
+        block_hash: T::Hash,
+    ) -> Result<Option<GrandpaJustification<T::Hash, HeaderNumber<T>, T::Header>>, Error>
+    where
+        T::Header: Decode,
+        HeaderNumber<T>: Decode,
+    {
+        let Some(block) = self.rpc.block(Some(block_hash)).await? else {
+            return Ok(None);
+        };
+        let Some((_, justification)) = block
+            .justifications
+            .unwrap_or_default()
+            .into_iter()
+            .find(|(engine_id, _)| *engine_id == GRANDPA_ENGINE_ID)
+        else {
+            return Ok(None);
+        };
+        Ok(Some(Decode::decode(&mut &*justification)?))
+    }
+
+    /// Fetch the encoded extrinsics which are currently sitting in the node's transaction pool
+    /// via `author_pendingExtrinsics`. Use [`Self::pending_extrinsic_details()`] to decode them.
+    ///
+    /// Returns [`crate::error::RpcError::MethodNotSupported`] if the node doesn't support this
+    /// (nodes commonly disable it when running with `--rpc-methods safe`).
+    pub async fn pending_extrinsics(&self) -> Result<Vec<Vec<u8>>, Error> {
+        const METHOD: &str = "author_pendingExtrinsics";
+        match self.rpc.pending_extrinsics().await {
+            Ok(extrinsics) => Ok(extrinsics.into_iter().map(|ext| ext.0).collect()),
+            Err(e) => Err(self.method_not_supported_or(METHOD, e).await),
+        }
+    }
+
+    /// Like [`Self::pending_extrinsics()`], but decode each extrinsic using the current metadata,
+    /// so that the calls and signed extensions in the pool can be inspected. The index of each
+    /// extrinsic is its position in the pool.
+    ///
+    /// Pending extrinsics aren't in a block and so have no events; this is why the details
+    /// are handed back with an [`OfflineClient`], and have no block hash.
+    pub async fn pending_extrinsic_details(
+        &self,
+    ) -> Result<Vec<ExtrinsicDetails<T, OfflineClient<T>>>, Error> {
+        let extrinsics = self.pending_extrinsics().await?;
+        let client = self.offline();
+        let ids = ExtrinsicPartTypeIds::new(&client.metadata())?;
+        extrinsics
+            .into_iter()
+            .enumerate()
+            .map(|(idx, bytes)| {
+                ExtrinsicDetails::decode_from(
+                    idx as u32,
+                    bytes.into(),
+                    client.clone(),
+                    None,
+                    Default::default(),
+                    ids,
+                )
+            })
+            .collect()
+    }
+
+    /// Remove a stuck extrinsic, given either its hash or its bytes, from the node's transaction
+    /// pool via the unsafe `author_removeExtrinsic` method. The node also bans the extrinsic from
+    /// re-entering the pool for a while. Returns the hashes of the extrinsics that were removed,
+    /// which is empty if the extrinsic wasn't in the pool.
+    ///
+    /// Returns [`crate::error::RpcError::UnsafeMethodNotAllowed`] if the node doesn't allow
+    /// unsafe methods to be called over this connection.
+    pub async fn remove_extrinsic_unsafe(
+        &self,
+        extrinsic: ExtrinsicOrHash<T::Hash>,
+    ) -> Result<Vec<T::Hash>, Error> {
+        const METHOD: &str = "author_removeExtrinsic";
+        match self.rpc.remove_extrinsic(vec![extrinsic]).await {
+            Ok(hashes) => Ok(hashes),
+            Err(e) => Err(self.unsafe_method_error(METHOD, e).await),
+        }
+    }
+
+    /// Re-execute the given block and record what happened while doing so via the unsafe
+    /// `state_traceBlock` method, which is useful for working out why a call behaved the way
+    /// it did. See [`crate::rpc::Rpc::trace_block()`] for the meaning of the arguments.
+    ///
+    /// This is a heavy call, and the node must allow unsafe methods over this connection
+    /// (`--rpc-methods unsafe`), still have the state of the block's parent (so run it with
+    /// `--pruning archive` to trace older blocks), and have been built with the `with-tracing`
+    /// feature to record spans from within the runtime. Returns
+    /// [`crate::error::RpcError::UnsafeMethodNotAllowed`] if unsafe methods aren't allowed.
+    pub async fn trace_block_unsafe(
+        &self,
+        block_hash: T::Hash,
+        targets: Option<&str>,
+        storage_keys: Option<&str>,
+    ) -> Result<TraceBlockResponse, Error> {
+        const METHOD: &str = "state_traceBlock";
+        let trace = self.rpc.trace_block(block_hash, targets, storage_keys);
+        match trace.await {
+            Ok(trace) => Ok(trace),
+            Err(e) => Err(self.unsafe_method_error(METHOD, e).await),
+        }
+    }
+
+    /// Fetch the peer ID, roles and best block of each peer that the node is connected to, via
+    /// the unsafe `system_peers` method.
+    ///
+    /// Returns [`crate::error::RpcError::UnsafeMethodNotAllowed`] if the node doesn't allow
+    /// unsafe methods to be called over this connection.
+    pub async fn peers_unsafe(&self) -> Result<Vec<PeerInfo<T::Hash>>, Error> {
+        const METHOD: &str = "system_peers";
+        match self.rpc.system_peers().await {
+            Ok(peers) => Ok(peers),
+            Err(e) => Err(self.unsafe_method_error(METHOD, e).await),
+        }
+    }
+
+    /// Fetch the state of the node's networking, via the unsafe `system_networkState` method.
+    ///
+    /// Returns [`crate::error::RpcError::UnsafeMethodNotAllowed`] if the node doesn't allow
+    /// unsafe methods to be called over this connection.
+    pub async fn network_state_unsafe(&self) -> Result<NetworkState, Error> {
+        const METHOD: &str = "system_networkState";
+        match self.rpc.system_network_state().await {
+            Ok(state) => Ok(state),
+            Err(e) => Err(self.unsafe_method_error(METHOD, e).await),
+        }
+    }
+
+    /// Given an error from calling some unsafe RPC method, hand back
+    /// [`RpcError::UnsafeMethodNotAllowed`] if the node refused to run it, or otherwise see
+    /// [`Self::method_not_supported_or()`].
+    async fn unsafe_method_error(&self, method: &str, e: Error) -> Error {
+        match e {
+            Error::Rpc(RpcError::ClientError(e)) if is_unsafe_method_rejection(&*e) => {
+                RpcError::UnsafeMethodNotAllowed(method.to_owned()).into()
+            }
+            e => self.method_not_supported_or(method, e).await,
+        }
+    }
+
+    /// Given an error from calling some RPC method, check whether the node lists the method
+    /// at all, handing back [`RpcError::MethodNotSupported`] if not, or the original error if so.
+    async fn method_not_supported_or(&self, method: &str, e: Error) -> Error {
+        match self.rpc.rpc_methods().await {
+            Ok(methods) if !methods.iter().any(|m| m == method) => {
+                RpcError::MethodNotSupported(method.to_owned()).into()
+            }
+            _ => e,
+        }
+    }
+
+    /// Fetch the SS58 network prefix that the node advertises via `system_properties`, for use
+    /// with [`crate::utils::AccountId32::to_ss58check_with_prefix()`]. Returns `None` if the node
+    /// doesn't advertise a valid one.
+    pub async fn ss58_prefix(&self) -> Result<Option<u16>, Error> {
+        Ok(self.properties().await?.ss58_format)
+    }
+
+    /// Fetch the token decimals, token symbols and SS58 prefix of the chain via
+    /// `system_properties`. The result is cached after it has first been fetched.
+    pub async fn properties(&self) -> Result<ChainProperties, Error> {
+        let cached = self
+            .inner
+            .read()
+            .expect("shouldn't be poisoned")
+            .chain_properties
+            .clone();
+        if let Some(properties) = cached {
+            return Ok(properties);
+        }
+
+        let system_properties = self.rpc.system_properties().await?;
+        let properties = ChainProperties::from_system_properties(&system_properties);
+
+        let mut inner = self.inner.write().expect("shouldn't be poisoned");
+        inner.chain_properties = Some(properties.clone());
+        Ok(properties)
+    }
+
+    /// Return an offline client configured with the runtime version and metadata in use as of
+    /// the given block, so that (for instance) constants can be read as they were at that block.
+    ///
+    /// The metadata for each runtime version seen is cached, so it is fetched at most once.
+    /// Returns [`Error::HistoricalMetadata`] if it can't be fetched.
+    pub async fn offline_at(&self, block_hash: T::Hash) -> Result<OfflineClient<T>, Error> {
+        let to_err = |e| Error::HistoricalMetadata(format!("{block_hash:?}"), Box::new(e));
+        let runtime_version = self
+            .rpc
+            .runtime_version(Some(block_hash))
+            .await
+            .map_err(to_err)?;
+
+        let cached = {
+            let inner = self.inner.read().expect("shouldn't be poisoned");
+            if inner.runtime_version.spec_version == runtime_version.spec_version {
+                Some(inner.metadata.clone())
+            } else {
+                inner
+                    .historical_metadata
+                    .get(&runtime_version.spec_version)
+                    .cloned()
+            }
+        };
+        let metadata = match cached {
+            Some(metadata) => metadata,
+            None => {
+                let metadata = self
+                    .rpc
+                    .metadata_at_block(block_hash)
+                    .await
+                    .map_err(to_err)?;
+                let mut inner = self.inner.write().expect("shouldn't be poisoned");
+                let metadata = metadata.with_decode_limits(inner.decode_limits);
+                inner
+                    .historical_metadata
+                    .insert(runtime_version.spec_version, metadata.clone());
+                metadata
+            }
+        };
+
+        Ok(OfflineClient::new(
+            self.genesis_hash(),
+            runtime_version,
+            metadata,
+        ))
+    }
+
     /// Return an offline client with the same configuration as this.
     pub fn offline(&self) -> OfflineClient<T> {
         let inner = self.inner.read().expect("shouldn't be poisoned");
@@ -262,6 +760,12 @@ impl<T: Config> OnlineClient<T> {
     // Just a copy of the most important trait methods so that people
     // don't need to import the trait for most things:
 
+    /// The signed extensions that the runtime expects signed extrinsics to have. See
+    /// [`OfflineClientT::signed_extensions()`].
+    pub fn signed_extensions(&self) -> Vec<SignedExtensionMetadata> {
+        <Self as OfflineClientT<T>>::signed_extensions(self)
+    }
+
     /// Work with transactions.
     pub fn tx(&self) -> TxClient<T, Self> {
         <Self as OfflineClientT<T>>::tx(self)
@@ -323,7 +827,7 @@ impl<T: Config> ClientRuntimeUpdater<T> {
 
     fn do_update(&self, update: Update) {
         let mut writable = self.0.inner.write().expect("shouldn't be poisoned");
-        writable.metadata = update.metadata;
+        writable.metadata = update.metadata.with_decode_limits(writable.decode_limits);
         writable.runtime_version = update.runtime_version;
     }
 
@@ -427,6 +931,25 @@ impl Update {
     }
 }
 
+/// Is this error from the RPC client the node refusing to run an unsafe method? Only errors
+/// from the default (`jsonrpsee`) client carry the JSON-RPC error code needed to tell.
+fn is_unsafe_method_rejection(e: &(dyn std::error::Error + 'static)) -> bool {
+    #[cfg(feature = "jsonrpsee")]
+    {
+        use jsonrpsee::{core::Error, types::error::CallError};
+
+        // Substrate nodes reject calls to disallowed unsafe methods with this error.
+        const CODE: i32 = -32601;
+        const MESSAGE: &str = "RPC call is unsafe to be called externally";
+        if let Some(Error::Call(CallError::Custom(e))) = e.downcast_ref() {
+            return e.code() == CODE && e.message() == MESSAGE;
+        }
+    }
+    #[cfg(not(feature = "jsonrpsee"))]
+    let _ = e;
+    false
+}
+
 // helpers for a jsonrpsee specific OnlineClient.
 #[cfg(feature = "jsonrpsee-ws")]
 mod jsonrpsee_helpers {
@@ -437,13 +960,17 @@ mod jsonrpsee_helpers {
             Error,
         },
     };
+    use std::time::Duration;
 
-    /// Build WS RPC client from URL
-    pub async fn client(url: &str) -> Result<Client, Error> {
+    /// Build WS RPC client from URL, optionally pinging the node whenever the connection
+    /// has been idle for the given interval.
+    pub async fn client(url: &str, keepalive: Option<Duration>) -> Result<Client, Error> {
         let (sender, receiver) = ws_transport(url).await?;
-        Ok(ClientBuilder::default()
-            .max_notifs_per_subscription(4096)
-            .build_with_tokio(sender, receiver))
+        let mut builder = ClientBuilder::default().max_notifs_per_subscription(4096);
+        if let Some(interval) = keepalive {
+            builder = builder.ping_interval(interval);
+        }
+        Ok(builder.build_with_tokio(sender, receiver))
     }
 
     async fn ws_transport(url: &str) -> Result<(Sender, Receiver), Error> {
@@ -467,12 +994,47 @@ mod jsonrpsee_helpers {
             Error,
         },
     };
+    use std::time::Duration;
 
-    /// Build web RPC client from URL
-    pub async fn client(url: &str) -> Result<Client, Error> {
+    /// Build web RPC client from URL. Browsers don't allow pings to be sent, and so the
+    /// keepalive interval is ignored.
+    pub async fn client(url: &str, _keepalive: Option<Duration>) -> Result<Client, Error> {
         let (sender, receiver) = web::connect(url).await.unwrap();
         Ok(ClientBuilder::default()
             .max_notifs_per_subscription(4096)
             .build_with_wasm(sender, receiver))
     }
 }
+
+#[cfg(all(test, feature = "jsonrpsee"))]
+mod test {
+    use super::is_unsafe_method_rejection;
+    use jsonrpsee::{
+        core::Error,
+        types::error::{CallError, ErrorObject},
+    };
+
+    fn call_error(code: i32, message: &str) -> Error {
+        Error::Call(CallError::Custom(ErrorObject::owned(
+            code, message, None::<()>,
+        )))
+    }
+
+    #[test]
+    fn unsafe_method_rejections_are_recognised() {
+        let e = call_error(-32601, "RPC call is unsafe to be called externally");
+        assert!(is_unsafe_method_rejection(&e));
+    }
+
+    #[test]
+    fn other_errors_mentioning_unsafe_are_not_rejections() {
+        let e = call_error(-32602, "RPC call is unsafe to be called externally");
+        assert!(!is_unsafe_method_rejection(&e));
+        let e = call_error(-32601, "Method not found");
+        assert!(!is_unsafe_method_rejection(&e));
+        let e = call_error(1010, "Invalid Transaction: unsafe nonce");
+        assert!(!is_unsafe_method_rejection(&e));
+        let e: Box<dyn std::error::Error> = "RPC call is unsafe to be called externally".into();
+        assert!(!is_unsafe_method_rejection(&*e));
+    }
+}
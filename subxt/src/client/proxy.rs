@@ -0,0 +1,646 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! Connecting to a node through a SOCKS5 or HTTP `CONNECT` proxy. See
+//! [`super::ClientBuilder::proxy()`].
+
+use base64::Engine;
+use futures::io::{BufReader, BufWriter};
+use jsonrpsee::{
+    client_transport::ws::{InvalidUri, Uri},
+    core::{
+        async_trait,
+        client::{Client, ClientBuilder, ReceivedMessage, TransportReceiverT, TransportSenderT},
+    },
+};
+use soketto::{
+    connection,
+    data::ByteSlice125,
+    handshake::client::{Client as WsHandshakeClient, ServerResponse},
+    Data, Incoming,
+};
+use std::{net::IpAddr, sync::Arc, time::Duration};
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    net::TcpStream,
+};
+use tokio_util::compat::{Compat, TokioAsyncReadCompatExt};
+
+/// How long we'll wait to connect to the proxy, and for it to connect to the node.
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(10);
+/// The largest message we'll accept from the node; the same as for direct connections.
+const MAX_MESSAGE_SIZE: usize = 10 * 1024 * 1024;
+/// The longest response head that we'll accept from an HTTP proxy.
+const MAX_RESPONSE_HEAD_SIZE: usize = 8 * 1024;
+
+/// The kind of proxy to connect through.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProxyKind {
+    /// A SOCKS5 proxy. The node address is handed to the proxy to resolve.
+    Socks5,
+    /// An HTTP proxy which supports the `CONNECT` method.
+    HttpConnect,
+}
+
+/// A proxy to tunnel connections to the node through. Hand this to
+/// [`super::ClientBuilder::proxy()`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use subxt::client::{ClientBuilder, ProxyConfig};
+/// use subxt::PolkadotConfig;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), subxt::Error> {
+/// let proxy = ProxyConfig::socks5("127.0.0.1:1080").with_credentials("user", "pass");
+/// let client = ClientBuilder::<PolkadotConfig>::new()
+///     .proxy(proxy)
+///     .build("wss://rpc.polkadot.io:443")
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct ProxyConfig {
+    kind: ProxyKind,
+    addr: String,
+    credentials: Option<(String, String)>,
+}
+
+impl ProxyConfig {
+    /// Connect through the SOCKS5 proxy listening at the given address, for
+    /// example `"127.0.0.1:1080"`.
+    pub fn socks5(addr: impl Into<String>) -> Self {
+        ProxyConfig {
+            kind: ProxyKind::Socks5,
+            addr: addr.into(),
+            credentials: None,
+        }
+    }
+
+    /// Connect through the HTTP proxy listening at the given address, for
+    /// example `"127.0.0.1:3128"`, using the `CONNECT` method.
+    pub fn http_connect(addr: impl Into<String>) -> Self {
+        ProxyConfig {
+            kind: ProxyKind::HttpConnect,
+            addr: addr.into(),
+            credentials: None,
+        }
+    }
+
+    /// Authenticate with the proxy. SOCKS5 proxies are sent these using username and
+    /// password authentication, and HTTP proxies in a `Proxy-Authorization` header
+    /// using basic authentication.
+    pub fn with_credentials(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.credentials = Some((username.into(), password.into()));
+        self
+    }
+
+    /// The kind of proxy to connect through.
+    pub fn kind(&self) -> ProxyKind {
+        self.kind
+    }
+
+    /// The address that the proxy is listening at.
+    pub fn addr(&self) -> &str {
+        &self.addr
+    }
+}
+
+// Don't print passwords into logs.
+impl std::fmt::Debug for ProxyConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProxyConfig")
+            .field("kind", &self.kind)
+            .field("addr", &self.addr)
+            .field(
+                "username",
+                &self.credentials.as_ref().map(|(username, _)| username),
+            )
+            .finish()
+    }
+}
+
+/// An error connecting to a node through a proxy.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ProxyError {
+    /// The URL of the node can't be connected to.
+    #[error("Invalid URL: {0}")]
+    Url(String),
+    /// An IO error talking to the proxy, or to the node through it.
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    /// Connecting took too long.
+    #[error("Timed out after {0:?} connecting through the proxy")]
+    Timeout(Duration),
+    /// The SOCKS5 proxy refused to connect us to the node.
+    #[error("SOCKS5 proxy error: {0}")]
+    Socks5(String),
+    /// The HTTP proxy refused to connect us to the node.
+    #[error("HTTP proxy error: {0}")]
+    HttpConnect(String),
+    /// The TLS connection to the node couldn't be established.
+    #[error("TLS error: {0}")]
+    Tls(String),
+    /// The WebSocket handshake with the node failed.
+    #[error("WebSocket handshake error: {0}")]
+    Handshake(#[from] soketto::handshake::Error),
+    /// The node rejected the WebSocket handshake.
+    #[error("Connection rejected with status code: {0}")]
+    Rejected(u16),
+}
+
+/// Build a WS RPC client from the URL, connecting through the given proxy, and optionally
+/// pinging the node whenever the connection has been idle for the given interval.
+pub(crate) async fn client(
+    url: &str,
+    proxy: &ProxyConfig,
+    keepalive: Option<Duration>,
+) -> Result<Client, ProxyError> {
+    let (sender, receiver) = ws_transport(url, proxy).await?;
+    let mut builder = ClientBuilder::default().max_notifs_per_subscription(4096);
+    if let Some(interval) = keepalive {
+        builder = builder.ping_interval(interval);
+    }
+    Ok(builder.build_with_tokio(sender, receiver))
+}
+
+/// Anything that we can speak WebSocket over.
+trait Stream: AsyncRead + AsyncWrite + Unpin + Send {}
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> Stream for T {}
+
+type WsStream = BufReader<BufWriter<Compat<Box<dyn Stream>>>>;
+
+async fn ws_transport(url: &str, proxy: &ProxyConfig) -> Result<(Sender, Receiver), ProxyError> {
+    let target = Target::parse(url)?;
+    let stream = tokio::time::timeout(CONNECTION_TIMEOUT, tunnel(proxy, &target))
+        .await
+        .map_err(|_| ProxyError::Timeout(CONNECTION_TIMEOUT))??;
+
+    let mut client = WsHandshakeClient::new(
+        BufReader::new(BufWriter::new(stream.compat())),
+        &target.host_header,
+        &target.path_and_query,
+    );
+    match client.handshake().await? {
+        ServerResponse::Accepted { .. } => {
+            let mut builder = client.into_builder();
+            builder.set_max_message_size(MAX_MESSAGE_SIZE);
+            let (sender, receiver) = builder.finish();
+            Ok((Sender(sender), Receiver(receiver)))
+        }
+        // Redirects aren't followed when connecting through a proxy.
+        ServerResponse::Rejected { status_code } | ServerResponse::Redirect { status_code, .. } => {
+            Err(ProxyError::Rejected(status_code))
+        }
+    }
+}
+
+/// The node that we want the proxy to connect us to.
+struct Target {
+    /// The host name or IP address of the node (IPv6 addresses are in brackets).
+    host: String,
+    port: u16,
+    tls: bool,
+    /// The `Host` header to send in the WebSocket handshake.
+    host_header: String,
+    path_and_query: String,
+}
+
+impl Target {
+    fn parse(url: &str) -> Result<Self, ProxyError> {
+        let uri: Uri = url
+            .parse()
+            .map_err(|e: InvalidUri| ProxyError::Url(e.to_string()))?;
+        let tls = match uri.scheme_str() {
+            Some("ws") => false,
+            Some("wss") => true,
+            scheme => {
+                return Err(ProxyError::Url(format!(
+                    "`{}` not supported, expects 'ws' or 'wss'",
+                    scheme.unwrap_or("no scheme")
+                )))
+            }
+        };
+        let host = uri
+            .host()
+            .ok_or_else(|| ProxyError::Url("No host in URL".into()))?
+            .to_owned();
+        let port = uri.port_u16().unwrap_or(if tls { 443 } else { 80 });
+        let path_and_query = uri
+            .path_and_query()
+            .map(|p| p.as_str())
+            .unwrap_or("/")
+            .to_owned();
+        Ok(Target {
+            host_header: format!("{host}:{port}"),
+            host,
+            port,
+            tls,
+            path_and_query,
+        })
+    }
+}
+
+/// Connect to the proxy, and have it connect us to the target, handing back a stream
+/// which talks to the target (over TLS if the URL asked for it).
+async fn tunnel(proxy: &ProxyConfig, target: &Target) -> Result<Box<dyn Stream>, ProxyError> {
+    let mut stream = TcpStream::connect(&proxy.addr).await?;
+    if let Err(err) = stream.set_nodelay(true) {
+        tracing::warn!("set nodelay failed: {:?}", err);
+    }
+
+    let credentials = proxy.credentials.as_ref();
+    match proxy.kind {
+        ProxyKind::Socks5 => socks5_connect(&mut stream, target, credentials).await?,
+        ProxyKind::HttpConnect => http_connect(&mut stream, target, credentials).await?,
+    }
+
+    if target.tls {
+        Ok(Box::new(tls_connect(stream, &target.host).await?))
+    } else {
+        Ok(Box::new(stream))
+    }
+}
+
+/// Ask a SOCKS5 proxy to connect us to the target (see RFC 1928, and RFC 1929 for
+/// username and password authentication).
+async fn socks5_connect(
+    stream: &mut TcpStream,
+    target: &Target,
+    credentials: Option<&(String, String)>,
+) -> Result<(), ProxyError> {
+    // Offer a single authentication method; username and password if we have them.
+    let method = if credentials.is_some() { 0x02 } else { 0x00 };
+    stream.write_all(&[0x05, 0x01, method]).await?;
+    let mut reply = [0u8; 2];
+    stream.read_exact(&mut reply).await?;
+    if reply != [0x05, method] {
+        return Err(ProxyError::Socks5(
+            "the proxy doesn't accept the authentication method offered".into(),
+        ));
+    }
+
+    if let Some((username, password)) = credentials {
+        let mut request = vec![0x01];
+        push_len_prefixed(&mut request, "username", username)?;
+        push_len_prefixed(&mut request, "password", password)?;
+        stream.write_all(&request).await?;
+        stream.read_exact(&mut reply).await?;
+        if reply[1] != 0x00 {
+            return Err(ProxyError::Socks5(
+                "the proxy rejected the username and password given".into(),
+            ));
+        }
+    }
+
+    let mut request = vec![0x05, 0x01, 0x00];
+    match target
+        .host
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse()
+    {
+        Ok(IpAddr::V4(ip)) => {
+            request.push(0x01);
+            request.extend(ip.octets());
+        }
+        Ok(IpAddr::V6(ip)) => {
+            request.push(0x04);
+            request.extend(ip.octets());
+        }
+        Err(_) => {
+            request.push(0x03);
+            push_len_prefixed(&mut request, "host name", &target.host)?;
+        }
+    }
+    request.extend(target.port.to_be_bytes());
+    stream.write_all(&request).await?;
+
+    // The reply ends in the address that the proxy bound to, which we don't need.
+    let mut reply = [0u8; 4];
+    stream.read_exact(&mut reply).await?;
+    if reply[1] != 0x00 {
+        return Err(ProxyError::Socks5(socks5_reply_message(reply[1])));
+    }
+    let addr_len = match reply[3] {
+        0x01 => 4,
+        0x04 => 16,
+        0x03 => stream.read_u8().await? as usize,
+        atyp => {
+            return Err(ProxyError::Socks5(format!(
+                "unexpected address type {atyp} in the reply"
+            )))
+        }
+    };
+    let mut bound_addr = vec![0u8; addr_len + 2];
+    stream.read_exact(&mut bound_addr).await?;
+
+    Ok(())
+}
+
+/// SOCKS5 prefixes strings with a single byte length.
+fn push_len_prefixed(buf: &mut Vec<u8>, what: &str, s: &str) -> Result<(), ProxyError> {
+    let len = u8::try_from(s.len())
+        .map_err(|_| ProxyError::Socks5(format!("the {what} is longer than 255 bytes")))?;
+    buf.push(len);
+    buf.extend(s.as_bytes());
+    Ok(())
+}
+
+fn socks5_reply_message(code: u8) -> String {
+    let reason = match code {
+        0x01 => "general failure",
+        0x02 => "connection not allowed by ruleset",
+        0x03 => "network unreachable",
+        0x04 => "host unreachable",
+        0x05 => "connection refused",
+        0x06 => "TTL expired",
+        0x07 => "command not supported",
+        0x08 => "address type not supported",
+        _ => "unknown error",
+    };
+    format!("the proxy failed to connect to the node: {reason} ({code:#04x})")
+}
+
+/// Ask an HTTP proxy to connect us to the target using the `CONNECT` method.
+async fn http_connect(
+    stream: &mut TcpStream,
+    target: &Target,
+    credentials: Option<&(String, String)>,
+) -> Result<(), ProxyError> {
+    let authority = &target.host_header;
+    let mut request = format!("CONNECT {authority} HTTP/1.1\r\nHost: {authority}\r\n");
+    if let Some((username, password)) = credentials {
+        let token =
+            base64::engine::general_purpose::STANDARD.encode(format!("{username}:{password}"));
+        request.push_str(&format!("Proxy-Authorization: Basic {token}\r\n"));
+    }
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes()).await?;
+
+    // Read the response head a byte at a time, so that nothing after it is consumed.
+    let mut head = Vec::new();
+    while !head.ends_with(b"\r\n\r\n") {
+        if head.len() >= MAX_RESPONSE_HEAD_SIZE {
+            return Err(ProxyError::HttpConnect("the response is too long".into()));
+        }
+        head.push(stream.read_u8().await?);
+    }
+
+    let head = String::from_utf8_lossy(&head);
+    let status_line = head.lines().next().unwrap_or_default();
+    match status_line.split_whitespace().nth(1) {
+        Some(status) if status.starts_with('2') => Ok(()),
+        _ => Err(ProxyError::HttpConnect(format!(
+            "the proxy failed to connect to the node: {status_line}"
+        ))),
+    }
+}
+
+/// Establish a TLS connection to the node over the tunnel, trusting the system certificates.
+async fn tls_connect(
+    stream: TcpStream,
+    host: &str,
+) -> Result<tokio_rustls::client::TlsStream<TcpStream>, ProxyError> {
+    use tokio_rustls::rustls;
+
+    let mut roots = rustls::RootCertStore::empty();
+    let certs = rustls_native_certs::load_native_certs()
+        .map_err(|e| ProxyError::Tls(format!("failed to load system certs: {e}")))?;
+    for cert in certs {
+        // Skip over any certificates which can't be parsed.
+        let _ = roots.add(&rustls::Certificate(cert.0));
+    }
+    let config = rustls::ClientConfig::builder()
+        .with_safe_defaults()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+
+    let server_name = rustls::ServerName::try_from(host)
+        .map_err(|e| ProxyError::Tls(format!("invalid host {host}: {e}")))?;
+    let connector = tokio_rustls::TlsConnector::from(Arc::new(config));
+    Ok(connector.connect(server_name, stream).await?)
+}
+
+/// Sending end of a proxied WebSocket connection.
+struct Sender(connection::Sender<WsStream>);
+
+/// Receiving end of a proxied WebSocket connection.
+struct Receiver(connection::Receiver<WsStream>);
+
+#[async_trait]
+impl TransportSenderT for Sender {
+    type Error = connection::Error;
+
+    async fn send(&mut self, body: String) -> Result<(), Self::Error> {
+        self.0.send_text(body).await?;
+        self.0.flush().await
+    }
+
+    async fn send_ping(&mut self) -> Result<(), Self::Error> {
+        let empty = ByteSlice125::try_from(&[][..]).expect("an empty slice fits in 125 bytes; qed");
+        self.0.send_ping(empty).await?;
+        self.0.flush().await
+    }
+
+    async fn close(&mut self) -> Result<(), Self::Error> {
+        self.0.close().await
+    }
+}
+
+#[async_trait]
+impl TransportReceiverT for Receiver {
+    type Error = connection::Error;
+
+    async fn receive(&mut self) -> Result<ReceivedMessage, Self::Error> {
+        loop {
+            let mut message = Vec::new();
+            match self.0.receive(&mut message).await? {
+                Incoming::Data(Data::Text(_)) => {
+                    let text = String::from_utf8(message)
+                        .map_err(|e| connection::Error::Utf8(e.utf8_error()))?;
+                    return Ok(ReceivedMessage::Text(text));
+                }
+                Incoming::Data(Data::Binary(_)) => return Ok(ReceivedMessage::Bytes(message)),
+                Incoming::Pong(_) => return Ok(ReceivedMessage::Pong),
+                _ => continue,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpsee::core::{client::ClientT, rpc_params};
+    use std::{net::SocketAddr, sync::mpsc};
+    use tokio::net::TcpListener;
+
+    /// The address that we ask the proxies to connect us to. Only they know how to reach it, so
+    /// nothing gets through unless it went via them.
+    const NODE_URL: &str = "ws://node.test:9944";
+
+    /// Spawn something which accepts a WebSocket connection, and answers every JSON-RPC
+    /// request with the name of the method called.
+    async fn spawn_node() -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut server =
+                soketto::handshake::Server::new(BufReader::new(BufWriter::new(socket.compat())));
+            let key = server.receive_request().await.unwrap().key();
+            let accept = soketto::handshake::server::Response::Accept {
+                key,
+                protocol: None,
+            };
+            server.send_response(&accept).await.unwrap();
+
+            let (mut sender, mut receiver) = server.into_builder().finish();
+            let mut message = Vec::new();
+            while receiver.receive_data(&mut message).await.is_ok() {
+                let request: serde_json::Value = serde_json::from_slice(&message).unwrap();
+                let response = serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": request["id"],
+                    "result": request["method"],
+                });
+                sender.send_text(response.to_string()).await.unwrap();
+                sender.flush().await.unwrap();
+                message.clear();
+            }
+        });
+        addr
+    }
+
+    /// Spawn a SOCKS5 proxy which expects the given credentials, and connects everybody to the
+    /// node. The destination asked for is sent down the channel.
+    async fn spawn_socks5_proxy(
+        node: SocketAddr,
+        credentials: (&'static str, &'static str),
+    ) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        let (tx, rx) = mpsc::channel();
+        tokio::spawn(async move {
+            let (mut client, _) = listener.accept().await.unwrap();
+
+            let mut greeting = [0u8; 2];
+            client.read_exact(&mut greeting).await.unwrap();
+            let mut methods = vec![0u8; greeting[1] as usize];
+            client.read_exact(&mut methods).await.unwrap();
+            assert!(methods.contains(&0x02));
+            client.write_all(&[0x05, 0x02]).await.unwrap();
+
+            let mut version_and_len = [0u8; 2];
+            client.read_exact(&mut version_and_len).await.unwrap();
+            let mut username = vec![0u8; version_and_len[1] as usize];
+            client.read_exact(&mut username).await.unwrap();
+            let mut password = vec![0u8; client.read_u8().await.unwrap() as usize];
+            client.read_exact(&mut password).await.unwrap();
+            if (&*username, &*password) != (credentials.0.as_bytes(), credentials.1.as_bytes()) {
+                client.write_all(&[0x01, 0x01]).await.unwrap();
+                return;
+            }
+            client.write_all(&[0x01, 0x00]).await.unwrap();
+
+            let mut request = [0u8; 4];
+            client.read_exact(&mut request).await.unwrap();
+            assert_eq!(request, [0x05, 0x01, 0x00, 0x03]);
+            let mut host = vec![0u8; client.read_u8().await.unwrap() as usize];
+            client.read_exact(&mut host).await.unwrap();
+            let port = client.read_u16().await.unwrap();
+            tx.send(format!("{}:{port}", String::from_utf8(host).unwrap()))
+                .unwrap();
+
+            let mut node = TcpStream::connect(node).await.unwrap();
+            client
+                .write_all(&[0x05, 0x00, 0x00, 0x01, 127, 0, 0, 1, 0, 0])
+                .await
+                .unwrap();
+            let _ = tokio::io::copy_bidirectional(&mut client, &mut node).await;
+        });
+        (addr, rx)
+    }
+
+    /// Spawn an HTTP proxy which connects everybody to the node. The request head is sent down
+    /// the channel.
+    async fn spawn_http_proxy(node: SocketAddr) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        let (tx, rx) = mpsc::channel();
+        tokio::spawn(async move {
+            let (mut client, _) = listener.accept().await.unwrap();
+
+            let mut head = Vec::new();
+            while !head.ends_with(b"\r\n\r\n") {
+                head.push(client.read_u8().await.unwrap());
+            }
+            tx.send(String::from_utf8(head).unwrap()).unwrap();
+
+            let mut node = TcpStream::connect(node).await.unwrap();
+            client
+                .write_all(b"HTTP/1.1 200 Connection established\r\n\r\n")
+                .await
+                .unwrap();
+            let _ = tokio::io::copy_bidirectional(&mut client, &mut node).await;
+        });
+        (addr, rx)
+    }
+
+    #[tokio::test]
+    async fn connects_through_a_socks5_proxy() {
+        let node = spawn_node().await;
+        let (proxy_addr, destinations) = spawn_socks5_proxy(node, ("alice", "secret")).await;
+
+        let proxy = ProxyConfig::socks5(proxy_addr).with_credentials("alice", "secret");
+        let client = client(NODE_URL, &proxy, None).await.unwrap();
+        let method: String = client.request("system_name", rpc_params![]).await.unwrap();
+
+        assert_eq!(method, "system_name");
+        assert_eq!(destinations.try_recv().unwrap(), "node.test:9944");
+    }
+
+    #[tokio::test]
+    async fn socks5_proxy_rejecting_credentials_is_an_error() {
+        let node = spawn_node().await;
+        let (proxy_addr, _) = spawn_socks5_proxy(node, ("alice", "secret")).await;
+
+        let proxy = ProxyConfig::socks5(proxy_addr).with_credentials("alice", "wrong");
+        let err = client(NODE_URL, &proxy, None).await.unwrap_err();
+
+        assert!(matches!(err, ProxyError::Socks5(_)), "{err:?}");
+    }
+
+    #[tokio::test]
+    async fn connects_through_an_http_connect_proxy() {
+        let node = spawn_node().await;
+        let (proxy_addr, heads) = spawn_http_proxy(node).await;
+
+        let proxy = ProxyConfig::http_connect(proxy_addr).with_credentials("alice", "secret");
+        let client = client(NODE_URL, &proxy, None).await.unwrap();
+        let method: String = client.request("system_name", rpc_params![]).await.unwrap();
+
+        assert_eq!(method, "system_name");
+        assert_eq!(
+            heads.try_recv().unwrap(),
+            "CONNECT node.test:9944 HTTP/1.1\r\n\
+             Host: node.test:9944\r\n\
+             Proxy-Authorization: Basic YWxpY2U6c2VjcmV0\r\n\r\n"
+        );
+    }
+
+    #[test]
+    fn passwords_are_not_debug_printed() {
+        let proxy = ProxyConfig::socks5("127.0.0.1:1080").with_credentials("alice", "secret");
+        assert!(!format!("{proxy:?}").contains("secret"));
+    }
+}
@@ -7,7 +7,7 @@
 //! implementation of the trait is provided ([`BaseExtrinsicParams`]) which is
 //! used by the provided Substrate and Polkadot configuration.
 
-use crate::{utils::Encoded, Config};
+use crate::{config::Header, utils::Encoded, Config};
 use codec::{Compact, Decode, Encode};
 use core::fmt::Debug;
 use derivative::Derivative;
@@ -104,6 +104,23 @@ impl<T: Config, Tip: Default> BaseExtrinsicParamsBuilder<T, Tip> {
         self
     }
 
+    /// Make the transaction mortal, valid for `period` blocks (see [`Era::mortal()`]) from the
+    /// given checkpoint block. Both the number and the hash of the checkpoint block are given
+    /// explicitly, so that nothing needs fetching from a node; this is useful when creating
+    /// transactions offline.
+    pub fn mortal(mut self, period: u64, checkpoint_number: u64, checkpoint_hash: T::Hash) -> Self {
+        self.era = Era::mortal(period, checkpoint_number);
+        self.mortality_checkpoint = Some(checkpoint_hash);
+        self
+    }
+
+    /// Make the transaction mortal, valid for `period` blocks from the block with the given
+    /// header. This is handy for anchoring transactions to some finalized block that you
+    /// trust, rather than to whichever block happens to be the best one; see [`Self::mortal()`].
+    pub fn mortal_from_header(self, period: u64, checkpoint: &T::Header) -> Self {
+        self.mortal(period, checkpoint.number().into(), checkpoint.hash())
+    }
+
     /// Set the tip you'd like to give to the block author
     /// for this transaction.
     pub fn tip(mut self, tip: impl Into<Tip>) -> Self {
@@ -112,6 +129,12 @@ impl<T: Config, Tip: Default> BaseExtrinsicParamsBuilder<T, Tip> {
     }
 }
 
+impl<T: Config, Tip: Default + From<u128>> WithTip for BaseExtrinsicParamsBuilder<T, Tip> {
+    fn with_tip(self, tip: u128) -> Self {
+        self.tip(tip)
+    }
+}
+
 impl<T: Config, Tip: Default> Default for BaseExtrinsicParamsBuilder<T, Tip> {
     fn default() -> Self {
         Self {
@@ -165,6 +188,36 @@ impl<T: Config, Tip: Debug + Encode + 'static> ExtrinsicParams<T::Index, T::Hash
     }
 }
 
+/// How the tip given to the block author for a transaction is worked out. A tip worked out
+/// from the weight of the transaction helps it to compete for inclusion when blocks are full.
+/// See [`crate::tx::TxClient::create_signed_with_tip()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TipStrategy {
+    /// Tip this amount, whatever the weight of the transaction.
+    Flat(u128),
+    /// Tip this amount for each unit of computational (`ref_time`) weight that the transaction
+    /// is estimated to use.
+    PerWeight(u128),
+}
+
+impl TipStrategy {
+    /// The tip to give for a transaction of the given `ref_time` weight. This saturates at
+    /// `u128::MAX` rather than overflowing.
+    pub fn tip(&self, ref_time: u64) -> u128 {
+        match *self {
+            TipStrategy::Flat(tip) => tip,
+            TipStrategy::PerWeight(rate) => rate.saturating_mul(ref_time as u128),
+        }
+    }
+}
+
+/// Implemented by [`ExtrinsicParams::OtherParams`] which can have the tip set on them, so that
+/// a tip worked out from a [`TipStrategy`] can be applied when signing.
+pub trait WithTip {
+    /// Set the tip, overriding any tip already set.
+    fn with_tip(self, tip: u128) -> Self;
+}
+
 // Dev note: This and related bits taken from `sp_runtime::generic::Era`
 /// An era to describe the longevity of a transaction.
 #[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
@@ -256,3 +309,92 @@ impl Decode for Era {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::{
+        substrate::{SubstrateExtrinsicParams, SubstrateExtrinsicParamsBuilder},
+        SubstrateConfig,
+    };
+    use primitive_types::H256;
+
+    #[test]
+    fn mortal_params_are_anchored_to_the_given_checkpoint() {
+        let genesis_hash = H256::repeat_byte(1);
+        let checkpoint_hash = H256::repeat_byte(2);
+
+        let other_params = SubstrateExtrinsicParamsBuilder::<SubstrateConfig>::new().mortal(
+            64,
+            1000,
+            checkpoint_hash,
+        );
+        let params = <SubstrateExtrinsicParams<SubstrateConfig> as ExtrinsicParams<u32, H256>>::new(
+            100,
+            2,
+            0,
+            genesis_hash,
+            other_params,
+        );
+
+        // The era is mortal, and its phase lines up with the checkpoint block number:
+        let mut extra = Vec::new();
+        params.encode_extra_to(&mut extra);
+        let era = Era::decode(&mut &*extra).unwrap();
+        assert_eq!(era, Era::Mortal(64, 1000 % 64));
+
+        // The checkpoint hash is signed, after the genesis hash:
+        let mut additional = Vec::new();
+        params.encode_additional_to(&mut additional);
+        assert_eq!(
+            additional,
+            (100u32, 2u32, genesis_hash, checkpoint_hash).encode()
+        );
+    }
+
+    #[test]
+    fn mortal_params_can_be_anchored_to_a_header() {
+        use crate::config::substrate::{BlakeTwo256, Digest, SubstrateHeader};
+
+        let genesis_hash = H256::repeat_byte(1);
+        let checkpoint = SubstrateHeader::<u32, BlakeTwo256> {
+            parent_hash: H256::repeat_byte(3),
+            number: 1000,
+            state_root: H256::repeat_byte(4),
+            extrinsics_root: H256::repeat_byte(5),
+            digest: Digest { logs: vec![] },
+        };
+
+        let other_params = SubstrateExtrinsicParamsBuilder::<SubstrateConfig>::new()
+            .mortal_from_header(100, &checkpoint);
+        let params = <SubstrateExtrinsicParams<SubstrateConfig> as ExtrinsicParams<u32, H256>>::new(
+            100,
+            2,
+            0,
+            genesis_hash,
+            other_params,
+        );
+
+        // The period is rounded up to a power of two, and the phase lines up with the header:
+        let mut extra = Vec::new();
+        params.encode_extra_to(&mut extra);
+        let era = Era::decode(&mut &*extra).unwrap();
+        assert_eq!(era, Era::Mortal(128, 1000 % 128));
+
+        let mut additional = Vec::new();
+        params.encode_additional_to(&mut additional);
+        assert_eq!(
+            additional,
+            (100u32, 2u32, genesis_hash, checkpoint.hash()).encode()
+        );
+    }
+
+    #[test]
+    fn tips_are_worked_out_from_the_strategy() {
+        assert_eq!(TipStrategy::Flat(1_000).tip(0), 1_000);
+        assert_eq!(TipStrategy::Flat(1_000).tip(123_456), 1_000);
+        assert_eq!(TipStrategy::PerWeight(3).tip(0), 0);
+        assert_eq!(TipStrategy::PerWeight(3).tip(123_456), 370_368);
+        assert_eq!(TipStrategy::PerWeight(u128::MAX).tip(2), u128::MAX);
+    }
+}
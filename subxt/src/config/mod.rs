@@ -16,7 +16,7 @@ use codec::{Decode, Encode};
 use core::fmt::Debug;
 use serde::{de::DeserializeOwned, Serialize};
 
-pub use extrinsic_params::ExtrinsicParams;
+pub use extrinsic_params::{ExtrinsicParams, TipStrategy};
 pub use polkadot::PolkadotConfig;
 pub use substrate::SubstrateConfig;
 
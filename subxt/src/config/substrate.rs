@@ -83,6 +83,18 @@ impl Hasher for BlakeTwo256 {
     }
 }
 
+/// A type that can hash values using the keccak_256 algorithm. Use this as the
+/// [`Config::Hasher`] for chains which hash blocks and extrinsics with keccak.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode)]
+pub struct Keccak256;
+
+impl Hasher for Keccak256 {
+    type Output = H256;
+    fn hash(s: &[u8]) -> Self::Output {
+        sp_core_hashing::keccak_256(s).into()
+    }
+}
+
 /// A generic Substrate header type, adapted from `sp_runtime::generic::Header`.
 /// The block number and hasher can be configured to adapt this for other nodes.
 #[derive(Encode, Decode, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
@@ -125,6 +137,40 @@ pub struct Digest {
     pub logs: Vec<DigestItem>,
 }
 
+impl Digest {
+    /// The data of the first `PreRuntime` log from the given consensus engine, if any.
+    pub fn pre_runtime(&self, engine_id: ConsensusEngineId) -> Option<&[u8]> {
+        self.logs.iter().find_map(|log| match log {
+            DigestItem::PreRuntime(id, data) if *id == engine_id => Some(&data[..]),
+            _ => None,
+        })
+    }
+
+    /// The data of the first `Seal` log from the given consensus engine, if any.
+    pub fn seal(&self, engine_id: ConsensusEngineId) -> Option<&[u8]> {
+        self.logs.iter().find_map(|log| match log {
+            DigestItem::Seal(id, data) if *id == engine_id => Some(&data[..]),
+            _ => None,
+        })
+    }
+
+    /// Decode the BABE `PreRuntime` log, which says which authority produced the block and in
+    /// which slot. Returns `None` if there is no such log.
+    pub fn babe_pre_digest(&self) -> Result<Option<BabePreDigest>, codec::Error> {
+        self.pre_runtime(BABE_ENGINE_ID)
+            .map(|mut data| BabePreDigest::decode(&mut data))
+            .transpose()
+    }
+
+    /// Decode the slot from the Aura `PreRuntime` log. Returns `None` if there is no such log.
+    /// The author is the authority whose turn it is in this slot: `slot % authorities.len()`.
+    pub fn aura_slot(&self) -> Result<Option<u64>, codec::Error> {
+        self.pre_runtime(AURA_ENGINE_ID)
+            .map(|mut data| u64::decode(&mut data))
+            .transpose()
+    }
+}
+
 /// Digest item that is able to encode/decode 'system' digest items and
 /// provide opaque access to other items. From `sp_runtime::generic::digest`.
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -164,6 +210,19 @@ pub enum DigestItem {
     RuntimeEnvironmentUpdated,
 }
 
+impl DigestItem {
+    /// The consensus engine that this log is from, if it's a `PreRuntime`, `Consensus` or
+    /// `Seal` log.
+    pub fn engine_id(&self) -> Option<ConsensusEngineId> {
+        match self {
+            DigestItem::PreRuntime(id, _)
+            | DigestItem::Consensus(id, _)
+            | DigestItem::Seal(id, _) => Some(*id),
+            DigestItem::Other(_) | DigestItem::RuntimeEnvironmentUpdated => None,
+        }
+    }
+}
+
 // From sp_runtime::generic, DigestItem enum indexes are encoded using this:
 #[repr(u32)]
 #[derive(Encode, Decode)]
@@ -228,6 +287,53 @@ impl Decode for DigestItem {
 /// Consensus engine unique ID. From `sp_runtime::ConsensusEngineId`.
 pub type ConsensusEngineId = [u8; 4];
 
+/// The [`ConsensusEngineId`] of BABE digest logs.
+pub const BABE_ENGINE_ID: ConsensusEngineId = *b"BABE";
+/// The [`ConsensusEngineId`] of Aura digest logs.
+pub const AURA_ENGINE_ID: ConsensusEngineId = *b"aura";
+
+/// The parts of a BABE pre-runtime digest needed to identify the author of a block. From
+/// `sp_consensus_babe::digests::PreDigest`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BabePreDigest {
+    /// How the author won the right to author the block.
+    pub kind: BabeSlotClaim,
+    /// The index of the author in the list of BABE authorities for the epoch.
+    pub authority_index: u32,
+    /// The slot that the block was authored in.
+    pub slot: u64,
+}
+
+/// How the author of a BABE block won the right to author it.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BabeSlotClaim {
+    /// The author won the primary VRF lottery for the slot.
+    Primary,
+    /// The author is the secondary slot author, chosen without a VRF.
+    SecondaryPlain,
+    /// The author is the secondary slot author, chosen with a VRF.
+    SecondaryVrf,
+}
+
+impl Decode for BabePreDigest {
+    fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+        let kind = match input.read_byte()? {
+            1 => BabeSlotClaim::Primary,
+            2 => BabeSlotClaim::SecondaryPlain,
+            3 => BabeSlotClaim::SecondaryVrf,
+            _ => return Err("Unknown BABE pre-digest variant".into()),
+        };
+        let authority_index = u32::decode(input)?;
+        let slot = u64::decode(input)?;
+        // Any VRF output and proof that follows isn't needed to identify the author.
+        Ok(BabePreDigest {
+            kind,
+            authority_index,
+            slot,
+        })
+    }
+}
+
 impl serde::Serialize for DigestItem {
     fn serialize<S>(&self, seq: S) -> Result<S::Ok, S::Error>
     where
@@ -312,4 +418,98 @@ mod test {
             serde_json::from_str(numeric_block_number_json).expect("valid block header");
         assert_eq!(header.number(), 4);
     }
+
+    #[test]
+    fn babe_and_aura_pre_runtime_digests_decode() {
+        let header_json = r#"
+            {
+                "digest": {
+                    "logs": [
+                        "0x06424142453402010000003412000000000000",
+                        "0x066175726120f0debc9a78563412",
+                        "0x054241424508abcd"
+                    ]
+                },
+                "extrinsicsRoot": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                "number": "0x04",
+                "parentHash": "0xcb2690b2c85ceab55be03fc7f7f5f3857e7efeb7a020600ebd4331e10be2f7a5",
+                "stateRoot": "0x0000000000000000000000000000000000000000000000000000000000000000"
+            }
+        "#;
+        let header: SubstrateHeader<u32, BlakeTwo256> =
+            serde_json::from_str(header_json).expect("valid block header");
+        let digest = &header.digest;
+
+        let engine_ids: Vec<_> = digest.logs.iter().map(|log| log.engine_id()).collect();
+        assert_eq!(
+            engine_ids,
+            vec![
+                Some(BABE_ENGINE_ID),
+                Some(AURA_ENGINE_ID),
+                Some(BABE_ENGINE_ID)
+            ]
+        );
+
+        let babe = digest
+            .babe_pre_digest()
+            .unwrap()
+            .expect("has a BABE pre-digest");
+        assert_eq!(
+            babe,
+            BabePreDigest {
+                kind: BabeSlotClaim::SecondaryPlain,
+                authority_index: 1,
+                slot: 0x1234,
+            }
+        );
+        assert_eq!(digest.aura_slot().unwrap(), Some(0x1234_5678_9abc_def0));
+        assert_eq!(digest.seal(BABE_ENGINE_ID), Some(&[0xab, 0xcd][..]));
+        assert_eq!(digest.seal(AURA_ENGINE_ID), None);
+
+        // A digest without the logs decodes to nothing:
+        assert_eq!(Digest::default().babe_pre_digest().unwrap(), None);
+        assert_eq!(Digest::default().aura_slot().unwrap(), None);
+    }
+
+    #[test]
+    fn keccak_config_hashes_with_keccak() {
+        use crate::config::polkadot::PlainTip;
+
+        enum KeccakConfig {}
+        impl Config for KeccakConfig {
+            type Index = u32;
+            type Hash = H256;
+            type AccountId = AccountId32;
+            type Address = MultiAddress<Self::AccountId, ()>;
+            type Signature = MultiSignature;
+            type Hasher = Keccak256;
+            type Header = SubstrateHeader<u32, Keccak256>;
+            type ExtrinsicParams = BaseExtrinsicParams<Self, PlainTip>;
+        }
+
+        // Extrinsic hashes are the hash of the SCALE encoded (ie length prefixed) extrinsic.
+        let extrinsic = vec![0x04u8, 0x00, 0x01, 0x02];
+        let mut encoded = vec![16u8];
+        encoded.extend(&extrinsic);
+
+        let hash = <KeccakConfig as Config>::Hasher::hash_of(&extrinsic);
+        assert_eq!(hash, H256(sp_core_hashing::keccak_256(&encoded)));
+        assert_ne!(
+            hash,
+            <SubstrateConfig as Config>::Hasher::hash_of(&extrinsic)
+        );
+
+        // Headers are hashed with the same hasher.
+        let header = SubstrateHeader::<u32, Keccak256> {
+            parent_hash: H256::zero(),
+            number: 1,
+            state_root: H256::zero(),
+            extrinsics_root: H256::zero(),
+            digest: Default::default(),
+        };
+        assert_eq!(
+            header.hash(),
+            H256(sp_core_hashing::keccak_256(&header.encode()))
+        );
+    }
 }
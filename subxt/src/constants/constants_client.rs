@@ -4,7 +4,7 @@
 
 use super::ConstantAddress;
 use crate::{
-    client::OfflineClientT,
+    client::{OfflineClient, OfflineClientT, OnlineClient},
     error::{Error, MetadataError},
     metadata::DecodeWithMetadata,
     Config,
@@ -78,3 +78,30 @@ impl<T: Config, Client: OfflineClientT<T>> ConstantsClient<T, Client> {
         Ok(value)
     }
 }
+
+impl<T: Config> ConstantsClient<T, OnlineClient<T>> {
+    /// Access constants as they were at the given block, rather than as they are in the
+    /// metadata that the client is currently using. Constants live in the metadata, so this
+    /// fetches (and caches) the metadata in use at that block; see [`OnlineClient::offline_at()`].
+    ///
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), subxt::Error> {
+    /// use subxt::{OnlineClient, PolkadotConfig};
+    ///
+    /// let api = OnlineClient::<PolkadotConfig>::new().await?;
+    /// let block_hash = api.rpc().block_hash(Some(1u32.into())).await?.unwrap();
+    ///
+    /// let address = subxt::dynamic::constant("Balances", "ExistentialDeposit");
+    /// let deposit = api.constants().at_block(block_hash).await?.at(&address)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn at_block(
+        &self,
+        block_hash: T::Hash,
+    ) -> Result<ConstantsClient<T, OfflineClient<T>>, Error> {
+        let client = self.client.offline_at(block_hash).await?;
+        Ok(ConstantsClient::new(client))
+    }
+}
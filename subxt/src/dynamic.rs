@@ -10,6 +10,8 @@ use crate::{
     metadata::{DecodeWithMetadata, Metadata},
 };
 use scale_decode::DecodeAsType;
+use scale_info::{form::PortableForm, Field, PortableRegistry, TypeDef};
+use scale_value::{scale::TypeId, Composite};
 
 pub use scale_value::{At, Value};
 
@@ -75,4 +77,57 @@ impl DecodedValueThunk {
         )?;
         Ok(val)
     }
+    /// Like [`Self::to_value()`], but if the value is a composite type, its fields are decoded
+    /// one at a time, and if one fails to decode then the fields before it are handed back
+    /// alongside the error. Values of other types are handed back as a single unnamed field.
+    ///
+    /// This is a best-effort way to salvage what we can from corrupt or unexpected data (for
+    /// instance when scanning historical storage); don't rely on it for anything consensus
+    /// critical, since a partially decoded value is not the value that the chain stored.
+    pub fn try_to_fields(&self) -> (Composite<TypeId>, Option<Error>) {
+        let types = self.metadata.types();
+        let bytes = &mut &*self.scale_bytes;
+        match types.resolve(self.type_id).map(|ty| &ty.type_def) {
+            Some(TypeDef::Composite(composite)) => {
+                try_decode_fields(bytes, &composite.fields, types)
+            }
+            _ => match DecodedValue::decode_as_type(bytes, self.type_id, types) {
+                Ok(value) => (Composite::Unnamed(vec![value]), None),
+                Err(e) => (Composite::Unnamed(vec![]), Some(e.into())),
+            },
+        }
+    }
+}
+
+/// Decode the given fields one at a time, stopping at the first one which fails to decode and
+/// handing back the fields decoded up to that point, alongside the error.
+pub(crate) fn try_decode_fields(
+    bytes: &mut &[u8],
+    fields: &[Field<PortableForm>],
+    types: &PortableRegistry,
+) -> (Composite<TypeId>, Option<Error>) {
+    let mut values = Vec::with_capacity(fields.len());
+    let mut error = None;
+    for field in fields {
+        match DecodedValue::decode_as_type(bytes, field.ty.id, types) {
+            Ok(value) => values.push((field.name.clone(), value)),
+            Err(e) => {
+                error = Some(e.into());
+                break;
+            }
+        }
+    }
+
+    let is_named = !fields.is_empty() && fields.iter().all(|f| f.name.is_some());
+    let composite = if is_named {
+        Composite::Named(
+            values
+                .into_iter()
+                .map(|(name, value)| (name.unwrap_or_default(), value))
+                .collect(),
+        )
+    } else {
+        Composite::Unnamed(values.into_iter().map(|(_, value)| value).collect())
+    };
+    (composite, error)
 }
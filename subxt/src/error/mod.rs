@@ -52,6 +52,9 @@ pub enum Error {
     /// Error encoding from a [`crate::dynamic::Value`].
     #[error("Error encoding from dynamic value: {0}")]
     Encode(#[from] EncodeError),
+    /// Error constructing a client.
+    #[error("Client error: {0}")]
+    ClientInit(Box<ClientInitError>),
     /// Transaction progress error.
     #[error("Transaction error: {0}")]
     Transaction(#[from] TransactionError),
@@ -61,6 +64,12 @@ pub enum Error {
     /// An error encoding a storage address.
     #[error("Error encoding storage address: {0}")]
     StorageAddress(#[from] StorageAddressError),
+    /// We could not fetch the metadata in use at some block.
+    #[error("Could not fetch the metadata at block {0}: {1}")]
+    HistoricalMetadata(String, Box<Error>),
+    /// Some bytes to decode exceeded the configured [`crate::metadata::DecodeLimits`].
+    #[error("Decode limit exceeded: {0}")]
+    DecodeLimit(#[from] DecodeLimitError),
     /// The bytes representing an error that we were unable to decode.
     #[error("An error occurred but it could not be decoded: {0:?}")]
     Unknown(Vec<u8>),
@@ -81,6 +90,12 @@ impl From<String> for Error {
     }
 }
 
+impl From<ClientInitError> for Error {
+    fn from(error: ClientInitError) -> Self {
+        Error::ClientInit(Box::new(error))
+    }
+}
+
 /// An RPC error. Since we are generic over the RPC client that is used,
 /// the error is boxed and could be casted.
 #[derive(Debug, thiserror::Error)]
@@ -94,6 +109,39 @@ pub enum RpcError {
     /// The RPC subscription dropped.
     #[error("RPC error: subscription dropped.")]
     SubscriptionDropped,
+    /// The node is reachable, but is still syncing with the network.
+    #[error("RPC error: node is syncing.")]
+    NodeSyncing,
+    /// The node doesn't support the given RPC method.
+    #[error("RPC error: the node doesn't support the '{0}' method.")]
+    MethodNotSupported(String),
+    /// The node refused to run the given RPC method because it is unsafe, and unsafe methods
+    /// aren't allowed on this connection.
+    #[error("RPC error: the node doesn't allow the unsafe '{0}' method to be called.")]
+    UnsafeMethodNotAllowed(String),
+}
+
+/// An error constructing an [`crate::OnlineClient`], saying which of the steps involved
+/// failed, so that (for instance) connection failures can be retried while metadata that
+/// can't be decoded is not.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ClientInitError {
+    /// We could not connect to the node.
+    #[error("Could not connect to the node: {0}")]
+    Transport(RpcError),
+    /// We could not fetch the genesis hash of the chain.
+    #[error("Could not fetch the genesis hash: {0}")]
+    GenesisHash(Box<Error>),
+    /// We could not fetch the metadata.
+    #[error("Could not fetch the metadata: {0}")]
+    Metadata(Box<Error>),
+    /// We fetched the metadata, but could not decode it.
+    #[error("Could not decode the metadata: {0}")]
+    MetadataDecoding(codec::Error),
+    /// We could not fetch the runtime version.
+    #[error("Could not fetch the runtime version: {0}")]
+    RuntimeVersion(Box<Error>),
 }
 
 /// Block error
@@ -113,6 +161,9 @@ pub enum BlockError {
     /// Decoding error.
     #[error("Cannot decode extrinsic: {0}")]
     DecodingError(codec::Error),
+    /// The extrinsic isn't in a block, and so has no events.
+    #[error("The extrinsic is not in a block, and so has no events")]
+    NotInBlock,
 }
 
 impl BlockError {
@@ -131,6 +182,10 @@ pub enum TransactionError {
     /// block hasn't yet been finalized).
     #[error("The finality subscription expired")]
     FinalityTimeout,
+    /// We gave up waiting for the transaction to reach the desired status. It may still be
+    /// in the transaction pool, and may yet make it into a block.
+    #[error("Gave up waiting for the transaction after {0:?}")]
+    WaitTimeout(std::time::Duration),
     /// The block hash that the transaction was added to could not be found.
     /// This is probably because the block was retracted before being finalized.
     #[error("The block containing the transaction can no longer be found (perhaps it was on a non-finalized fork?)")]
@@ -144,6 +199,134 @@ pub enum TransactionError {
     /// The transaction was dropped because of some limit
     #[error("The transaction was dropped from the pool because of a limit.")]
     Dropped,
+    /// An unsigned transaction was expected, but the extrinsic given is signed.
+    #[error("Expected an unsigned transaction, but the extrinsic is signed")]
+    NotUnsigned,
+    /// The node rejected an unsigned transaction; the call may need to be signed.
+    #[error("The node rejected the unsigned transaction (does the call need to be signed?): {0}")]
+    UnsignedRejected(String),
+    /// The transaction was not found in any of the blocks that were watched for it.
+    #[error("The transaction was not included in any of the blocks that were watched for it")]
+    NotIncluded,
+    /// The transaction succeeded, but didn't emit the event that it was expected to.
+    #[error("The transaction did not emit the expected {0}::{1} event")]
+    ExpectedEventNotFound(&'static str, &'static str),
+    /// The extrinsic was to be submitted through a client for a different chain than the one
+    /// that it was created for.
+    #[error("The extrinsic is for the chain with genesis hash {expected}, but was to be submitted to the chain with genesis hash {found}")]
+    GenesisHashMismatch {
+        /// The genesis hash of the chain that the extrinsic was created for.
+        expected: String,
+        /// The genesis hash of the chain that it was to be submitted to.
+        found: String,
+    },
+    /// A batch of calls didn't emit one item event per call.
+    #[error("Expected {expected} batch item events, but found {found}")]
+    BatchItemsMismatch {
+        /// The number of calls in the batch.
+        expected: usize,
+        /// The number of item events found.
+        found: usize,
+    },
+    /// The node's transaction pool refused to accept the transaction. The error message given
+    /// by the node is included.
+    #[error("The transaction pool rejected the transaction ({0:?}): {1}")]
+    PoolRejected(PoolRejection, String),
+    /// A bounded call argument has more items than the runtime allows.
+    #[error("{0}")]
+    ArgumentTooLong(Box<ArgumentTooLongError>),
+}
+
+/// A bounded call argument has more items than the runtime allows.
+#[derive(Clone, Debug, Eq, thiserror::Error, PartialEq)]
+#[error(
+    "The {argument} argument to {pallet}::{call} has {len} items, but at most {max} are allowed"
+)]
+#[non_exhaustive]
+pub struct ArgumentTooLongError {
+    /// The pallet name.
+    pub pallet: String,
+    /// The call name.
+    pub call: String,
+    /// The name of the argument.
+    pub argument: String,
+    /// The number of items that the argument has.
+    pub len: usize,
+    /// The most items that the argument can have.
+    pub max: u64,
+}
+
+/// Why the node's transaction pool refused to accept a transaction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum PoolRejection {
+    /// The transaction is invalid; for instance its nonce is stale or it can't pay its fees.
+    Invalid,
+    /// The validity of the transaction couldn't be determined.
+    UnknownValidity,
+    /// The transaction was recently found to be invalid, and is temporarily banned.
+    TemporarilyBanned,
+    /// The same transaction is already in the pool.
+    AlreadyImported,
+    /// A transaction with the same nonce and a higher priority is already in the pool.
+    TooLowPriority,
+    /// The transaction depends on itself via the tags that it requires and provides.
+    CycleDetected,
+    /// The pool is full, and the transaction was dropped straight away.
+    ImmediatelyDropped,
+}
+
+impl PoolRejection {
+    /// Work out why the transaction was rejected from the error message that Substrate's
+    /// `author_` RPC methods give back, if it's a transaction pool error.
+    pub(crate) fn from_message(message: &str) -> Option<Self> {
+        let message = message.to_ascii_lowercase();
+        let reasons = [
+            ("unknown transaction validity", PoolRejection::UnknownValidity),
+            ("invalid transaction", PoolRejection::Invalid),
+            ("temporarily banned", PoolRejection::TemporarilyBanned),
+            ("already imported", PoolRejection::AlreadyImported),
+            ("priority is too low", PoolRejection::TooLowPriority),
+            ("cycle detected", PoolRejection::CycleDetected),
+            ("immediately dropped", PoolRejection::ImmediatelyDropped),
+        ];
+        reasons
+            .into_iter()
+            .find(|(text, _)| message.contains(text))
+            .map(|(_, reason)| reason)
+    }
+}
+
+/// Some bytes to decode don't stay within the configured [`crate::metadata::DecodeLimits`],
+/// or don't line up with the type they are supposed to be.
+#[derive(Clone, Debug, Eq, thiserror::Error, PartialEq)]
+#[non_exhaustive]
+pub enum DecodeLimitError {
+    /// Types were nested more deeply than allowed.
+    #[error("Types are nested more than {0} levels deep")]
+    DepthExceeded(u32),
+    /// A length prefix was larger than allowed.
+    #[error("Length {len} is larger than the maximum of {max}")]
+    LengthExceeded {
+        /// The length given in the bytes.
+        len: u64,
+        /// The maximum length allowed.
+        max: usize,
+    },
+    /// There were fewer bytes left than a value needs.
+    #[error("Need {needed} bytes but only {remaining} are left")]
+    NotEnoughBytes {
+        /// The number of bytes needed.
+        needed: usize,
+        /// The number of bytes remaining.
+        remaining: usize,
+    },
+    /// A type could not be found in the metadata.
+    #[error("Type with ID {0} not found")]
+    TypeNotFound(u32),
+    /// A variant index could not be found in the metadata.
+    #[error("Variant with index {0} not found")]
+    VariantNotFound(u8),
 }
 
 /// Something went wrong trying to encode a storage address.
@@ -2,9 +2,17 @@
 // This file is dual-licensed as Apache-2.0 or GPL-3.0.
 // see LICENSE for license details.
 
-use crate::{client::OnlineClientT, error::Error, events::Events, rpc::types::StorageKey, Config};
+use crate::{
+    client::{OnlineClient, OnlineClientT},
+    error::Error,
+    events::{EventDetails, Events},
+    rpc::types::StorageKey,
+    Config, Metadata,
+};
 use derivative::Derivative;
-use std::future::Future;
+use futures::{future, stream, Stream, TryStreamExt};
+use scale_decode::DecodeAsType;
+use std::{collections::BTreeMap, future::Future, ops::RangeInclusive};
 
 /// A client for working with events.
 #[derive(Derivative)]
@@ -74,6 +82,174 @@ where
     }
 }
 
+impl<T: Config> EventsClient<T, OnlineClient<T>> {
+    /// Obtain events at some block hash, decoded using the metadata in use as of that block
+    /// rather than the metadata that the client is currently using; see
+    /// [`OnlineClient::offline_at()`]. Unlike [`Self::at()`], this works for blocks from
+    /// before the most recent runtime upgrade.
+    pub async fn at_block(&self, block_hash: T::Hash) -> Result<Events<T>, Error> {
+        let metadata = self.client.offline_at(block_hash).await?.metadata();
+        let event_bytes = get_event_bytes(&self.client, Some(block_hash)).await?;
+        Ok(Events::new(metadata, block_hash, event_bytes))
+    }
+
+    /// Total up the fees paid by each of the given accounts in each block of the given
+    /// (inclusive) range of block numbers, handing back a stream of each block number along
+    /// with the totals for that block. Every account given is in each map, even if it paid
+    /// nothing. The stream ends early if the end of the range is beyond the head of the chain.
+    ///
+    /// Fees are read from `TransactionPayment::TransactionFeePaid` events, whose `actual_fee`
+    /// includes any tip. For blocks whose runtime doesn't have that event, the
+    /// `Balances::Withdraw` events are totalled instead, which includes any other withdrawals
+    /// too. Each block is decoded with the metadata in use as of that block, so the range can
+    /// span runtime upgrades. The fees can be any unsigned integer type; an error is handed
+    /// back if the events in some block don't have the fields we expect.
+    ///
+    /// Blocks are fetched one at a time. Each takes two round trips to the node: one for its
+    /// hash, and then one for its runtime version and events. The metadata of each runtime
+    /// version in the range is also fetched the first time that it's seen.
+    pub fn fees_paid_per_block(
+        &self,
+        block_numbers: RangeInclusive<u64>,
+        accounts: &[T::AccountId],
+    ) -> impl Stream<Item = Result<(u64, BTreeMap<T::AccountId, u128>), Error>> + Send + 'static
+    where
+        T::AccountId: DecodeAsType + Ord + Send + Sync,
+    {
+        let client = self.client.clone();
+        let accounts = accounts.to_vec();
+        stream::try_unfold(block_numbers, move |mut block_numbers| {
+            let client = client.clone();
+            let accounts = accounts.clone();
+            async move {
+                let Some(block_number) = block_numbers.next() else {
+                    return Ok(None);
+                };
+                let block_hash = client.rpc().block_hash(Some(block_number.into()));
+                let Some(block_hash) = block_hash.await? else {
+                    return Ok(None);
+                };
+                let (client_at, event_bytes) = future::try_join(
+                    client.offline_at(block_hash),
+                    get_event_bytes(&client, Some(block_hash)),
+                )
+                .await?;
+                let fees = fees_paid_in_block::<T>(
+                    client_at.metadata(),
+                    block_hash,
+                    event_bytes,
+                    &accounts,
+                )?;
+                Ok(Some(((block_number, fees), block_numbers)))
+            }
+        })
+    }
+
+    /// Total up the fees paid by each of the given accounts over the given (inclusive) range
+    /// of block numbers. Every account given is in the returned map, even if it paid nothing.
+    /// Block numbers beyond the head of the chain are ignored.
+    ///
+    /// This waits for every block in the range to be fetched; see
+    /// [`Self::fees_paid_per_block()`] for how fees are found, what that costs, and to see
+    /// the totals as each block is fetched.
+    pub async fn fees_paid(
+        &self,
+        block_numbers: RangeInclusive<u64>,
+        accounts: &[T::AccountId],
+    ) -> Result<BTreeMap<T::AccountId, u128>, Error>
+    where
+        T::AccountId: DecodeAsType + Ord + Send + Sync,
+    {
+        let mut fees: BTreeMap<T::AccountId, u128> = accounts
+            .iter()
+            .map(|account| (account.clone(), 0))
+            .collect();
+
+        let blocks = self.fees_paid_per_block(block_numbers, accounts);
+        futures::pin_mut!(blocks);
+        while let Some((_, block_fees)) = blocks.try_next().await? {
+            for (who, fee) in block_fees {
+                let total = fees.entry(who).or_default();
+                *total = total.saturating_add(fee);
+            }
+        }
+        Ok(fees)
+    }
+}
+
+/// Total up the fees paid by each of the given accounts in the events of one block. See
+/// [`EventsClient::fees_paid_per_block()`].
+fn fees_paid_in_block<T: Config>(
+    metadata: Metadata,
+    block_hash: T::Hash,
+    event_bytes: Vec<u8>,
+    accounts: &[T::AccountId],
+) -> Result<BTreeMap<T::AccountId, u128>, Error>
+where
+    T::AccountId: DecodeAsType + Ord,
+{
+    let mut fees: BTreeMap<T::AccountId, u128> = accounts
+        .iter()
+        .map(|account| (account.clone(), 0))
+        .collect();
+
+    let has_fee_paid_event = metadata
+        .pallet_by_name("TransactionPayment")
+        .and_then(|pallet| pallet.event_variants())
+        .map_or(false, |variants| {
+            variants.iter().any(|v| v.name == "TransactionFeePaid")
+        });
+
+    let events = Events::<T>::new(metadata.clone(), block_hash, event_bytes);
+    for event in events.iter() {
+        let event = event?;
+        let is_fee = match (event.pallet_name(), event.variant_name()) {
+            ("TransactionPayment", "TransactionFeePaid") => true,
+            ("Balances", "Withdraw") => !has_fee_paid_event,
+            _ => false,
+        };
+        if !is_fee {
+            continue;
+        }
+        let (who, fee) = decode_fee::<T>(&metadata, &event)?;
+        if let Some(total) = fees.get_mut(&who) {
+            *total = total.saturating_add(fee);
+        }
+    }
+    Ok(fees)
+}
+
+/// Decode the account and amount from the first two fields of a `TransactionFeePaid` or
+/// `Withdraw` event, according to their types in the metadata.
+fn decode_fee<T: Config>(
+    metadata: &Metadata,
+    event: &EventDetails<T>,
+) -> Result<(T::AccountId, u128), Error>
+where
+    T::AccountId: DecodeAsType,
+{
+    let unexpected = |reason: String| {
+        Error::Other(format!(
+            "Cannot read the fee from the {}::{} event: {reason}",
+            event.pallet_name(),
+            event.variant_name()
+        ))
+    };
+
+    let event_metadata = event.event_metadata();
+    let [who, amount, ..] = event_metadata.variant.fields.as_slice() else {
+        return Err(unexpected(
+            "expected an account and an amount as its first fields".into(),
+        ));
+    };
+    let bytes = &mut event.field_bytes();
+    let who = T::AccountId::decode_as_type(bytes, who.ty.id, metadata.types())
+        .map_err(|e| unexpected(format!("the account doesn't decode: {e}")))?;
+    let amount = u128::decode_as_type(bytes, amount.ty.id, metadata.types())
+        .map_err(|e| unexpected(format!("the amount isn't an unsigned integer: {e}")))?;
+    Ok((who, amount))
+}
+
 // The storage key needed to access events.
 fn system_events_key() -> StorageKey {
     let mut storage_key = sp_core_hashing::twox_128(b"System").to_vec();
@@ -97,3 +273,55 @@ where
         .map(|e| e.0)
         .unwrap_or_else(Vec::new))
 }
+
+#[cfg(test)]
+mod test {
+    use super::decode_fee;
+    use crate::{
+        events::{
+            events_type::test_utils::{event_record, events, metadata},
+            Phase,
+        },
+        SubstrateConfig,
+    };
+    use codec::{Decode, Encode};
+    use scale_info::TypeInfo;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Decode, Encode, TypeInfo)]
+    struct AccountId([u8; 32]);
+
+    #[derive(Clone, Debug, PartialEq, Eq, Decode, Encode, TypeInfo)]
+    enum Event {
+        FeePaid(AccountId, u64, u64),
+        NotAFee(AccountId, bool),
+        NoAmount(AccountId),
+    }
+
+    fn decode(event: Event) -> Result<(crate::utils::AccountId32, u128), crate::Error> {
+        let metadata = metadata::<Event>();
+        let events = events::<Event>(
+            metadata.clone(),
+            vec![event_record(Phase::Finalization, event)],
+        );
+        let event = events.iter().next().unwrap().unwrap();
+        decode_fee::<SubstrateConfig>(&metadata, &event)
+    }
+
+    #[test]
+    fn fees_are_decoded_according_to_the_metadata() {
+        let (who, fee) = decode(Event::FeePaid(AccountId([1; 32]), 1234, 5)).unwrap();
+        assert_eq!(who, crate::utils::AccountId32([1; 32]));
+        assert_eq!(fee, 1234);
+    }
+
+    #[test]
+    fn unexpected_fee_events_are_an_error() {
+        let err = decode(Event::NotAFee(AccountId([1; 32]), true)).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Cannot read the fee from the Test::NotAFee event"),
+            "{err}"
+        );
+        assert!(decode(Event::NoAmount(AccountId([1; 32]))).is_err());
+    }
+}
@@ -235,6 +235,11 @@ impl<T: Config> EventDetails<T> {
 
         // Skip over the bytes belonging to this event.
         for field_metadata in &event_variant.fields {
+            // Don't trust the bytes to be reasonable before we walk over them:
+            metadata
+                .decode_limits()
+                .check(input, field_metadata.ty.id, metadata.types())?;
+
             // Skip over the bytes for this field:
             scale_decode::visitor::decode_with_visitor(
                 input,
@@ -347,6 +352,25 @@ impl<T: Config> EventDetails<T> {
         Ok(decoded)
     }
 
+    /// Like [`Self::field_values()`], but the fields are decoded one at a time, and if one of
+    /// them fails to decode, the fields decoded before it are handed back alongside the error.
+    ///
+    /// This is a best-effort way to salvage what we can from corrupt or unexpected event data
+    /// (for instance when scanning historical blocks); don't rely on it for anything consensus
+    /// critical.
+    pub fn try_field_values(
+        &self,
+    ) -> (
+        scale_value::Composite<scale_value::scale::TypeId>,
+        Option<Error>,
+    ) {
+        crate::dynamic::try_decode_fields(
+            &mut self.field_bytes(),
+            &self.event_metadata().variant.fields,
+            self.metadata.types(),
+        )
+    }
+
     /// Attempt to decode these [`EventDetails`] into a type representing the event fields.
     /// Such types are exposed in the codegen as `pallet_name::events::EventName` types.
     pub fn as_event<E: StaticEvent>(&self) -> Result<Option<E>, Error> {
@@ -575,8 +599,8 @@ mod tests {
         test_utils::{event_record, events, events_raw, AllEvents, EventRecord},
         *,
     };
-    use crate::SubstrateConfig;
-    use codec::Encode;
+    use crate::{error::DecodeLimitError, SubstrateConfig};
+    use codec::{Compact, Encode};
     use primitive_types::H256;
     use scale_info::TypeInfo;
     use scale_value::Value;
@@ -843,6 +867,58 @@ mod tests {
         assert!(events_iter.next().is_none());
     }
 
+    #[test]
+    fn oversized_length_prefix_in_event_fails_gracefully() {
+        #[derive(Clone, Debug, PartialEq, Decode, Encode, TypeInfo)]
+        enum Event {
+            A(Vec<u8>),
+        }
+
+        let metadata = metadata::<Event>();
+
+        // Encode an event with an empty vec, and then swap the (last but one) length byte
+        // for a length prefix which claims that there are u32::MAX bytes in the vec.
+        let mut event_bytes = event_record(Phase::Initialization, Event::A(vec![])).encode();
+        event_bytes.truncate(event_bytes.len() - 2);
+        Compact(u32::MAX).encode_to(&mut event_bytes);
+        event_bytes.extend_from_slice(&[1, 2, 3]);
+
+        let events = events_raw(metadata, event_bytes, 1);
+        let mut events_iter = events.iter();
+        assert!(matches!(
+            events_iter.next().unwrap(),
+            Err(Error::DecodeLimit(DecodeLimitError::LengthExceeded { .. }))
+        ));
+        assert!(events_iter.next().is_none());
+    }
+
+    #[test]
+    fn try_field_values_returns_fields_before_the_failure() {
+        #[derive(Clone, Debug, PartialEq, Decode, Encode, TypeInfo)]
+        enum Event {
+            A(u8, bool, String),
+        }
+
+        let metadata = metadata::<Event>();
+
+        // Swap the bytes of the string for some invalid UTF-8. The event can still be
+        // iterated over, since the string has the length we claim, but it won't decode.
+        let mut event_bytes =
+            event_record(Phase::Initialization, Event::A(1, true, "Hi".into())).encode();
+        // The string bytes come just before the (empty) topics at the end.
+        let string_end = event_bytes.len() - 1;
+        event_bytes[string_end - 2..string_end].copy_from_slice(&[0xff, 0xfe]);
+
+        let events = events_raw(metadata, event_bytes, 1);
+        let ev = events.iter().next().unwrap().unwrap();
+        assert!(ev.field_values().is_err());
+
+        let (fields, error) = ev.try_field_values();
+        let fields: Vec<_> = fields.into_values().map(|v| v.remove_context()).collect();
+        assert_eq!(fields, vec![Value::u128(1), Value::bool(true)]);
+        assert!(error.is_some());
+    }
+
     #[test]
     fn compact_event_field() {
         #[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo)]
@@ -266,6 +266,208 @@ pub mod ext {
 /// )]
 /// mod polkadot {}
 /// ```
+///
+/// ## `emit_tests`
+///
+/// Generate a `#[cfg(test)]` module alongside the calls of each pallet, which checks that each call whose
+/// fields can all be defaulted round-trips through SCALE encoding and decoding.
+///
+/// ```rust,no_run
+/// #[subxt::subxt(
+///     runtime_metadata_path = "../artifacts/polkadot_metadata_full.scale",
+///     emit_tests
+/// )]
+/// mod polkadot {}
+/// ```
+///
+/// ## `deprecations_from_docs`
+///
+/// Mark generated call functions as `#[deprecated]` if the docs for the call contain a `# Deprecated` heading;
+/// the text below the heading is used as the deprecation note.
+///
+/// ```rust,no_run
+/// #[subxt::subxt(
+///     runtime_metadata_path = "../artifacts/polkadot_metadata_full.scale",
+///     deprecations_from_docs
+/// )]
+/// mod polkadot {}
+/// ```
+///
+/// ## `emit_submit_fns`
+///
+/// Alongside each call function on the generated `TransactionApi`, also emit an async `<call>_submit` function
+/// which fetches the nonce, signs and submits the call, and then waits for it to be finalized successfully.
+///
+/// ```rust,no_run
+/// #[subxt::subxt(
+///     runtime_metadata_path = "../artifacts/polkadot_metadata_full.scale",
+///     emit_submit_fns
+/// )]
+/// mod polkadot {}
+/// ```
+///
+/// ## `emit_call_info`
+///
+/// Emit a `call_info()` function on each generated call struct, which returns the name of each argument alongside
+/// a description of its type (as given in the metadata where possible).
+///
+/// ```rust,no_run
+/// #[subxt::subxt(
+///     runtime_metadata_path = "../artifacts/polkadot_metadata_full.scale",
+///     emit_call_info
+/// )]
+/// mod polkadot {}
+/// ```
+///
+/// ## `emit_success_events`
+///
+/// Implement `CallSuccessEvent` for call structs whose success event can be guessed from the names of the call and
+/// the pallet's events (for instance `Balances::transfer` and `Balances::Transfer`), so that the event can be waited for
+/// with `sign_submit_and_watch_for`. Calls where the guess is ambiguous are left alone.
+///
+/// ```rust,no_run
+/// #[subxt::subxt(
+///     runtime_metadata_path = "../artifacts/polkadot_metadata_full.scale",
+///     emit_success_events
+/// )]
+/// mod polkadot {}
+/// ```
+///
+/// ## `impl_into_args`
+///
+/// Have the generated call functions take their arguments as `impl Into<FieldType>`, so that references or other
+/// convertible values can be passed without cloning them first. Numeric arguments are left as they are so that integer
+/// literals still infer their type.
+///
+/// ```rust,no_run
+/// #[subxt::subxt(
+///     runtime_metadata_path = "../artifacts/polkadot_metadata_full.scale",
+///     impl_into_args
+/// )]
+/// mod polkadot {}
+/// ```
+///
+/// ## `emit_unknown_call_variant`
+///
+/// Add an `Unknown { pallet_index, bytes }` variant to the generated outer `Call` enum, which `decode_runtime_call`
+/// falls back to for calls to pallets that are not in the metadata, rather than failing to decode them.
+///
+/// ```rust,no_run
+/// #[subxt::subxt(
+///     runtime_metadata_path = "../artifacts/polkadot_metadata_full.scale",
+///     emit_unknown_call_variant
+/// )]
+/// mod polkadot {}
+/// ```
+///
+/// ## `emit_type_alias_newtypes`
+///
+/// Generate a newtype for each type name (like `BalanceOf<T>`) given to integer call arguments in the metadata, and
+/// have the generated call functions take those arguments as the newtypes, so that a balance can't be passed where a block
+/// number is expected.
+///
+/// ```rust,no_run
+/// #[subxt::subxt(
+///     runtime_metadata_path = "../artifacts/polkadot_metadata_full.scale",
+///     emit_type_alias_newtypes
+/// )]
+/// mod polkadot {}
+/// ```
+///
+/// ## `emit_asserts`
+///
+/// Emit a compile time assertion alongside each call struct which checks that the number of fields passed to it
+/// matches the number of fields that the call has in the metadata.
+///
+/// ```rust,no_run
+/// #[subxt::subxt(
+///     runtime_metadata_path = "../artifacts/polkadot_metadata_full.scale",
+///     emit_asserts
+/// )]
+/// mod polkadot {}
+/// ```
+///
+/// ## `emit_decimal_amount_fns`
+///
+/// Alongside each call function which takes balances, also emit a `<call>_with_decimals` function which takes those
+/// balances as human readable decimal amounts like `"1.5"`, along with the number of decimals of the token, and converts
+/// them into base units using [`utils::parse_amount`].
+///
+/// ```rust,no_run
+/// #[subxt::subxt(
+///     runtime_metadata_path = "../artifacts/polkadot_metadata_full.scale",
+///     emit_decimal_amount_fns
+/// )]
+/// mod polkadot {}
+/// ```
+///
+/// ## `types_mod_name = "..."`
+///
+/// The name of the module that the runtime types are generated into, which defaults to `runtime_types`. Change this
+/// if the module that the macro is used on already has (or needs) an item with that name.
+///
+/// ```rust,no_run
+/// #[subxt::subxt(
+///     runtime_metadata_path = "../artifacts/polkadot_metadata_full.scale",
+///     types_mod_name = "chain_types"
+/// )]
+/// mod polkadot {}
+/// ```
+///
+/// ## `allow_lints(...)`
+///
+/// Replace the lints which are allowed on the generated code (by default `dead_code`, `unused_imports`,
+/// `non_camel_case_types` and `clippy::all`) with those given. An empty list allows nothing.
+///
+/// ```rust,no_run
+/// #[subxt::subxt(
+///     runtime_metadata_path = "../artifacts/polkadot_metadata_full.scale",
+///     allow_lints(dead_code, clippy::all)
+/// )]
+/// mod polkadot {}
+/// ```
+///
+/// ## `pallet_alias(alias = "...", pallet = "...")`
+///
+/// Also make the generated code for a pallet available under another name, which is handy when a runtime renames a
+/// pallet and existing code still refers to it by its old name. A module named after the alias re-exports everything
+/// in the pallet's module, and `tx()`, `storage()` and `constants()` gain a function named after the alias too. The
+/// calls and storage entries are still validated against the pallet's metadata under its current name. This can be
+/// given multiple times.
+///
+/// ```rust,no_run
+/// #[subxt::subxt(
+///     runtime_metadata_path = "../artifacts/polkadot_metadata_full.scale",
+///     pallet_alias(alias = "Currencies", pallet = "Balances")
+/// )]
+/// mod polkadot {}
+///
+/// let transfer = polkadot::tx().currencies().transfer(subxt::utils::MultiAddress::Index(()), 123);
+/// ```
+///
+/// ## `rpc_method(name = "...", signature = "...")`
+///
+/// Generate a typed async function for calling some RPC method that Subxt doesn't know about. The signature
+/// is that of the function to generate; each argument is sent as a param in the order given, and the response
+/// is deserialized into the return type. The functions end up in a `rpc` module, and take the RPC client as their
+/// first argument. Paths in the signature are resolved from within that module.
+///
+/// ```rust,no_run
+/// #[subxt::subxt(
+///     runtime_metadata_path = "../artifacts/polkadot_metadata_full.scale",
+///     rpc_method(
+///         name = "author_hasKey",
+///         signature = "fn has_key(public_key: ::subxt::rpc::types::Bytes, key_type: String) -> bool"
+///     )
+/// )]
+/// mod polkadot {}
+///
+/// # async fn example(api: subxt::OnlineClient<subxt::PolkadotConfig>) -> Result<(), subxt::Error> {
+/// let public_key = vec![0u8; 32].into();
+/// let has_key: bool = polkadot::rpc::has_key(api.rpc(), public_key, "babe".to_owned()).await?;
+/// # Ok(())
+/// # }
+/// ```
 /// ## `no_default_derives`
 ///
 /// By default, the macro will add all derives necessary for the generated code to play nicely with Subxt. Adding this attribute
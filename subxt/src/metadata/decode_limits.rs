@@ -0,0 +1,237 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use crate::error::{DecodeLimitError, Error};
+use codec::{Compact, Decode};
+use scale_info::{PortableRegistry, TypeDef, TypeDefPrimitive};
+
+/// Limits which are checked before decoding events and storage values, so that a hostile
+/// payload (for instance one with a huge length prefix, handed back by a malicious node)
+/// fails to decode gracefully rather than exhausting memory.
+///
+/// The defaults are generous enough that honest payloads will never run into them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeLimits {
+    /// The maximum depth of nested types (composites, variants, sequences and so on)
+    /// that we will descend into.
+    pub max_depth: u32,
+    /// The maximum length of any single sequence, string or bit sequence.
+    pub max_len: usize,
+}
+
+impl Default for DecodeLimits {
+    fn default() -> Self {
+        DecodeLimits {
+            max_depth: 256,
+            max_len: 16 * 1024 * 1024,
+        }
+    }
+}
+
+impl DecodeLimits {
+    /// Check that the bytes given, which are expected to be a SCALE encoded value of
+    /// the given type, stay within these limits. This doesn't allocate anything in
+    /// proportion to the sizes given in the bytes.
+    pub fn check(&self, bytes: &[u8], type_id: u32, types: &PortableRegistry) -> Result<(), Error> {
+        self.check_type(&mut &*bytes, type_id, types, 0)
+    }
+
+    fn check_type(
+        &self,
+        bytes: &mut &[u8],
+        type_id: u32,
+        types: &PortableRegistry,
+        depth: u32,
+    ) -> Result<(), Error> {
+        if depth > self.max_depth {
+            return Err(DecodeLimitError::DepthExceeded(self.max_depth).into());
+        }
+        let ty = types
+            .resolve(type_id)
+            .ok_or(DecodeLimitError::TypeNotFound(type_id))?;
+        let depth = depth + 1;
+
+        match &ty.type_def {
+            TypeDef::Composite(composite) => {
+                for field in &composite.fields {
+                    self.check_type(bytes, field.ty.id, types, depth)?;
+                }
+            }
+            TypeDef::Variant(variant) => {
+                let index = u8::decode(bytes)?;
+                let variant = variant
+                    .variants
+                    .iter()
+                    .find(|v| v.index == index)
+                    .ok_or(DecodeLimitError::VariantNotFound(index))?;
+                for field in &variant.fields {
+                    self.check_type(bytes, field.ty.id, types, depth)?;
+                }
+            }
+            TypeDef::Sequence(sequence) => {
+                let len = self.decode_len(bytes)?;
+                for _ in 0..len {
+                    self.check_type(bytes, sequence.type_param.id, types, depth)?;
+                }
+            }
+            TypeDef::Array(array) => {
+                for _ in 0..array.len {
+                    self.check_type(bytes, array.type_param.id, types, depth)?;
+                }
+            }
+            TypeDef::Tuple(tuple) => {
+                for field in &tuple.fields {
+                    self.check_type(bytes, field.id, types, depth)?;
+                }
+            }
+            TypeDef::Primitive(TypeDefPrimitive::Str) => {
+                let len = self.decode_len(bytes)?;
+                skip(bytes, len)?;
+            }
+            TypeDef::Primitive(primitive) => {
+                skip(bytes, primitive_size(primitive))?;
+            }
+            TypeDef::Compact(_) => {
+                Compact::<u128>::decode(bytes)?;
+            }
+            TypeDef::BitSequence(bit_sequence) => {
+                let num_bits = self.decode_len(bytes)?;
+                let store_size = types
+                    .resolve(bit_sequence.bit_store_type.id)
+                    .and_then(|ty| match &ty.type_def {
+                        TypeDef::Primitive(primitive) => Some(primitive_size(primitive)),
+                        _ => None,
+                    })
+                    .filter(|size| *size > 0)
+                    .ok_or(DecodeLimitError::TypeNotFound(
+                        bit_sequence.bit_store_type.id,
+                    ))?;
+                let store_bits = store_size * 8;
+                let num_stores = num_bits / store_bits + usize::from(num_bits % store_bits != 0);
+                skip(bytes, num_stores * store_size)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Decode a compact length prefix, checking that it is within our limits.
+    fn decode_len(&self, bytes: &mut &[u8]) -> Result<usize, Error> {
+        let len = Compact::<u64>::decode(bytes)?.0;
+        match usize::try_from(len) {
+            Ok(len) if len <= self.max_len => Ok(len),
+            _ => Err(DecodeLimitError::LengthExceeded {
+                len,
+                max: self.max_len,
+            }
+            .into()),
+        }
+    }
+}
+
+/// Skip over `len` bytes, complaining if there aren't enough of them.
+fn skip(bytes: &mut &[u8], len: usize) -> Result<(), Error> {
+    if len > bytes.len() {
+        return Err(DecodeLimitError::NotEnoughBytes {
+            needed: len,
+            remaining: bytes.len(),
+        }
+        .into());
+    }
+    *bytes = &bytes[len..];
+    Ok(())
+}
+
+/// The encoded size of a fixed size primitive (`str` is handled separately).
+fn primitive_size(primitive: &TypeDefPrimitive) -> usize {
+    match primitive {
+        TypeDefPrimitive::Bool | TypeDefPrimitive::U8 | TypeDefPrimitive::I8 => 1,
+        TypeDefPrimitive::U16 | TypeDefPrimitive::I16 => 2,
+        TypeDefPrimitive::Char | TypeDefPrimitive::U32 | TypeDefPrimitive::I32 => 4,
+        TypeDefPrimitive::U64 | TypeDefPrimitive::I64 => 8,
+        TypeDefPrimitive::U128 | TypeDefPrimitive::I128 => 16,
+        TypeDefPrimitive::U256 | TypeDefPrimitive::I256 => 32,
+        TypeDefPrimitive::Str => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codec::Encode;
+    use scale_info::{meta_type, Registry, TypeInfo};
+
+    fn registry_with<T: TypeInfo + 'static>() -> (u32, PortableRegistry) {
+        let mut registry = Registry::new();
+        let id = registry.register_type(&meta_type::<T>()).id;
+        (id, registry.into())
+    }
+
+    #[test]
+    fn honest_values_are_within_the_limits() {
+        #[derive(Encode, TypeInfo)]
+        struct Foo {
+            a: Vec<u8>,
+            b: String,
+            c: Option<(u32, bool)>,
+            #[codec(compact)]
+            d: u128,
+        }
+
+        let (id, types) = registry_with::<Foo>();
+        let bytes = Foo {
+            a: vec![1, 2, 3],
+            b: "hello".into(),
+            c: Some((1, true)),
+            d: u128::MAX,
+        }
+        .encode();
+
+        DecodeLimits::default().check(&bytes, id, &types).unwrap();
+    }
+
+    #[test]
+    fn oversized_length_prefix_is_rejected() {
+        let (id, types) = registry_with::<Vec<u8>>();
+
+        // Claim that there are u32::MAX bytes following, but provide only a couple.
+        let mut bytes = Compact(u32::MAX).encode();
+        bytes.extend([1, 2]);
+
+        let err = DecodeLimits::default()
+            .check(&bytes, id, &types)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::DecodeLimit(DecodeLimitError::LengthExceeded { len, .. })
+                if len == u64::from(u32::MAX)
+        ));
+
+        // Within the length limit, but longer than the bytes we have.
+        let limits = DecodeLimits {
+            max_len: usize::MAX,
+            ..Default::default()
+        };
+        assert!(matches!(
+            limits.check(&bytes, id, &types).unwrap_err(),
+            Error::DecodeLimit(DecodeLimitError::NotEnoughBytes { .. })
+        ));
+    }
+
+    #[test]
+    fn deeply_nested_values_are_rejected() {
+        let (id, types) = registry_with::<Vec<Vec<Vec<u8>>>>();
+        let bytes = vec![vec![vec![1u8]]].encode();
+
+        let limits = DecodeLimits {
+            max_depth: 2,
+            ..Default::default()
+        };
+        assert!(matches!(
+            limits.check(&bytes, id, &types).unwrap_err(),
+            Error::DecodeLimit(DecodeLimitError::DepthExceeded(2))
+        ));
+        DecodeLimits::default().check(&bytes, id, &types).unwrap();
+    }
+}
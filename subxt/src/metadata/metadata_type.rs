@@ -2,6 +2,7 @@
 // This file is dual-licensed as Apache-2.0 or GPL-3.0.
 // see LICENSE for license details.
 
+use super::DecodeLimits;
 use crate::error::MetadataError;
 use std::sync::Arc;
 
@@ -9,6 +10,7 @@ use std::sync::Arc;
 #[derive(Clone, Debug)]
 pub struct Metadata {
     inner: Arc<subxt_metadata::Metadata>,
+    decode_limits: DecodeLimits,
 }
 
 impl std::ops::Deref for Metadata {
@@ -22,9 +24,26 @@ impl Metadata {
     pub(crate) fn new(md: subxt_metadata::Metadata) -> Self {
         Metadata {
             inner: Arc::new(md),
+            decode_limits: DecodeLimits::default(),
         }
     }
 
+    /// Set the limits which event and storage value bytes are checked against before
+    /// they are decoded.
+    ///
+    /// Prefer [`crate::OnlineClient::set_decode_limits()`] to set limits on a client, so that
+    /// they're kept for metadata which is fetched after a runtime upgrade.
+    pub fn with_decode_limits(mut self, decode_limits: DecodeLimits) -> Self {
+        self.decode_limits = decode_limits;
+        self
+    }
+
+    /// The limits which event and storage value bytes are checked against before
+    /// they are decoded.
+    pub fn decode_limits(&self) -> DecodeLimits {
+        self.decode_limits
+    }
+
     /// Identical to `metadata.pallet_by_name()`, but returns an error if the pallet is not found.
     pub fn pallet_by_name_err(
         &self,
@@ -5,9 +5,11 @@
 //! Types representing the metadata obtained from a node.
 
 mod decode_encode_traits;
+mod decode_limits;
 mod metadata_type;
 
 pub use decode_encode_traits::{DecodeWithMetadata, EncodeWithMetadata};
+pub use decode_limits::DecodeLimits;
 pub use metadata_type::Metadata;
 
 // Expose metadata types under a sub module in case somebody needs to reference them:
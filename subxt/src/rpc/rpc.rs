@@ -36,7 +36,7 @@ use std::sync::Arc;
 use codec::{Decode, Encode};
 use serde::Serialize;
 
-use crate::{error::Error, utils::PhantomDataSendSync, Config, Metadata};
+use crate::{client::MetricsRecorder, error::Error, utils::PhantomDataSendSync, Config, Metadata};
 
 use super::{
     rpc_params,
@@ -77,6 +77,12 @@ impl<T: Config> Rpc<T> {
         }
     }
 
+    /// Report RPC request metrics to the given [`MetricsRecorder`].
+    pub fn with_metrics_recorder(mut self, recorder: Arc<dyn MetricsRecorder>) -> Self {
+        self.client.set_metrics_recorder(recorder);
+        self
+    }
+
     /// Fetch the raw bytes for a given storage key
     pub async fn storage(
         &self,
@@ -88,6 +94,31 @@ impl<T: Config> Rpc<T> {
         Ok(data)
     }
 
+    /// Fetch the raw bytes for a given key in a child trie. The child storage key must be
+    /// prefixed; see [`crate::storage::ChildInfo::prefixed_storage_key()`].
+    pub async fn child_storage(
+        &self,
+        child_storage_key: &[u8],
+        key: &[u8],
+        hash: Option<T::Hash>,
+    ) -> Result<Option<types::StorageData>, Error> {
+        let params = rpc_params![to_hex(child_storage_key), to_hex(key), hash];
+        let data = self.client.request("childstate_getStorage", params).await?;
+        Ok(data)
+    }
+
+    /// Fetch the size in bytes of the value stored at a given storage key, without fetching
+    /// the value itself. Returns `None` if there is no value at the key.
+    pub async fn storage_size(
+        &self,
+        key: &[u8],
+        hash: Option<T::Hash>,
+    ) -> Result<Option<u64>, Error> {
+        let params = rpc_params![to_hex(key), hash];
+        let size = self.client.request("state_getStorageSize", params).await?;
+        Ok(size)
+    }
+
     /// Returns the keys with prefix with pagination support.
     /// Up to `count` keys will be returned.
     /// If `start_key` is passed, return next keys in storage in lexicographic order.
@@ -159,6 +190,12 @@ impl<T: Config> Rpc<T> {
             .await
     }
 
+    /// Fetch the names of the RPC methods that the node supports.
+    pub async fn rpc_methods(&self) -> Result<Vec<String>, Error> {
+        let methods: types::RpcMethods = self.client.request("rpc_methods", rpc_params![]).await?;
+        Ok(methods.methods)
+    }
+
     /// Fetch system health
     pub async fn system_health(&self) -> Result<types::Health, Error> {
         self.client.request("system_health", rpc_params![]).await
@@ -179,6 +216,33 @@ impl<T: Config> Rpc<T> {
         self.client.request("system_version", rpc_params![]).await
     }
 
+    /// Fetch the base58 encoded peer ID of the node.
+    pub async fn system_local_peer_id(&self) -> Result<String, Error> {
+        self.client
+            .request("system_localPeerId", rpc_params![])
+            .await
+    }
+
+    /// Fetch the multiaddresses that the node is listening on for peer connections. These
+    /// include the node's peer ID, and so can be handed to other nodes as bootnodes.
+    pub async fn system_local_listen_addresses(&self) -> Result<Vec<String>, Error> {
+        self.client
+            .request("system_localListenAddresses", rpc_params![])
+            .await
+    }
+
+    /// Fetch the peers that the node is connected to. This is an unsafe method.
+    pub async fn system_peers(&self) -> Result<Vec<types::PeerInfo<T::Hash>>, Error> {
+        self.client.request("system_peers", rpc_params![]).await
+    }
+
+    /// Fetch the state of the node's networking. This is an unsafe method.
+    pub async fn system_network_state(&self) -> Result<types::NetworkState, Error> {
+        self.client
+            .request("system_networkState", rpc_params![])
+            .await
+    }
+
     /// Fetch the current nonce for the given account ID.
     pub async fn system_account_next_index<AccountId: Serialize>(
         &self,
@@ -225,6 +289,16 @@ impl<T: Config> Rpc<T> {
         Ok(block)
     }
 
+    /// Fetch the state of the GRANDPA rounds that the node is currently voting in, via
+    /// `grandpa_roundState`.
+    pub async fn grandpa_round_state(&self) -> Result<types::GrandpaReportedRoundStates, Error> {
+        let round_state = self
+            .client
+            .request("grandpa_roundState", rpc_params![])
+            .await?;
+        Ok(round_state)
+    }
+
     /// Reexecute the specified `block_hash` and gather statistics while doing so.
     ///
     /// This function requires the specified block and its parent to be available
@@ -239,6 +313,21 @@ impl<T: Config> Rpc<T> {
         Ok(stats)
     }
 
+    /// Re-execute the given block via the unsafe `state_traceBlock` method, recording the
+    /// spans and events emitted under the given comma separated tracing `targets` (for
+    /// instance `"pallet,frame,state"`), and the storage events for the given comma separated
+    /// hex encoded `storage_keys`.
+    pub async fn trace_block(
+        &self,
+        block_hash: T::Hash,
+        targets: Option<&str>,
+        storage_keys: Option<&str>,
+    ) -> Result<types::TraceBlockResponse, Error> {
+        let params = rpc_params![block_hash, targets, storage_keys];
+        let trace = self.client.request("state_traceBlock", params).await?;
+        Ok(trace)
+    }
+
     /// Get proof of storage entries at a specific block's state.
     pub async fn read_proof(
         &self,
@@ -334,6 +423,24 @@ impl<T: Config> Rpc<T> {
         Ok(subscription)
     }
 
+    /// Subscribe to changes to the given storage keys. Each item is the set of changes to
+    /// those keys in some block, starting with their current values.
+    pub async fn subscribe_storage(
+        &self,
+        keys: impl IntoIterator<Item = &[u8]>,
+    ) -> Result<Subscription<types::StorageChangeSet<T::Hash>>, Error> {
+        let keys: Vec<String> = keys.into_iter().map(to_hex).collect();
+        let subscription = self
+            .client
+            .subscribe(
+                "state_subscribeStorage",
+                rpc_params![keys],
+                "state_unsubscribeStorage",
+            )
+            .await?;
+        Ok(subscription)
+    }
+
     /// Create and submit an extrinsic and return corresponding Hash if successful
     pub async fn submit_extrinsic<X: Encode>(&self, extrinsic: X) -> Result<T::Hash, Error> {
         let bytes: types::Bytes = extrinsic.encode().into();
@@ -404,6 +511,16 @@ impl<T: Config> Rpc<T> {
         Ok(metadata)
     }
 
+    /// Fetch the metadata in use as of the given block, by calling into the
+    /// `Metadata_metadata` method of the runtime at that block.
+    pub async fn metadata_at_block(&self, at: T::Hash) -> Result<Metadata, Error> {
+        let bytes: frame_metadata::OpaqueMetadata =
+            self.state_call("Metadata_metadata", None, Some(at)).await?;
+
+        let metadata: Metadata = Decode::decode(&mut &bytes.0[..])?;
+        Ok(metadata)
+    }
+
     /// Create and submit an extrinsic and return a subscription to the events triggered.
     pub async fn watch_extrinsic<X: Encode>(
         &self,
@@ -441,6 +558,27 @@ impl<T: Config> Rpc<T> {
             .await
     }
 
+    /// Fetch the extrinsics which are currently in the node's transaction pool and ready to be
+    /// included in a block.
+    pub async fn pending_extrinsics(&self) -> Result<Vec<types::ChainBlockExtrinsic>, Error> {
+        self.client
+            .request("author_pendingExtrinsics", rpc_params![])
+            .await
+    }
+
+    /// Remove the given extrinsics from the node's transaction pool (and ban them from
+    /// re-entering it for a while), returning the hashes of the extrinsics that were removed.
+    ///
+    /// This is an unsafe RPC method, so nodes only allow it to be called over a local
+    /// connection or when run with `--rpc-methods unsafe`.
+    pub async fn remove_extrinsic(
+        &self,
+        extrinsics: Vec<types::ExtrinsicOrHash<T::Hash>>,
+    ) -> Result<Vec<T::Hash>, Error> {
+        let params = rpc_params![extrinsics];
+        self.client.request("author_removeExtrinsic", params).await
+    }
+
     /// Checks if the keystore has private keys for the given session public keys.
     ///
     /// `session_keys` is the SCALE encoded session keys object from the runtime.
@@ -3,11 +3,15 @@
 // see LICENSE for license details.
 
 use super::{RpcClientT, RpcSubscription, RpcSubscriptionId};
-use crate::error::Error;
+use crate::{
+    client::{MetricsRecorder, NoopMetricsRecorder, Stopwatch},
+    error::Error,
+};
 use futures::{Stream, StreamExt};
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::value::RawValue;
 use std::{pin::Pin, sync::Arc, task::Poll};
+use tracing::Instrument;
 
 /// A concrete wrapper around an [`RpcClientT`] which exposes the udnerlying interface via some
 /// higher level methods that make it a little easier to work with.
@@ -15,11 +19,38 @@ use std::{pin::Pin, sync::Arc, task::Poll};
 /// Wrapping [`RpcClientT`] in this way is simply a way to expose this additional functionality
 /// without getting into issues with non-object-safe methods or no `async` in traits.
 #[derive(Clone)]
-pub struct RpcClient(Arc<dyn RpcClientT>);
+pub struct RpcClient {
+    client: Arc<dyn RpcClientT>,
+    metrics: Arc<dyn MetricsRecorder>,
+    // Whether a recorder other than the default no-op one was given.
+    records_metrics: bool,
+}
 
 impl RpcClient {
     pub(crate) fn new<R: RpcClientT>(client: Arc<R>) -> Self {
-        RpcClient(client)
+        RpcClient {
+            client,
+            metrics: Arc::new(NoopMetricsRecorder),
+            records_metrics: false,
+        }
+    }
+
+    /// Report RPC request metrics (and anything else that makes use of this client) to
+    /// the given [`MetricsRecorder`].
+    pub(crate) fn set_metrics_recorder(&mut self, metrics: Arc<dyn MetricsRecorder>) {
+        self.metrics = metrics;
+        self.records_metrics = true;
+    }
+
+    /// Has a [`MetricsRecorder`] been given? If not, there's no need to work out the details
+    /// of anything to record, since they'd be thrown away.
+    pub(crate) fn records_metrics(&self) -> bool {
+        self.records_metrics
+    }
+
+    /// The [`MetricsRecorder`] that this client reports to.
+    pub fn metrics_recorder(&self) -> &dyn MetricsRecorder {
+        &*self.metrics
     }
 
     /// Make an RPC request, given a method name and some parameters.
@@ -31,8 +62,16 @@ impl RpcClient {
         method: &str,
         params: RpcParams,
     ) -> Result<Res, Error> {
-        let res = self.0.request_raw(method, params.build()).await?;
-        let val = serde_json::from_str(res.get())?;
+        let span = tracing::debug_span!("rpc_request", method);
+        let stopwatch = Stopwatch::start();
+        let res = self
+            .client
+            .request_raw(method, params.build())
+            .instrument(span)
+            .await;
+        self.metrics
+            .record_rpc_request(method, stopwatch.elapsed(), res.is_ok());
+        let val = serde_json::from_str(res?.get())?;
         Ok(val)
     }
 
@@ -47,7 +86,16 @@ impl RpcClient {
         params: RpcParams,
         unsub: &str,
     ) -> Result<Subscription<Res>, Error> {
-        let sub = self.0.subscribe_raw(sub, params.build(), unsub).await?;
+        let span = tracing::debug_span!("rpc_subscribe", method = sub);
+        let stopwatch = Stopwatch::start();
+        let res = self
+            .client
+            .subscribe_raw(sub, params.build(), unsub)
+            .instrument(span)
+            .await;
+        self.metrics
+            .record_rpc_request(sub, stopwatch.elapsed(), res.is_ok());
+        let sub = res?;
         Ok(Subscription::new(sub))
     }
 }
@@ -61,7 +109,7 @@ impl std::fmt::Debug for RpcClient {
 impl std::ops::Deref for RpcClient {
     type Target = dyn RpcClientT;
     fn deref(&self) -> &Self::Target {
-        &*self.0
+        &*self.client
     }
 }
 
@@ -109,6 +109,166 @@ pub type ConsensusEngineId = [u8; 4];
 /// The encoded justification specific to a consensus engine.
 pub type EncodedJustification = Vec<u8>;
 
+/// The [`ConsensusEngineId`] of GRANDPA justifications.
+pub const GRANDPA_ENGINE_ID: ConsensusEngineId = *b"FRNK";
+
+/// A decoded GRANDPA justification, which proves that a block has been finalized. This is
+/// generic over the block hash, block number and header types of the chain.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct GrandpaJustification<Hash, Number, Header> {
+    /// The round in which the block was finalized.
+    pub round: u64,
+    /// The commit message of the round, containing the signed precommits.
+    pub commit: GrandpaCommit<Hash, Number>,
+    /// The headers needed to show that each precommit target descends from the commit target.
+    pub votes_ancestries: Vec<Header>,
+}
+
+/// The precommits of a GRANDPA round for a given target block.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct GrandpaCommit<Hash, Number> {
+    /// The hash of the block being finalized.
+    pub target_hash: Hash,
+    /// The number of the block being finalized.
+    pub target_number: Number,
+    /// The precommits for the target block (or its descendants), along with their signatures.
+    pub precommits: Vec<GrandpaSignedPrecommit<Hash, Number>>,
+}
+
+/// A GRANDPA precommit signed by one of the authorities.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct GrandpaSignedPrecommit<Hash, Number> {
+    /// The block being precommitted to.
+    pub precommit: GrandpaPrecommit<Hash, Number>,
+    /// The ed25519 signature of the authority over the precommit.
+    pub signature: [u8; 64],
+    /// The ed25519 public key of the authority.
+    pub id: [u8; 32],
+}
+
+/// A GRANDPA precommit for some block.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct GrandpaPrecommit<Hash, Number> {
+    /// The hash of the block.
+    pub target_hash: Hash,
+    /// The number of the block.
+    pub target_number: Number,
+}
+
+/// The response from `grandpa_roundState`, describing the state of the GRANDPA rounds that
+/// the node is voting in.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GrandpaReportedRoundStates {
+    /// The current authority set ID.
+    pub set_id: u32,
+    /// The state of the best (latest) round.
+    pub best: GrandpaRoundState,
+    /// The state of any earlier rounds that are still being tracked.
+    pub background: Vec<GrandpaRoundState>,
+}
+
+/// The state of a single GRANDPA round.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GrandpaRoundState {
+    /// The round number.
+    pub round: u32,
+    /// The total weight of all authorities.
+    pub total_weight: u32,
+    /// The weight needed for a supermajority.
+    pub threshold_weight: u32,
+    /// The prevotes seen so far.
+    pub prevotes: GrandpaVotes,
+    /// The precommits seen so far.
+    pub precommits: GrandpaVotes,
+}
+
+/// The prevotes or precommits seen in a GRANDPA round.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GrandpaVotes {
+    /// The weight of the authorities which have voted.
+    pub current_weight: u32,
+    /// The SS58 addresses of the authorities which have not yet voted.
+    pub missing: Vec<String>,
+}
+
+/// The response from `state_traceBlock`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TraceBlockResponse {
+    /// The node couldn't trace the block.
+    TraceError(TraceError),
+    /// The spans and events recorded while re-executing the block.
+    BlockTrace(BlockTrace),
+}
+
+/// The reason that a block couldn't be traced.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceError {
+    /// The error message.
+    pub error: String,
+}
+
+/// The spans and events recorded while re-executing a block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockTrace {
+    /// The hash of the block that was traced.
+    pub block_hash: String,
+    /// The hash of the parent block.
+    pub parent_hash: String,
+    /// The tracing targets that were asked for.
+    pub tracing_targets: String,
+    /// The storage keys that were asked for.
+    pub storage_keys: String,
+    /// The methods that were asked for.
+    #[serde(default)]
+    pub methods: String,
+    /// The spans recorded, in the order that they were exited.
+    pub spans: Vec<TraceSpan>,
+    /// The events recorded, in the order that they happened.
+    pub events: Vec<TraceEvent>,
+}
+
+/// A span of execution recorded while tracing a block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceSpan {
+    /// The ID of this span, which events and child spans refer to.
+    pub id: u64,
+    /// The ID of the span that this one is within, if any.
+    pub parent_id: Option<u64>,
+    /// The name of the span.
+    pub name: String,
+    /// The tracing target that the span was recorded under.
+    pub target: String,
+    /// Was the span recorded from within the WASM runtime?
+    pub wasm: bool,
+}
+
+/// An event recorded while tracing a block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceEvent {
+    /// The tracing target that the event was recorded under.
+    pub target: String,
+    /// The values recorded with the event.
+    pub data: TraceEventData,
+    /// The ID of the span that the event happened within, if any.
+    pub parent_id: Option<u64>,
+}
+
+/// The values recorded with a [`TraceEvent`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceEventData {
+    /// Each value, by name. For storage events, these include the `key` and `value`.
+    pub string_values: HashMap<String, String>,
+}
+
 /// Bytes representing an extrinsic in a [`ChainBlock`].
 #[derive(Clone, Debug)]
 pub struct ChainBlockExtrinsic(pub Vec<u8>);
@@ -125,6 +285,17 @@ impl<'a> ::serde::Deserialize<'a> for ChainBlockExtrinsic {
     }
 }
 
+/// Identify an extrinsic in the transaction pool, either by its hash or by its encoded bytes.
+/// Handed to [`crate::rpc::Rpc::remove_extrinsic()`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExtrinsicOrHash<Hash> {
+    /// The hash of the extrinsic.
+    Hash(Hash),
+    /// The encoded extrinsic.
+    Extrinsic(Bytes),
+}
+
 /// Wrapper for NumberOrHex to allow custom From impls
 #[derive(Serialize)]
 pub struct BlockNumber(NumberOrHex);
@@ -230,9 +401,72 @@ macro_rules! into_block_number {
 }
 into_block_number!(u8 u16 u32 u64);
 
+/// The response from `rpc_methods`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RpcMethods {
+    /// The version of the response format.
+    pub version: u32,
+    /// The names of the supported RPC methods.
+    pub methods: Vec<String>,
+}
+
 /// Arbitrary properties defined in the chain spec as a JSON object.
 pub type SystemProperties = serde_json::Map<String, serde_json::Value>;
 
+/// The commonly used properties of a chain, decoded from its [`SystemProperties`].
+///
+/// Chains with multiple tokens provide `tokenDecimals` and `tokenSymbol` as arrays, while others
+/// provide single values; both forms are accepted, and the native token comes first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChainProperties {
+    /// The number of decimals of each token.
+    pub token_decimals: Vec<u32>,
+    /// The symbol of each token.
+    pub token_symbols: Vec<String>,
+    /// The SS58 network prefix used for addresses, if any.
+    pub ss58_format: Option<u16>,
+}
+
+impl ChainProperties {
+    /// Decode the known properties from some [`SystemProperties`], ignoring any
+    /// properties which are missing or malformed.
+    pub fn from_system_properties(properties: &SystemProperties) -> Self {
+        fn one_or_many<'a, T>(
+            value: Option<&'a serde_json::Value>,
+            f: impl Fn(&'a serde_json::Value) -> Option<T>,
+        ) -> Vec<T> {
+            match value {
+                Some(serde_json::Value::Array(values)) => values.iter().filter_map(f).collect(),
+                Some(value) => f(value).into_iter().collect(),
+                None => Vec::new(),
+            }
+        }
+
+        ChainProperties {
+            token_decimals: one_or_many(properties.get("tokenDecimals"), |v| {
+                v.as_u64().and_then(|d| u32::try_from(d).ok())
+            }),
+            token_symbols: one_or_many(properties.get("tokenSymbol"), |v| {
+                v.as_str().map(ToOwned::to_owned)
+            }),
+            ss58_format: properties
+                .get("ss58Format")
+                .and_then(|v| v.as_u64())
+                .and_then(|f| u16::try_from(f).ok()),
+        }
+    }
+
+    /// The number of decimals of the native token, if known.
+    pub fn native_token_decimals(&self) -> Option<u32> {
+        self.token_decimals.first().copied()
+    }
+
+    /// The symbol of the native token, if known.
+    pub fn native_token_symbol(&self) -> Option<&str> {
+        self.token_symbols.first().map(|s| s.as_str())
+    }
+}
+
 /// Possible transaction status events.
 ///
 /// # Note
@@ -374,6 +608,36 @@ pub struct Health {
     pub should_have_peers: bool,
 }
 
+/// A peer that the node is connected to, as returned by `system_peers`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeerInfo<Hash> {
+    /// The base58 encoded peer ID.
+    pub peer_id: String,
+    /// The roles of the peer, for instance `FULL` or `AUTHORITY`.
+    pub roles: String,
+    /// The hash of the peer's best block.
+    pub best_hash: Hash,
+    /// The number of the peer's best block.
+    pub best_number: u64,
+}
+
+/// The state of the node's networking, as returned by `system_networkState`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkState {
+    /// The base58 encoded peer ID of the node.
+    pub peer_id: String,
+    /// The multiaddresses that the node is listening on.
+    pub listened_addresses: Vec<String>,
+    /// The multiaddresses that the node believes others can reach it on.
+    pub external_addresses: Vec<String>,
+    /// The other fields present (for instance details of each connected peer) vary between
+    /// node versions, and so are preserved here as they are.
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
 /// The operation could not be processed due to an error.
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -744,6 +1008,178 @@ mod as_string {
 mod test {
     use super::*;
 
+    #[test]
+    fn grandpa_round_state_deserializes() {
+        let json = r#"{
+            "setId": 2,
+            "best": {
+                "round": 33,
+                "totalWeight": 1,
+                "thresholdWeight": 1,
+                "prevotes": { "currentWeight": 1, "missing": [] },
+                "precommits": {
+                    "currentWeight": 0,
+                    "missing": ["5FA9nQDVg267DEd8m1ZypXLBnvN7SFxYwV7ndqSYGiN9TTpu"]
+                }
+            },
+            "background": []
+        }"#;
+        let states: GrandpaReportedRoundStates = serde_json::from_str(json).unwrap();
+        assert_eq!(states.set_id, 2);
+        assert_eq!(states.best.round, 33);
+        assert_eq!(states.best.prevotes.current_weight, 1);
+        assert_eq!(
+            states.best.precommits.missing,
+            vec!["5FA9nQDVg267DEd8m1ZypXLBnvN7SFxYwV7ndqSYGiN9TTpu".to_owned()]
+        );
+        assert!(states.background.is_empty());
+    }
+
+    #[test]
+    fn peers_and_network_state_deserialize() {
+        let json = r#"[{
+            "peerId": "12D3KooWEyoppNCUx8Yx66oV9fJnriXwCcXwDDUA2kj6vnc6iDEp",
+            "roles": "AUTHORITY",
+            "bestHash": "0xab",
+            "bestNumber": 42
+        }]"#;
+        let peers: Vec<PeerInfo<String>> = serde_json::from_str(json).unwrap();
+        assert_eq!(peers[0].roles, "AUTHORITY");
+        assert_eq!(peers[0].best_number, 42);
+
+        let json = r#"{
+            "peerId": "12D3KooWEyoppNCUx8Yx66oV9fJnriXwCcXwDDUA2kj6vnc6iDEp",
+            "listenedAddresses": ["/ip4/127.0.0.1/tcp/30333"],
+            "externalAddresses": [],
+            "connectedPeers": {},
+            "notConnectedPeers": {}
+        }"#;
+        let state: NetworkState = serde_json::from_str(json).unwrap();
+        assert_eq!(state.listened_addresses, vec!["/ip4/127.0.0.1/tcp/30333"]);
+        assert!(state.external_addresses.is_empty());
+        assert!(state.other.contains_key("connectedPeers"));
+    }
+
+    #[test]
+    fn trace_block_response_deserializes() {
+        let json = r#"{
+            "blockTrace": {
+                "blockHash": "0xaaaa",
+                "parentHash": "0xbbbb",
+                "tracingTargets": "state",
+                "storageKeys": "",
+                "methods": "",
+                "spans": [
+                    { "id": 2, "parentId": 1, "name": "put", "target": "state", "wasm": true }
+                ],
+                "events": [
+                    {
+                        "target": "state",
+                        "data": { "stringValues": { "key": "26aa", "value": "0x00" } },
+                        "parentId": 2
+                    }
+                ]
+            }
+        }"#;
+        let TraceBlockResponse::BlockTrace(trace) = serde_json::from_str(json).unwrap() else {
+            panic!("expected a block trace");
+        };
+        assert_eq!(trace.block_hash, "0xaaaa");
+        assert_eq!(trace.spans[0].parent_id, Some(1));
+        assert!(trace.spans[0].wasm);
+        assert_eq!(trace.events[0].parent_id, Some(2));
+        assert_eq!(trace.events[0].data.string_values["key"], "26aa");
+
+        let json = r#"{ "traceError": { "error": "Invalid block" } }"#;
+        let response: TraceBlockResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            response,
+            TraceBlockResponse::TraceError(TraceError {
+                error: "Invalid block".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn grandpa_justification_decodes_from_its_encoding() {
+        use codec::Compact;
+
+        // Numbers are not compact encoded, and the precommit comes before its signature:
+        let mut encoded = 7u64.encode();
+        encoded.extend([1u8; 32]);
+        encoded.extend(100u32.encode());
+        encoded.extend(Compact(1u32).encode());
+        encoded.extend([1u8; 32]);
+        encoded.extend(100u32.encode());
+        encoded.extend([2u8; 64]);
+        encoded.extend([3u8; 32]);
+        encoded.extend(Compact(0u32).encode());
+
+        let justification =
+            GrandpaJustification::<[u8; 32], u32, Vec<u8>>::decode(&mut &*encoded).unwrap();
+        let precommit = GrandpaPrecommit {
+            target_hash: [1u8; 32],
+            target_number: 100,
+        };
+        let expected = GrandpaJustification {
+            round: 7,
+            commit: GrandpaCommit {
+                target_hash: [1u8; 32],
+                target_number: 100,
+                precommits: vec![GrandpaSignedPrecommit {
+                    precommit,
+                    signature: [2u8; 64],
+                    id: [3u8; 32],
+                }],
+            },
+            votes_ancestries: vec![],
+        };
+        assert_eq!(justification, expected);
+    }
+
+    #[test]
+    fn chain_properties_from_scalar_and_array_forms() {
+        let scalar: SystemProperties = serde_json::from_str(
+            r#"{ "ss58Format": 0, "tokenDecimals": 10, "tokenSymbol": "DOT" }"#,
+        )
+        .unwrap();
+        let properties = ChainProperties::from_system_properties(&scalar);
+        assert_eq!(
+            properties,
+            ChainProperties {
+                token_decimals: vec![10],
+                token_symbols: vec!["DOT".to_owned()],
+                ss58_format: Some(0),
+            }
+        );
+
+        let array: SystemProperties = serde_json::from_str(
+            r#"{ "ss58Format": 8, "tokenDecimals": [12, 12], "tokenSymbol": ["KAR", "KUSD"] }"#,
+        )
+        .unwrap();
+        let properties = ChainProperties::from_system_properties(&array);
+        assert_eq!(properties.native_token_decimals(), Some(12));
+        assert_eq!(properties.native_token_symbol(), Some("KAR"));
+        assert_eq!(properties.token_symbols, vec!["KAR", "KUSD"]);
+        assert_eq!(properties.ss58_format, Some(8));
+
+        let empty = SystemProperties::new();
+        assert_eq!(
+            ChainProperties::from_system_properties(&empty),
+            ChainProperties::default()
+        );
+    }
+
+    #[test]
+    fn extrinsic_or_hash_serializes_like_substrate() {
+        let hash = ExtrinsicOrHash::Hash(sp_core::H256::repeat_byte(1));
+        let hash_json = format!(r#"{{"hash":"0x{}"}}"#, "01".repeat(32));
+        assert_deser(&hash_json, hash);
+
+        let extrinsic = ExtrinsicOrHash::<sp_core::H256>::Extrinsic(Bytes(vec![1, 2, 3]));
+        assert_deser(r#"{"extrinsic":"0x010203"}"#, extrinsic);
+    }
+
     /// A util function to assert the result of serialization and deserialization is the same.
     pub fn assert_deser<T>(s: &str, expected: T)
     where
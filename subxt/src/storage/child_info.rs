@@ -0,0 +1,68 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+/// The prefix given to the storage key of every default child trie.
+const DEFAULT_CHILD_STORAGE_KEY_PREFIX: &[u8] = b":child_storage:default:";
+
+/// Identifies a child trie, so that the storage within it can be read via
+/// [`super::Storage::fetch_child()`] and friends.
+///
+/// Pallets choose the storage key of their child tries themselves, so check the pallet to
+/// see how it's constructed. For instance, the crowdloan pallet stores each fund's
+/// contributions in a child trie whose storage key is the blake2 hash of
+/// `(b"crowdloan", fund_index).encode()`:
+///
+/// ```rust
+/// use subxt::config::{substrate::BlakeTwo256, Hasher};
+/// use subxt::ext::codec::Encode;
+/// use subxt::storage::ChildInfo;
+///
+/// let fund_index: u32 = 7;
+/// let storage_key = BlakeTwo256::hash(&(b"crowdloan", fund_index).encode());
+/// let child_info = ChildInfo::new_default(storage_key.as_bytes());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChildInfo {
+    storage_key: Vec<u8>,
+}
+
+impl ChildInfo {
+    /// Identify a default child trie (the only kind that Substrate currently supports)
+    /// by the storage key that the pallet gives it, without any prefix.
+    pub fn new_default(storage_key: impl Into<Vec<u8>>) -> Self {
+        ChildInfo {
+            storage_key: storage_key.into(),
+        }
+    }
+
+    /// The storage key of the child trie, without any prefix.
+    pub fn storage_key(&self) -> &[u8] {
+        &self.storage_key
+    }
+
+    /// The storage key of the child trie prefixed with `:child_storage:default:`, which is
+    /// the form that the `childstate_` RPC methods expect it in.
+    pub fn prefixed_storage_key(&self) -> Vec<u8> {
+        let mut key = DEFAULT_CHILD_STORAGE_KEY_PREFIX.to_vec();
+        key.extend_from_slice(&self.storage_key);
+        key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefixed_storage_key_matches_substrate() {
+        let child_info = ChildInfo::new_default(b"my_child".to_vec());
+        let expected = sp_core::storage::ChildInfo::new_default(b"my_child");
+
+        assert_eq!(child_info.storage_key(), expected.storage_key());
+        assert_eq!(
+            child_info.prefixed_storage_key(),
+            expected.prefixed_storage_key().into_inner()
+        );
+    }
+}
@@ -4,12 +4,15 @@
 
 //! Types associated with accessing and working with storage items.
 
+mod child_info;
 mod storage_address;
 mod storage_client;
 mod storage_type;
 
 pub mod utils;
+pub mod well_known;
 
+pub use child_info::ChildInfo;
 pub use storage_client::StorageClient;
 
 pub use storage_type::{KeyIter, Storage};
@@ -21,7 +24,7 @@ pub use crate::rpc::types::StorageKey;
 /// entry lives and how to properly decode it.
 pub mod address {
     pub use super::storage_address::{
-        dynamic, dynamic_root, make_static_storage_map_key, Address, DynamicAddress,
+        dynamic, dynamic_root, make_static_storage_map_key, Address, DynamicAddress, QueryKind,
         StaticStorageMapKey, StorageAddress, Yes,
     };
 }
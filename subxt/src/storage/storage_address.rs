@@ -6,6 +6,7 @@ use crate::{
     dynamic::{DecodedValueThunk, Value},
     error::{Error, MetadataError, StorageAddressError},
     metadata::{DecodeWithMetadata, EncodeWithMetadata, Metadata},
+    rpc::types::StorageKey as RawStorageKey,
     utils::{Encoded, Static},
 };
 use scale_info::TypeDef;
@@ -14,6 +15,11 @@ use subxt_metadata::{StorageEntryType, StorageHasher};
 
 /// This represents a storage address. Anything implementing this trait
 /// can be used to fetch and iterate over storage entries.
+///
+/// Every storage accessor generated by the `#[subxt]` macro returns an [`Address`], which
+/// implements this trait, so functions bounded by it (for instance
+/// `Addr: StorageAddress<IsFetchable = Yes>`) work with any generated entry and can name
+/// the type of its value as `Addr::Target`.
 pub trait StorageAddress {
     /// The target type of the value that lives at this address.
     type Target: DecodeWithMetadata;
@@ -49,6 +55,47 @@ pub trait StorageAddress {
 /// fetched and returned with a default value in the type system.
 pub struct Yes;
 
+/// Determines what [`crate::storage::Storage::fetch_query()`] returns for an address, based on
+/// its [`StorageAddress::IsDefaultable`] type. Entries with a default value (`ValueQuery` in FRAME)
+/// return the value itself, falling back to the default when absent, whereas entries without one
+/// (`OptionQuery`) return an `Option` which is `None` when absent.
+pub trait QueryKind<T> {
+    /// The type returned when fetching an entry of this kind.
+    type Output;
+
+    /// Build the output from the fetched value (if any), using `default` to obtain
+    /// a default value if necessary.
+    fn from_fetched(
+        value: Option<T>,
+        default: impl FnOnce() -> Result<T, Error>,
+    ) -> Result<Self::Output, Error>;
+}
+
+impl<T> QueryKind<T> for Yes {
+    type Output = T;
+
+    fn from_fetched(
+        value: Option<T>,
+        default: impl FnOnce() -> Result<T, Error>,
+    ) -> Result<Self::Output, Error> {
+        match value {
+            Some(value) => Ok(value),
+            None => default(),
+        }
+    }
+}
+
+impl<T> QueryKind<T> for () {
+    type Output = Option<T>;
+
+    fn from_fetched(
+        value: Option<T>,
+        _default: impl FnOnce() -> Result<T, Error>,
+    ) -> Result<Self::Output, Error> {
+        Ok(value)
+    }
+}
+
 /// A concrete storage address. This can be created from static values (ie those generated
 /// via the `subxt` macro) or dynamic values via [`dynamic`] and [`dynamic_root`].
 pub struct Address<StorageKey, ReturnTy, Fetchable, Defaultable, Iterable> {
@@ -116,6 +163,13 @@ where
     pub fn to_root_bytes(&self) -> Vec<u8> {
         super::utils::storage_address_root_bytes(self)
     }
+
+    /// Compute the full [`crate::storage::StorageKey`] of this entry (the root bytes followed
+    /// by any map keys, hashed as the metadata says), without fetching anything. This is what
+    /// RPC methods like `state_subscribeStorage` and `state_getReadProof` expect to be given.
+    pub fn key(&self, metadata: &Metadata) -> Result<RawStorageKey, Error> {
+        super::utils::storage_address_bytes(self, metadata).map(RawStorageKey)
+    }
 }
 
 impl<StorageKey, ReturnTy, Fetchable, Defaultable, Iterable> StorageAddress
@@ -2,14 +2,19 @@
 // This file is dual-licensed as Apache-2.0 or GPL-3.0.
 // see LICENSE for license details.
 
-use super::storage_address::{StorageAddress, Yes};
+use super::{
+    storage_address::{QueryKind, StorageAddress, Yes},
+    well_known::{ChangesTrieConfiguration, WellKnownKey},
+    ChildInfo,
+};
 use crate::{
     client::OnlineClientT,
     error::{Error, MetadataError},
     metadata::{DecodeWithMetadata, Metadata},
-    rpc::types::{StorageData, StorageKey},
+    rpc::types::{ReadProof, StorageData, StorageKey},
     Config,
 };
+use codec::Decode;
 use derivative::Derivative;
 use std::{future::Future, marker::PhantomData};
 use subxt_metadata::{PalletMetadata, StorageEntryMetadata, StorageEntryType};
@@ -115,6 +120,104 @@ where
         }
     }
 
+    /// Fetch the value at some storage address, but decode it as the type `U` given rather
+    /// than as the address's target type. This is an escape hatch for storage entries whose
+    /// metadata type doesn't describe their contents (for instance opaque bytes whose real
+    /// shape you know).
+    ///
+    /// The address itself is still validated against the metadata, but the value bypasses
+    /// any metadata type checks; it's up to you to ensure that `U` decodes it correctly.
+    pub fn fetch_as<'address, U, Address>(
+        &self,
+        address: &'address Address,
+    ) -> impl Future<Output = Result<Option<U>, Error>> + 'address
+    where
+        U: Decode,
+        Address: StorageAddress<IsFetchable = Yes> + 'address,
+    {
+        let client = self.clone();
+        async move {
+            let metadata = client.client.metadata();
+            let (pallet, _entry) =
+                lookup_entry_details(address.pallet_name(), address.entry_name(), &metadata)?;
+            validate_storage_address(address, pallet)?;
+
+            let lookup_bytes = super::utils::storage_address_bytes(address, &metadata)?;
+            match client.fetch_raw(&lookup_bytes).await? {
+                Some(data) => Ok(Some(U::decode(&mut &*data)?)),
+                None => Ok(None),
+            }
+        }
+    }
+
+    /// Fetch the raw encoded value at the given key in a child trie. Child tries aren't
+    /// described by the metadata, so the key is the raw key within the child trie.
+    pub fn fetch_child_raw<'a>(
+        &self,
+        child_info: &'a ChildInfo,
+        key: &'a [u8],
+    ) -> impl Future<Output = Result<Option<Vec<u8>>, Error>> + 'a {
+        let client = self.client.clone();
+        let block_hash = self.block_hash;
+        async move {
+            let data = client
+                .rpc()
+                .child_storage(&child_info.prefixed_storage_key(), key, Some(block_hash))
+                .await?;
+            Ok(data.map(|d| d.0))
+        }
+    }
+
+    /// Fetch the value at the given key in a child trie, and SCALE decode it as the type `V`
+    /// given. Child tries aren't described by the metadata, so it's up to you to ensure that
+    /// `V` decodes the value correctly.
+    pub fn fetch_child<'a, V: Decode>(
+        &self,
+        child_info: &'a ChildInfo,
+        key: &'a [u8],
+    ) -> impl Future<Output = Result<Option<V>, Error>> + 'a {
+        let client = self.clone();
+        async move {
+            match client.fetch_child_raw(child_info, key).await? {
+                Some(data) => Ok(Some(V::decode(&mut &*data)?)),
+                None => Ok(None),
+            }
+        }
+    }
+
+    /// Fetch the size in bytes of the raw value at the key given, without fetching the value
+    /// itself. Returns `None` if there is no value at the key.
+    pub fn fetch_raw_size<'address>(
+        &self,
+        key: &'address [u8],
+    ) -> impl Future<Output = Result<Option<u64>, Error>> + 'address {
+        let client = self.client.clone();
+        let block_hash = self.block_hash;
+        async move { client.rpc().storage_size(key, Some(block_hash)).await }
+    }
+
+    /// Fetch the size in bytes of the encoded value at some storage address, without fetching
+    /// the value itself, so that (for instance) very large values can be skipped. Returns `None`
+    /// if there is no value at the address.
+    pub fn fetch_size<'address, Address>(
+        &self,
+        address: &'address Address,
+    ) -> impl Future<Output = Result<Option<u64>, Error>> + 'address
+    where
+        Address: StorageAddress<IsFetchable = Yes> + 'address,
+    {
+        let client = self.clone();
+        async move {
+            let metadata = client.client.metadata();
+            let (pallet, _entry) =
+                lookup_entry_details(address.pallet_name(), address.entry_name(), &metadata)?;
+            validate_storage_address(address, pallet)?;
+
+            let lookup_bytes = super::utils::storage_address_bytes(address, &metadata)?;
+            client.fetch_raw_size(&lookup_bytes).await
+        }
+    }
+
     /// Fetch a StorageKey that has a default value with an optional block hash.
     pub fn fetch_or_default<'address, Address>(
         &self,
@@ -132,18 +235,34 @@ where
                 Ok(data)
             } else {
                 let metadata = client.client.metadata();
-                let (_pallet_metadata, storage_entry) =
-                    lookup_entry_details(pallet_name, entry_name, &metadata)?;
-
-                let return_ty_id = return_type_from_storage_entry_type(storage_entry.entry_type());
-                let bytes = &mut storage_entry.default_bytes();
-
-                let val = Address::Target::decode_with_metadata(bytes, return_ty_id, &metadata)?;
-                Ok(val)
+                decode_storage_default(pallet_name, entry_name, &metadata)
             }
         }
     }
 
+    /// Fetch a decoded value from storage at a given address, following FRAME query semantics.
+    /// Entries which have a default value (`ValueQuery`) return the value, or the default if the
+    /// entry is absent, while entries without one (`OptionQuery`) return `None` if absent.
+    pub fn fetch_query<'address, Address>(
+        &self,
+        address: &'address Address,
+    ) -> impl Future<
+        Output = Result<<Address::IsDefaultable as QueryKind<Address::Target>>::Output, Error>,
+    > + 'address
+    where
+        Address: StorageAddress<IsFetchable = Yes> + 'address,
+        Address::IsDefaultable: QueryKind<Address::Target>,
+    {
+        let client = self.clone();
+        async move {
+            let value = client.fetch(address).await?;
+            <Address::IsDefaultable as QueryKind<Address::Target>>::from_fetched(value, || {
+                let metadata = client.client.metadata();
+                decode_storage_default(address.pallet_name(), address.entry_name(), &metadata)
+            })
+        }
+    }
+
     /// Fetch up to `count` keys for a storage map in lexicographic order.
     ///
     /// Supports pagination by passing a value to `start_key`.
@@ -164,6 +283,85 @@ where
         }
     }
 
+    /// Fetch the on-chain storage version of the given pallet, which FRAME stores under the
+    /// well-known `:__STORAGE_VERSION__:` key. Returns `None` if the pallet doesn't track one.
+    pub fn storage_version<'a>(
+        &self,
+        pallet_name: &'a str,
+    ) -> impl Future<Output = Result<Option<u16>, Error>> + 'a {
+        let client = self.clone();
+        async move {
+            let mut key = sp_core_hashing::twox_128(pallet_name.as_bytes()).to_vec();
+            key.extend(sp_core_hashing::twox_128(b":__STORAGE_VERSION__:"));
+            let Some(data) = client.fetch_raw(&key).await? else {
+                return Ok(None);
+            };
+            let version = u16::decode(&mut &*data)?;
+            Ok(Some(version))
+        }
+    }
+
+    /// Fetch the runtime WASM code stored under the well-known `:code` key at this block.
+    /// Use [`super::utils::runtime_code_hash()`] to compare it against a proposed upgrade.
+    ///
+    /// This should only return `None` if the node has no state for this block.
+    pub fn runtime_code(&self) -> impl Future<Output = Result<Option<Vec<u8>>, Error>> {
+        let client = self.clone();
+        async move { client.fetch_raw(WellKnownKey::Code.key()).await }
+    }
+
+    /// Fetch the number of heap pages that the runtime can allocate, stored under the
+    /// well-known `:heappages` key. Returns `None` if the node's default is being used.
+    pub fn heap_pages(&self) -> impl Future<Output = Result<Option<u64>, Error>> {
+        self.fetch_well_known(WellKnownKey::HeapPages)
+    }
+
+    /// Fetch the index of the extrinsic being applied, stored under the well-known
+    /// `:extrinsic_index` key. This is removed once a block is finalized, so will usually
+    /// be `None`.
+    pub fn extrinsic_index(&self) -> impl Future<Output = Result<Option<u32>, Error>> {
+        self.fetch_well_known(WellKnownKey::ExtrinsicIndex)
+    }
+
+    /// Fetch the changes trie configuration stored under the well-known `:changes_trie`
+    /// key. This is `None` unless the chain predates changes tries being removed.
+    pub fn changes_trie_config(
+        &self,
+    ) -> impl Future<Output = Result<Option<ChangesTrieConfiguration>, Error>> {
+        self.fetch_well_known(WellKnownKey::ChangesTrie)
+    }
+
+    fn fetch_well_known<V: Decode>(
+        &self,
+        key: WellKnownKey,
+    ) -> impl Future<Output = Result<Option<V>, Error>> {
+        let client = self.clone();
+        async move {
+            let Some(data) = client.fetch_raw(key.key()).await? else {
+                return Ok(None);
+            };
+            Ok(Some(V::decode(&mut &*data)?))
+        }
+    }
+
+    /// Fetch a Merkle proof of the given raw storage keys against the state root of this
+    /// block. Keys that aren't present in storage are proven absent.
+    pub fn read_proof<'keys>(
+        &self,
+        keys: impl IntoIterator<Item = &'keys [u8]>,
+    ) -> impl Future<Output = Result<ReadProof<T::Hash>, Error>> + 'keys {
+        let client = self.client.clone();
+        let block_hash = self.block_hash;
+        let keys: Vec<&'keys [u8]> = keys.into_iter().collect();
+        async move {
+            let proof = client
+                .rpc()
+                .read_proof(keys, Some(block_hash))
+                .await?;
+            Ok(proof)
+        }
+    }
+
     /// Returns an iterator of key value pairs.
     ///
     /// ```no_run
@@ -362,6 +560,23 @@ fn decode_storage_with_metadata<T: DecodeWithMetadata>(
 ) -> Result<T, Error> {
     let ty = storage_metadata.entry_type();
     let return_ty = return_type_from_storage_entry_type(ty);
+    metadata
+        .decode_limits()
+        .check(bytes, return_ty, metadata.types())?;
     let val = T::decode_with_metadata(bytes, return_ty, metadata)?;
     Ok(val)
 }
+
+/// Decode the default value of the given storage entry, as provided in the metadata.
+fn decode_storage_default<T: DecodeWithMetadata>(
+    pallet_name: &str,
+    entry_name: &str,
+    metadata: &Metadata,
+) -> Result<T, Error> {
+    let (_pallet_metadata, storage_entry) =
+        lookup_entry_details(pallet_name, entry_name, metadata)?;
+    let return_ty_id = return_type_from_storage_entry_type(storage_entry.entry_type());
+    let bytes = &mut storage_entry.default_bytes();
+    let val = T::decode_with_metadata(bytes, return_ty_id, metadata)?;
+    Ok(val)
+}
@@ -37,3 +37,10 @@ pub(crate) fn storage_address_root_bytes<Address: StorageAddress>(addr: &Address
     write_storage_address_root_bytes(addr, &mut bytes);
     bytes
 }
+
+/// The blake2-256 hash of some runtime WASM code (for instance as returned from
+/// [`super::Storage::runtime_code()`]). This is how runtime code is identified on chain,
+/// and so it can be compared against the hash of a proposed runtime upgrade.
+pub fn runtime_code_hash(code: &[u8]) -> [u8; 32] {
+    sp_core_hashing::blake2_256(code)
+}
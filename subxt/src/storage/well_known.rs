@@ -0,0 +1,72 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! Storage keys which Substrate itself gives a meaning to. They don't belong to any pallet,
+//! and so don't appear in the generated storage interface. Use [`super::Storage::heap_pages()`]
+//! and friends to fetch and decode the values stored under them.
+
+use codec::{Decode, Encode};
+
+/// A well-known storage key, along with the type of the value stored under it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WellKnownKey {
+    /// `:code`; the runtime WASM blob, stored as raw bytes rather than SCALE encoded.
+    /// Writable, by a root origin calling `System::set_code`.
+    Code,
+    /// `:heappages`; the number of 64KiB pages the runtime can allocate, as a `u64`.
+    /// Writable, by a root origin calling `System::set_heap_pages`. The node uses its own
+    /// default if nothing is stored here.
+    HeapPages,
+    /// `:extrinsic_index`; the index of the extrinsic being applied, as a `u32`.
+    /// Read-only; the runtime sets it while executing a block, and removes it when the
+    /// block is finalized, so it won't usually be present at the end of a block.
+    ExtrinsicIndex,
+    /// `:changes_trie`; the [`ChangesTrieConfiguration`], if changes tries are enabled.
+    /// Read-only; changes tries have been removed from Substrate, so this will only be
+    /// present in the state of older chains.
+    ChangesTrie,
+}
+
+impl WellKnownKey {
+    /// The raw storage key.
+    pub fn key(&self) -> &'static [u8] {
+        match self {
+            WellKnownKey::Code => b":code",
+            WellKnownKey::HeapPages => b":heappages",
+            WellKnownKey::ExtrinsicIndex => b":extrinsic_index",
+            WellKnownKey::ChangesTrie => b":changes_trie",
+        }
+    }
+
+    /// Can the value under this key be changed by submitting a transaction?
+    pub fn is_writable(&self) -> bool {
+        matches!(self, WellKnownKey::Code | WellKnownKey::HeapPages)
+    }
+}
+
+/// How changes tries were configured, as stored under [`WellKnownKey::ChangesTrie`].
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct ChangesTrieConfiguration {
+    /// The interval (in blocks) at which digest changes tries are created.
+    pub digest_interval: u32,
+    /// The maximal number of digest levels.
+    pub digest_levels: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_core::storage::well_known_keys;
+
+    #[test]
+    fn keys_match_substrate() {
+        assert_eq!(WellKnownKey::Code.key(), well_known_keys::CODE);
+        assert_eq!(WellKnownKey::HeapPages.key(), well_known_keys::HEAP_PAGES);
+        assert_eq!(
+            WellKnownKey::ExtrinsicIndex.key(),
+            well_known_keys::EXTRINSIC_INDEX
+        );
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! Build `Utility::force_batch` calls and work out which of the batched calls failed.
+
+use super::TxPayload;
+use crate::{
+    blocks::ExtrinsicEvents,
+    error::{DispatchError, Error, MetadataError, TransactionError},
+    metadata::Metadata,
+    Config,
+};
+use codec::{Compact, Encode};
+
+/// A `Utility::force_batch` call wrapping some other calls. Unlike `batch` and `batch_all`,
+/// every call in the batch is dispatched even if some of them fail, and the outcome of each
+/// one is reported via an `ItemCompleted` or `ItemFailed` event. Use
+/// [`ForceBatch::item_results()`] to turn those events back into a result per call.
+///
+/// Construct one with [`force_batch()`].
+#[derive(Clone, Debug)]
+pub struct ForceBatch<Call> {
+    calls: Vec<Call>,
+}
+
+/// Batch the given calls into a single `Utility::force_batch` call. To batch calls of
+/// different types, [box](super::Payload::boxed) them first.
+pub fn force_batch<Call: TxPayload>(calls: impl IntoIterator<Item = Call>) -> ForceBatch<Call> {
+    ForceBatch {
+        calls: calls.into_iter().collect(),
+    }
+}
+
+impl<Call> ForceBatch<Call> {
+    /// The calls in the batch.
+    pub fn calls(&self) -> &[Call] {
+        &self.calls
+    }
+
+    /// Push another call onto the end of the batch.
+    pub fn push(&mut self, call: Call) {
+        self.calls.push(call)
+    }
+
+    /// Given the events emitted by this batch once it has been dispatched, return the outcome
+    /// of each call, in the same order as the calls were given.
+    ///
+    /// Calls which are themselves batches emit their own item events, and so they can't be
+    /// told apart from the calls in this batch; avoid nesting batches if using this.
+    ///
+    /// Returns [`TransactionError::BatchItemsMismatch`] if the number of item events doesn't
+    /// match the number of calls in the batch.
+    pub fn item_results<T: Config>(
+        &self,
+        events: &ExtrinsicEvents<T>,
+        metadata: &Metadata,
+    ) -> Result<Vec<Result<(), DispatchError>>, Error> {
+        let mut results = Vec::with_capacity(self.calls.len());
+        for ev in events.iter() {
+            let ev = ev?;
+            if ev.pallet_name() != "Utility" {
+                continue;
+            }
+            match ev.variant_name() {
+                "ItemCompleted" => results.push(Ok(())),
+                "ItemFailed" => {
+                    let error = DispatchError::decode_from(ev.field_bytes(), metadata.clone())?;
+                    results.push(Err(error));
+                }
+                _ => {}
+            }
+        }
+
+        if results.len() != self.calls.len() {
+            return Err(TransactionError::BatchItemsMismatch {
+                expected: self.calls.len(),
+                found: results.len(),
+            }
+            .into());
+        }
+        Ok(results)
+    }
+}
+
+impl<Call: TxPayload> TxPayload for ForceBatch<Call> {
+    fn encode_call_data_to(&self, metadata: &Metadata, out: &mut Vec<u8>) -> Result<(), Error> {
+        let pallet = metadata.pallet_by_name_err("Utility")?;
+        let call = pallet
+            .call_variant_by_name("force_batch")
+            .ok_or_else(|| MetadataError::CallNameNotFound("force_batch".to_owned()))?;
+
+        pallet.index().encode_to(out);
+        call.index.encode_to(out);
+
+        // The only argument is a `Vec<RuntimeCall>`, and a `RuntimeCall` is encoded in
+        // exactly the same way as the call data of each payload.
+        Compact(self.calls.len() as u32).encode_to(out);
+        for call in &self.calls {
+            call.encode_call_data_to(metadata, out)?;
+        }
+        Ok(())
+    }
+}
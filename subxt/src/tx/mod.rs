@@ -9,7 +9,9 @@
 //! additional and signed extra parameters are used when constructing an extrinsic, and is a part
 //! of the chain configuration (see [`crate::config::Config`]).
 
+mod force_batch;
 mod signer;
+mod submit_extrinsic;
 mod tx_client;
 mod tx_payload;
 mod tx_progress;
@@ -20,8 +22,12 @@ mod tx_progress;
 pub use self::signer::PairSigner;
 
 pub use self::{
-    signer::Signer,
-    tx_client::{SubmittableExtrinsic, TxClient},
-    tx_payload::{dynamic, BoxedPayload, DynamicPayload, Payload, TxPayload},
+    force_batch::{force_batch, ForceBatch},
+    signer::{AsyncSigner, AsyncSignerFuture, Signer},
+    submit_extrinsic::{SubmitExtrinsic, SubmitExtrinsicFuture},
+    tx_client::{SubmittableExtrinsic, TxBuilder, TxClient},
+    tx_payload::{
+        dynamic, BoxedPayload, CallSuccessEvent, DynamicPayload, MaxLength, Payload, TxPayload,
+    },
     tx_progress::{TxInBlock, TxProgress, TxStatus},
 };
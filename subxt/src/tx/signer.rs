@@ -5,7 +5,8 @@
 //! A library to **sub**mit e**xt**rinsics to a
 //! [substrate](https://github.com/paritytech/substrate) node via RPC.
 
-use crate::Config;
+use crate::{error::Error, Config};
+use std::{future::Future, pin::Pin};
 
 /// Signing transactions requires a [`Signer`]. This is responsible for
 /// providing the "from" account that the transaction is being signed by,
@@ -24,6 +25,25 @@ pub trait Signer<T: Config> {
     fn sign(&self, signer_payload: &[u8]) -> T::Signature;
 }
 
+/// The future returned from [`AsyncSigner::sign()`].
+pub type AsyncSignerFuture<'a, T> =
+    Pin<Box<dyn Future<Output = Result<<T as Config>::Signature, Error>> + Send + 'a>>;
+
+/// Like [`Signer`], but signing happens asynchronously. This is useful when the keys are
+/// held elsewhere (for instance, in another process or on an HSM), and the signer payload
+/// must be sent off to be signed.
+pub trait AsyncSigner<T: Config> {
+    /// Return the "from" account ID.
+    fn account_id(&self) -> &T::AccountId;
+
+    /// Return the "from" address.
+    fn address(&self) -> T::Address;
+
+    /// Takes a signer payload for an extrinsic, and returns a future which resolves to
+    /// a signature based on it, or an error if signing failed.
+    fn sign<'a>(&'a self, signer_payload: &'a [u8]) -> AsyncSignerFuture<'a, T>;
+}
+
 #[cfg(feature = "substrate-compat")]
 pub use pair_signer::PairSigner;
 
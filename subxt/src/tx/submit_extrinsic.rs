@@ -0,0 +1,27 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use super::Signer;
+use crate::{blocks::ExtrinsicEvents, error::Error, Config};
+use std::{future::Future, pin::Pin};
+
+/// The future returned from [`SubmitExtrinsic::submit()`].
+pub type SubmitExtrinsicFuture<'a, T> =
+    Pin<Box<dyn Future<Output = Result<ExtrinsicEvents<T>, Error>> + Send + 'a>>;
+
+/// An object safe way to sign and submit a call, which hides the type of the call so that
+/// calls of different types can be stored and submitted together, for instance in a
+/// `Vec<Box<dyn SubmitExtrinsic<T>>>`. This is implemented by [`super::TxBuilder`], which
+/// is obtained via [`super::TxClient::build()`].
+///
+/// The cost of this is a heap allocation for each boxed call, and another for the future
+/// returned from each call to [`SubmitExtrinsic::submit()`].
+pub trait SubmitExtrinsic<T: Config>: Send + Sync {
+    /// Sign the call with the given signer, submit it, and wait for it to be finalized
+    /// successfully, handing back the events that it emitted.
+    fn submit<'a>(
+        &'a self,
+        signer: &'a (dyn Signer<T> + Send + Sync),
+    ) -> SubmitExtrinsicFuture<'a, T>;
+}
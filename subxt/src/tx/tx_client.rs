@@ -4,15 +4,25 @@
 
 use std::borrow::Cow;
 
-use codec::{Compact, Encode};
+use codec::{Compact, Decode, Encode};
 use derivative::Derivative;
+use futures::StreamExt;
 use sp_core_hashing::blake2_256;
+use tracing::Instrument;
 
 use crate::{
+    blocks::{BlocksClient, ExtrinsicDetails, ExtrinsicEvents, ExtrinsicPartTypeIds},
     client::{OfflineClientT, OnlineClientT},
-    config::{Config, ExtrinsicParams, Hasher},
-    error::{Error, MetadataError},
-    tx::{Signer as SignerT, TxPayload, TxProgress},
+    config::{
+        extrinsic_params::{BaseExtrinsicParamsBuilder, WithTip},
+        Config, ExtrinsicParams, Hasher, TipStrategy,
+    },
+    error::{DispatchError, Error, MetadataError, PoolRejection, RpcError, TransactionError},
+    events::StaticEvent,
+    tx::{
+        tx_progress::ensure_success, AsyncSigner, CallSuccessEvent, ForceBatch, Payload,
+        Signer as SignerT, SubmitExtrinsic, SubmitExtrinsicFuture, TxPayload, TxProgress,
+    },
     utils::{Encoded, PhantomDataSendSync},
 };
 
@@ -167,6 +177,38 @@ impl<T: Config, C: OfflineClientT<T>> TxClient<T, C> {
         // 3. Sign and construct an extrinsic from these details.
         Ok(partial_signed.sign(signer))
     }
+
+    /// Start building a transaction from the given call. The returned [`TxBuilder`]
+    /// allows the nonce and the other extrinsic params to be overridden for this single
+    /// transaction before it is signed. Anything left unset falls back to the default
+    /// value (and the nonce is fetched from the chain).
+    ///
+    /// See [`TxBuilder`] for why these overrides are made here rather than on the
+    /// resulting [`SubmittableExtrinsic`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let tx = client
+    ///     .tx()
+    ///     .build(call)
+    ///     .tip(1_000)
+    ///     .nonce(5)
+    ///     .create_signed(&signer)
+    ///     .await?;
+    /// ```
+    pub fn build<Call>(&self, call: Call) -> TxBuilder<T, C, Call>
+    where
+        Call: TxPayload,
+        <T::ExtrinsicParams as ExtrinsicParams<T::Index, T::Hash>>::OtherParams: Default,
+    {
+        TxBuilder {
+            tx_client: self.clone(),
+            call,
+            nonce: None,
+            other_params: Default::default(),
+        }
+    }
 }
 
 impl<T, C> TxClient<T, C>
@@ -174,8 +216,11 @@ where
     T: Config,
     C: OnlineClientT<T>,
 {
-    // Get the next account nonce to use.
-    async fn next_account_nonce(&self, account_id: &T::AccountId) -> Result<T::Index, Error> {
+    /// Fetch the next nonce to sign a transaction from the given account with, via
+    /// `system_accountNextIndex`. Unlike the nonce in `System::Account` storage, this takes
+    /// into account transactions from the account which are still in the transaction pool.
+    /// This is the nonce used when one isn't given explicitly.
+    pub async fn account_nonce(&self, account_id: &T::AccountId) -> Result<T::Index, Error> {
         self.client
             .rpc()
             .system_account_next_index(account_id)
@@ -192,7 +237,7 @@ where
     where
         Call: TxPayload,
     {
-        let account_nonce = self.next_account_nonce(account_id).await?;
+        let account_nonce = self.account_nonce(account_id).await?;
         self.create_partial_signed_with_nonce(call, account_nonce, other_params)
     }
 
@@ -207,10 +252,59 @@ where
         Call: TxPayload,
         Signer: SignerT<T>,
     {
-        let account_nonce = self.next_account_nonce(signer.account_id()).await?;
+        let account_nonce = self.account_nonce(signer.account_id()).await?;
         self.create_signed_with_nonce(call, signer, account_nonce, other_params)
     }
 
+    /// Creates a signed extrinsic whose tip is worked out from the given [`TipStrategy`], without
+    /// submitting it. Any tip already set in the `other_params` is overridden.
+    ///
+    /// For [`TipStrategy::PerWeight`], the extrinsic is first signed without a tip so that its
+    /// weight can be estimated via [`SubmittableExtrinsic::weight_estimate()`], and is then
+    /// signed again with the tip for that weight.
+    pub async fn create_signed_with_tip<Call, Signer>(
+        &self,
+        call: &Call,
+        signer: &Signer,
+        tip: TipStrategy,
+        other_params: <T::ExtrinsicParams as ExtrinsicParams<T::Index, T::Hash>>::OtherParams,
+    ) -> Result<SubmittableExtrinsic<T, C>, Error>
+    where
+        Call: TxPayload,
+        Signer: SignerT<T>,
+        <T::ExtrinsicParams as ExtrinsicParams<T::Index, T::Hash>>::OtherParams: WithTip + Clone,
+    {
+        let account_nonce = self.account_nonce(signer.account_id()).await?;
+        let ref_time = match tip {
+            TipStrategy::Flat(_) => 0,
+            TipStrategy::PerWeight(_) => {
+                let untipped = other_params.clone().with_tip(0);
+                self.create_signed_with_nonce(call, signer, account_nonce, untipped)?
+                    .weight_estimate()
+                    .await?
+            }
+        };
+        let other_params = other_params.with_tip(tip.tip(ref_time));
+        self.create_signed_with_nonce(call, signer, account_nonce, other_params)
+    }
+
+    /// Creates a signed extrinsic using an [`AsyncSigner`], without submitting it.
+    pub async fn create_signed_async<Call, Signer>(
+        &self,
+        call: &Call,
+        signer: &Signer,
+        other_params: <T::ExtrinsicParams as ExtrinsicParams<T::Index, T::Hash>>::OtherParams,
+    ) -> Result<SubmittableExtrinsic<T, C>, Error>
+    where
+        Call: TxPayload,
+        Signer: AsyncSigner<T>,
+    {
+        self.create_partial_signed(call, signer.account_id(), other_params)
+            .await?
+            .sign_async(signer)
+            .await
+    }
+
     /// Creates and signs an extrinsic and submits it to the chain. Passes default parameters
     /// to construct the "signed extra" and "additional" payloads needed by the extrinsic.
     ///
@@ -230,6 +324,62 @@ where
             .await
     }
 
+    /// Creates and signs an extrinsic using default parameters, submits it to the chain, waits
+    /// for it to be finalized successfully and then returns the event which the call emits on
+    /// success (see [`CallSuccessEvent`]).
+    ///
+    /// Returns [`TransactionError::ExpectedEventNotFound`] if the call succeeded but that event
+    /// wasn't emitted.
+    pub async fn sign_submit_and_watch_for<CallData, Signer>(
+        &self,
+        call: &Payload<CallData>,
+        signer: &Signer,
+    ) -> Result<CallData::SuccessEvent, Error>
+    where
+        Payload<CallData>: TxPayload,
+        CallData: CallSuccessEvent,
+        Signer: SignerT<T>,
+        <T::ExtrinsicParams as ExtrinsicParams<T::Index, T::Hash>>::OtherParams: Default,
+    {
+        let events = self
+            .sign_and_submit_then_watch_default(call, signer)
+            .await?
+            .wait_for_finalized_success()
+            .await?;
+
+        events
+            .find_first::<CallData::SuccessEvent>()?
+            .ok_or_else(|| {
+                TransactionError::ExpectedEventNotFound(
+                    <CallData::SuccessEvent as StaticEvent>::PALLET,
+                    <CallData::SuccessEvent as StaticEvent>::EVENT,
+                )
+                .into()
+            })
+    }
+
+    /// Creates and signs a [`ForceBatch`] using default parameters, submits it to the chain and
+    /// waits for it to be finalized successfully, and then returns the outcome of each call in the
+    /// batch, in the order that they were given. See [`ForceBatch::item_results()`].
+    pub async fn sign_submit_and_watch_force_batch<Call, Signer>(
+        &self,
+        batch: &ForceBatch<Call>,
+        signer: &Signer,
+    ) -> Result<Vec<Result<(), DispatchError>>, Error>
+    where
+        Call: TxPayload,
+        Signer: SignerT<T>,
+        <T::ExtrinsicParams as ExtrinsicParams<T::Index, T::Hash>>::OtherParams: Default,
+    {
+        let events = self
+            .sign_and_submit_then_watch_default(batch, signer)
+            .await?
+            .wait_for_finalized_success()
+            .await?;
+
+        batch.item_results(&events, &self.client.metadata())
+    }
+
     /// Creates and signs an extrinsic and submits it to the chain.
     ///
     /// Returns a [`TxProgress`], which can be used to track the status of the transaction
@@ -244,10 +394,14 @@ where
         Call: TxPayload,
         Signer: SignerT<T>,
     {
-        self.create_signed(call, signer, other_params)
-            .await?
-            .submit_and_watch()
-            .await
+        async {
+            self.create_signed(call, signer, other_params)
+                .await?
+                .submit_and_watch()
+                .await
+        }
+        .instrument(call_span("sign_and_submit_then_watch", call))
+        .await
     }
 
     /// Creates and signs an extrinsic and submits to the chain for block inclusion. Passes
@@ -291,13 +445,319 @@ where
         Call: TxPayload,
         Signer: SignerT<T>,
     {
-        self.create_signed(call, signer, other_params)
-            .await?
-            .submit()
+        async {
+            self.create_signed(call, signer, other_params)
+                .await?
+                .submit()
+                .await
+        }
+        .instrument(call_span("sign_and_submit", call))
+        .await
+    }
+
+    /// Submit each of the provided extrinsics, and wait for each of them to be finalized
+    /// successfully. At most `max_concurrent` extrinsics are in flight at any one time.
+    ///
+    /// This returns one result per extrinsic, in the order that they were provided; an
+    /// error submitting or executing one extrinsic doesn't prevent the others from being
+    /// submitted.
+    ///
+    /// # Note
+    ///
+    /// Each extrinsic has already been signed with its nonce. If several of them come from
+    /// the same signer, create them with [`TxClient::create_signed_with_nonce()`] and
+    /// incrementing nonces, since fetching the nonce for each one up front would hand out
+    /// the same nonce more than once.
+    pub async fn submit_all<I>(
+        &self,
+        extrinsics: I,
+        max_concurrent: usize,
+    ) -> Vec<Result<ExtrinsicEvents<T>, Error>>
+    where
+        I: IntoIterator<Item = SubmittableExtrinsic<T, C>>,
+    {
+        futures::stream::iter(extrinsics)
+            .map(|extrinsic| async move {
+                extrinsic
+                    .submit_and_watch()
+                    .await?
+                    .wait_for_finalized_success()
+                    .await
+            })
+            .buffered(max_concurrent.max(1))
+            .collect()
+            .await
+    }
+
+    /// Submit each of the provided extrinsics, and then watch for all of them over a single
+    /// subscription to finalized blocks, looking for each one by its hash in the body of every
+    /// new block. We give up on any extrinsics that have not been found after `max_blocks`
+    /// blocks.
+    ///
+    /// This returns one result per extrinsic, in the order that they were provided. An extrinsic
+    /// which failed to be submitted, failed to execute or was not found returns an error (the
+    /// latter being [`TransactionError::NotIncluded`]). An error is returned for the batch as a
+    /// whole only if the block subscription itself fails.
+    ///
+    /// # Note
+    ///
+    /// This opens one subscription however many extrinsics are submitted, which makes it lighter
+    /// than [`TxClient::submit_all()`] for high volume submitters. The trade-off is latency and
+    /// detail: every finalized block body is downloaded and searched, and since the node doesn't
+    /// tell us about the progress of individual transactions, those which are dropped or found
+    /// to be invalid are only reported as not included once `max_blocks` blocks have passed.
+    ///
+    /// As with [`TxClient::submit_all()`], extrinsics from the same signer should be created
+    /// with [`TxClient::create_signed_with_nonce()`] and incrementing nonces.
+    pub async fn submit_and_watch_batch<I>(
+        &self,
+        extrinsics: I,
+        max_blocks: u32,
+    ) -> Result<Vec<Result<ExtrinsicEvents<T>, Error>>, Error>
+    where
+        I: IntoIterator<Item = SubmittableExtrinsic<T, C>>,
+        C: Send + Sync + 'static,
+    {
+        // Subscribe before submitting anything, so that we can't miss the block that any
+        // of the extrinsics end up in.
+        let mut blocks = BlocksClient::new(self.client.clone())
+            .subscribe_finalized()
+            .await?;
+
+        let mut results = Vec::new();
+        let mut pending = Vec::new();
+        for (idx, extrinsic) in extrinsics.into_iter().enumerate() {
+            match extrinsic.submit().await {
+                Ok(ext_hash) => {
+                    pending.push((ext_hash, idx));
+                    results.push(Err(TransactionError::NotIncluded.into()));
+                }
+                Err(e) => results.push(Err(e)),
+            }
+        }
+
+        let mut blocks_seen = 0;
+        while !pending.is_empty() && blocks_seen < max_blocks {
+            let Some(block) = blocks.next().await else {
+                break;
+            };
+            let body = block?.body().await?;
+            blocks_seen += 1;
+
+            for ext in body.extrinsics().iter() {
+                let ext = ext?;
+                let ext_hash = T::Hasher::hash_of(&ext.bytes());
+                let Some(pos) = pending.iter().position(|(hash, _)| *hash == ext_hash) else {
+                    continue;
+                };
+                let (_, idx) = pending.swap_remove(pos);
+                results[idx] = match ext.events().await {
+                    Ok(events) => ensure_success(events, &self.client),
+                    Err(e) => Err(e),
+                };
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Convert a weight into a fee using the runtime's own conversion, via the
+    /// `TransactionPaymentApi_query_weight_to_fee` runtime API. This is handy for modelling the
+    /// fees of hypothetical extrinsics alongside [`SubmittableExtrinsic::partial_fee_estimate()`].
+    ///
+    /// Returns [`MetadataError::RuntimeTraitNotFound`] or [`MetadataError::RuntimeMethodNotFound`]
+    /// if the metadata describes the runtime APIs and this one is missing from them. Older
+    /// metadata doesn't describe runtime APIs, in which case the call is just attempted.
+    pub async fn weight_to_fee(&self, ref_time: u64, proof_size: u64) -> Result<u128, Error> {
+        const TRAIT: &str = "TransactionPaymentApi";
+        const METHOD: &str = "query_weight_to_fee";
+
+        let metadata = self.client.metadata();
+        match metadata.runtime_api_trait_by_name(TRAIT) {
+            Some(api) if api.method_by_name(METHOD).is_none() => {
+                return Err(MetadataError::RuntimeMethodNotFound(METHOD.to_owned()).into());
+            }
+            None if metadata.runtime_api_traits().next().is_some() => {
+                return Err(MetadataError::RuntimeTraitNotFound(TRAIT.to_owned()).into());
+            }
+            _ => {}
+        }
+
+        // See <https://paritytech.github.io/substrate/master/sp_weights/struct.Weight.html>;
+        // both parts of the weight are compact encoded.
+        let params = (Compact(ref_time), Compact(proof_size)).encode();
+        self.client
+            .rpc()
+            .state_call::<u128>(
+                "TransactionPaymentApi_query_weight_to_fee",
+                Some(&params),
+                None,
+            )
             .await
     }
 }
 
+/// Create a span for submitting the given call, recording its pallet and call name
+/// where these are known.
+fn call_span<Call: TxPayload>(name: &'static str, call: &Call) -> tracing::Span {
+    let span = tracing::info_span!(
+        "tx",
+        operation = name,
+        pallet = tracing::field::Empty,
+        call = tracing::field::Empty,
+    );
+    if let Some(details) = call.validation_details() {
+        span.record("pallet", details.pallet_name);
+        span.record("call", details.call_name);
+    }
+    span
+}
+
+/// A builder which allows per-transaction overrides of the nonce and other
+/// extrinsic params. Construct one via [`TxClient::build()`].
+///
+/// These overrides can't be made on a [`SubmittableExtrinsic`], since that has already
+/// been signed, and the nonce and tip are part of the signed payload. Nor can they be
+/// made on the call payloads returned from the generated `tx()` API, since those know
+/// nothing of the [`Config`] in use, and so of the nonce type or the extrinsic params
+/// that a tip belongs to. This type sits between the two: it holds the call along with
+/// the params that it will be signed with.
+pub struct TxBuilder<T: Config, C, Call> {
+    tx_client: TxClient<T, C>,
+    call: Call,
+    nonce: Option<T::Index>,
+    other_params: <T::ExtrinsicParams as ExtrinsicParams<T::Index, T::Hash>>::OtherParams,
+}
+
+impl<T, C, Call> TxBuilder<T, C, Call>
+where
+    T: Config,
+    C: OfflineClientT<T>,
+    Call: TxPayload,
+{
+    /// Use the given account nonce rather than fetching the next one from the chain.
+    pub fn nonce(mut self, nonce: T::Index) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    /// Replace the other params used to construct the extrinsic params entirely.
+    pub fn other_params(
+        mut self,
+        other_params: <T::ExtrinsicParams as ExtrinsicParams<T::Index, T::Hash>>::OtherParams,
+    ) -> Self {
+        self.other_params = other_params;
+        self
+    }
+}
+
+impl<T, C, Call, Tip> TxBuilder<T, C, Call>
+where
+    T: Config,
+    T::ExtrinsicParams:
+        ExtrinsicParams<T::Index, T::Hash, OtherParams = BaseExtrinsicParamsBuilder<T, Tip>>,
+    Tip: Default,
+{
+    /// Set the tip you'd like to give to the block author for this transaction.
+    pub fn tip(mut self, tip: impl Into<Tip>) -> Self {
+        self.other_params = self.other_params.tip(tip);
+        self
+    }
+}
+
+impl<T, C, Call> TxBuilder<T, C, Call>
+where
+    T: Config,
+    C: OnlineClientT<T>,
+    Call: TxPayload,
+{
+    /// Creates a partial signed extrinsic, fetching the account nonce from the chain
+    /// if one was not provided.
+    pub async fn create_partial_signed(
+        self,
+        account_id: &T::AccountId,
+    ) -> Result<PartialExtrinsic<T, C>, Error> {
+        let nonce = match self.nonce {
+            Some(nonce) => nonce,
+            None => self.tx_client.account_nonce(account_id).await?,
+        };
+        self.tx_client
+            .create_partial_signed_with_nonce(&self.call, nonce, self.other_params)
+    }
+
+    /// Creates a signed extrinsic, fetching the account nonce from the chain
+    /// if one was not provided.
+    pub async fn create_signed<Signer>(
+        self,
+        signer: &Signer,
+    ) -> Result<SubmittableExtrinsic<T, C>, Error>
+    where
+        Signer: SignerT<T>,
+    {
+        let partial_signed = self.create_partial_signed(signer.account_id()).await?;
+        Ok(partial_signed.sign(signer))
+    }
+
+    /// Creates and signs an extrinsic and submits it to the chain.
+    ///
+    /// Returns a [`TxProgress`], which can be used to track the status of the transaction
+    /// and obtain details about it, once it has made it into a block.
+    pub async fn sign_and_submit_then_watch<Signer>(
+        self,
+        signer: &Signer,
+    ) -> Result<TxProgress<T, C>, Error>
+    where
+        Signer: SignerT<T>,
+    {
+        self.create_signed(signer).await?.submit_and_watch().await
+    }
+
+    /// Creates and signs an extrinsic and submits to the chain for block inclusion.
+    ///
+    /// Returns `Ok` with the extrinsic hash if it is valid extrinsic.
+    pub async fn sign_and_submit<Signer>(self, signer: &Signer) -> Result<T::Hash, Error>
+    where
+        Signer: SignerT<T>,
+    {
+        self.create_signed(signer).await?.submit().await
+    }
+}
+
+impl<T, C, Call> SubmitExtrinsic<T> for TxBuilder<T, C, Call>
+where
+    T: Config + Send + Sync,
+    T::Index: Send + Sync,
+    T::AccountId: Sync,
+    <T::ExtrinsicParams as ExtrinsicParams<T::Index, T::Hash>>::OtherParams: Clone + Send + Sync,
+    C: OnlineClientT<T>,
+    Call: TxPayload + Send + Sync,
+{
+    fn submit<'a>(
+        &'a self,
+        signer: &'a (dyn SignerT<T> + Send + Sync),
+    ) -> SubmitExtrinsicFuture<'a, T> {
+        Box::pin(async move {
+            let nonce = match self.nonce {
+                Some(nonce) => nonce,
+                None => self.tx_client.account_nonce(signer.account_id()).await?,
+            };
+            let signed = {
+                let partial_signed = self.tx_client.create_partial_signed_with_nonce(
+                    &self.call,
+                    nonce,
+                    self.other_params.clone(),
+                )?;
+                partial_signed.sign(signer)
+            };
+            signed
+                .submit_and_watch()
+                .await?
+                .wait_for_finalized_success()
+                .await
+        })
+    }
+}
+
 /// This payload contains the information needed to produce an extrinsic.
 pub struct PartialExtrinsic<T: Config, C> {
     client: C,
@@ -330,6 +790,11 @@ where
 
     /// Return the signer payload for this extrinsic. These are the bytes that must
     /// be signed in order to produce a valid signature for the extrinsic.
+    ///
+    /// This is exactly what [`PartialExtrinsic::sign()`] has the signer sign, and so it can be
+    /// handed to signers which don't live in this process (for instance each signatory of a
+    /// `Multisig` call, signing offline), and the signature attached afterwards via
+    /// [`PartialExtrinsic::with_signature()`].
     pub fn signer_payload(&self) -> Vec<u8> {
         self.with_signer_payload(|bytes| bytes.to_vec())
     }
@@ -345,7 +810,7 @@ where
     /// as well as providing a signature to attach to it.
     pub fn sign<Signer>(&self, signer: &Signer) -> SubmittableExtrinsic<T, C>
     where
-        Signer: SignerT<T>,
+        Signer: SignerT<T> + ?Sized,
     {
         // Given our signer, we can sign the payload representing this extrinsic.
         let signature = self.with_signer_payload(|bytes| signer.sign(&bytes));
@@ -353,6 +818,42 @@ where
         self.sign_with_address_and_signature(&signer.address(), &signature)
     }
 
+    /// Return the length in bytes of the extrinsic once signed by the given `signer`,
+    /// including its compact length prefix. Unlike the length of [`PartialExtrinsic::call_data()`],
+    /// this accounts for the address, signature and signed extensions too.
+    pub fn signed_len<Signer>(&self, signer: &Signer) -> usize
+    where
+        Signer: SignerT<T>,
+    {
+        self.sign(signer).encoded_len()
+    }
+
+    /// Convert this [`PartialExtrinsic`] into a [`SubmittableExtrinsic`], ready to submit,
+    /// using an [`AsyncSigner`] to provide the "from" address and signature.
+    pub async fn sign_async<Signer>(
+        &self,
+        signer: &Signer,
+    ) -> Result<SubmittableExtrinsic<T, C>, Error>
+    where
+        Signer: AsyncSigner<T>,
+    {
+        let signer_payload = self.signer_payload();
+        let signature = signer.sign(&signer_payload).await?;
+        Ok(self.sign_with_address_and_signature(&signer.address(), &signature))
+    }
+
+    /// Convert this [`PartialExtrinsic`] into a [`SubmittableExtrinsic`], ready to submit, given
+    /// the account which signed the [`PartialExtrinsic::signer_payload()`] and the signature that
+    /// it produced. This is the same as [`PartialExtrinsic::sign_with_address_and_signature()`],
+    /// but takes the account ID rather than its address.
+    pub fn with_signature(
+        &self,
+        account_id: T::AccountId,
+        signature: &T::Signature,
+    ) -> SubmittableExtrinsic<T, C> {
+        self.sign_with_address_and_signature(&account_id.into(), signature)
+    }
+
     /// Convert this [`PartialExtrinsic`] into a [`SubmittableExtrinsic`], ready to submit.
     /// An address, and something representing a signature that can be SCALE encoded, are both
     /// needed in order to construct it. If you have a `Signer` to hand, you can use
@@ -423,11 +924,87 @@ where
         &self.encoded.0
     }
 
+    /// Returns the length of the SCALE encoded extrinsic bytes, including the
+    /// compact length prefix.
+    pub fn encoded_len(&self) -> usize {
+        self.encoded.0.len()
+    }
+
+    /// Returns true if this extrinsic carries a signature.
+    pub fn is_signed(&self) -> bool {
+        let mut bytes = self.encoded();
+        // Skip the compact length prefix; the "is signed" bit is the top bit of the version byte.
+        let _ = <Compact<u32>>::decode(&mut bytes);
+        bytes
+            .first()
+            .map_or(false, |version| version & 0b1000_0000 != 0)
+    }
+
+    /// Returns the SCALE encoded call within this extrinsic; the pallet index, the call index,
+    /// and then the call arguments. These are the bytes that calls like `Sudo::sudo` and
+    /// `Proxy::proxy` wrap, and which other tools often expect to be given.
+    pub fn encode_call(&self) -> Result<Vec<u8>, Error> {
+        Ok(self.details()?.call_bytes().to_vec())
+    }
+
+    // Decode the extrinsic, so that the call within it can be inspected.
+    fn details(&self) -> Result<ExtrinsicDetails<T, C>, Error> {
+        let mut bytes = self.encoded();
+        // Skip the compact length prefix, which extrinsics in blocks don't have.
+        <Compact<u32>>::decode(&mut bytes)?;
+        let ids = ExtrinsicPartTypeIds::new(&self.client.metadata())?;
+        ExtrinsicDetails::decode_from(
+            0,
+            bytes.into(),
+            self.client.clone(),
+            None,
+            Default::default(),
+            ids,
+        )
+    }
+
     /// Consumes [`SubmittableExtrinsic`] and returns the SCALE encoded
     /// extrinsic bytes.
     pub fn into_encoded(self) -> Vec<u8> {
         self.encoded.0
     }
+
+    /// Hand this extrinsic over to some other client, so that it's submitted through that
+    /// client's connection rather than the one that it was created (and signed) with. This
+    /// allows metadata and nonces to be fetched from one node while submitting to another.
+    ///
+    /// Returns [`TransactionError::GenesisHashMismatch`] if the other client is connected to
+    /// a different chain.
+    pub fn via<Client: OfflineClientT<T>>(
+        self,
+        client: Client,
+    ) -> Result<SubmittableExtrinsic<T, Client>, Error> {
+        let expected = self.client.genesis_hash();
+        let found = client.genesis_hash();
+        if expected != found {
+            return Err(TransactionError::GenesisHashMismatch {
+                expected: format!("{expected:?}"),
+                found: format!("{found:?}"),
+            }
+            .into());
+        }
+        Ok(SubmittableExtrinsic::from_bytes(client, self.encoded.0))
+    }
+
+    /// Submit this extrinsic through some other client; see [`Self::via()`]. The extrinsic is
+    /// already signed, so no signer is needed. Returns the extrinsic hash if the other node
+    /// accepts it into its transaction pool.
+    pub async fn submit_via<Client: OnlineClientT<T>>(
+        &self,
+        client: Client,
+    ) -> Result<T::Hash, Error> {
+        let submittable = SubmittableExtrinsic {
+            client: self.client.clone(),
+            encoded: self.encoded.clone(),
+            marker: std::marker::PhantomData,
+        };
+        submittable.via(client)?.submit().await
+    }
 }
 
 impl<T, C> SubmittableExtrinsic<T, C>
@@ -442,23 +1019,86 @@ where
     pub async fn submit_and_watch(&self) -> Result<TxProgress<T, C>, Error> {
         // Get a hash of the extrinsic (we'll need this later).
         let ext_hash = T::Hasher::hash_of(&self.encoded);
+        let span = tracing::info_span!("submit_and_watch", ext_hash = ?ext_hash);
 
         // Submit and watch for transaction progress.
-        let sub = self.client.rpc().watch_extrinsic(&self.encoded).await?;
+        let res = self
+            .client
+            .rpc()
+            .watch_extrinsic(&self.encoded)
+            .instrument(span)
+            .await;
+        self.record_submission(res.is_ok());
+        let sub = res.map_err(pool_rejection_or)?;
 
         Ok(TxProgress::new(sub, self.client.clone(), ext_hash))
     }
 
     /// Submits the extrinsic to the chain for block inclusion.
     ///
-    /// Returns `Ok` with the extrinsic hash if it is valid extrinsic.
+    /// Returns `Ok` with the extrinsic hash if it is valid extrinsic. This uses
+    /// `author_submitExtrinsic`, and so (unlike [`Self::submit_and_watch()`]) doesn't open a
+    /// subscription. Returns [`TransactionError::PoolRejected`] if the transaction pool
+    /// refuses the extrinsic.
     ///
     /// # Note
     ///
     /// Success does not mean the extrinsic has been included in the block, just that it is valid
     /// and has been included in the transaction pool.
     pub async fn submit(&self) -> Result<T::Hash, Error> {
-        self.client.rpc().submit_extrinsic(&self.encoded).await
+        let ext_hash = T::Hasher::hash_of(&self.encoded);
+        let span = tracing::info_span!("submit", ext_hash = ?ext_hash);
+        let res = self
+            .client
+            .rpc()
+            .submit_extrinsic(&self.encoded)
+            .instrument(span)
+            .await;
+        self.record_submission(res.is_ok());
+        res.map_err(pool_rejection_or)
+    }
+
+    /// Submits an unsigned extrinsic (for example one built with [`TxClient::create_unsigned()`])
+    /// to the chain for block inclusion, returning its hash if the node accepts it.
+    ///
+    /// Returns [`TransactionError::NotUnsigned`] if this extrinsic is signed, and
+    /// [`TransactionError::UnsignedRejected`] if the node refuses it, which is typically
+    /// because the call doesn't validate unsigned and must be signed instead.
+    pub async fn submit_unsigned(&self) -> Result<T::Hash, Error> {
+        if self.is_signed() {
+            return Err(TransactionError::NotUnsigned.into());
+        }
+        let ext_hash = T::Hasher::hash_of(&self.encoded);
+        let span = tracing::info_span!("submit_unsigned", ext_hash = ?ext_hash);
+        let res = self
+            .client
+            .rpc()
+            .submit_extrinsic(&self.encoded)
+            .instrument(span)
+            .await;
+        self.record_submission(res.is_ok());
+        match res {
+            Err(Error::Rpc(RpcError::ClientError(e))) => {
+                Err(TransactionError::UnsignedRejected(e.to_string()).into())
+            }
+            res => res,
+        }
+    }
+
+    // Report a submission of this extrinsic to the metrics recorder. Every submission goes
+    // through here, however the extrinsic was created. Decoding the extrinsic to find its
+    // pallet and call names isn't free, so we only do so if something is recording them.
+    fn record_submission(&self, accepted: bool) {
+        if !self.client.rpc().records_metrics() {
+            return;
+        }
+        let details = self.details().ok();
+        let pallet = details.as_ref().and_then(|d| d.pallet_name().ok());
+        let call = details.as_ref().and_then(|d| d.variant_name().ok());
+        self.client
+            .rpc()
+            .metrics_recorder()
+            .record_submission(pallet, call, accepted);
     }
 
     /// Submits the extrinsic to the dry_run RPC, to test if it would succeed.
@@ -472,19 +1112,44 @@ where
     /// This returns an estimate for what the extrinsic is expected to cost to execute, less any tips.
     /// The actual amount paid can vary from block to block based on node traffic and other factors.
     pub async fn partial_fee_estimate(&self) -> Result<u128, Error> {
+        let (_, _, _, partial_fee) = self.query_info().await?;
+        Ok(partial_fee)
+    }
+
+    /// This returns an estimate of the computational (`ref_time`) weight that the extrinsic is
+    /// expected to use, as reported by `TransactionPaymentApi_query_info`.
+    pub async fn weight_estimate(&self) -> Result<u64, Error> {
+        let (Compact(ref_time), _, _, _) = self.query_info().await?;
+        Ok(ref_time)
+    }
+
+    async fn query_info(&self) -> Result<(Compact<u64>, Compact<u64>, u8, u128), Error> {
         let mut params = self.encoded().to_vec();
         (self.encoded().len() as u32).encode_to(&mut params);
         // destructuring RuntimeDispatchInfo, see type information <https://paritytech.github.io/substrate/master/pallet_transaction_payment_rpc_runtime_api/struct.RuntimeDispatchInfo.html>
         // data layout: {weight_ref_time: Compact<u64>, weight_proof_size: Compact<u64>, class: u8, partial_fee: u128}
-        let (_, _, _, partial_fee) = self
-            .client
+        self.client
             .rpc()
             .state_call::<(Compact<u64>, Compact<u64>, u8, u128)>(
                 "TransactionPaymentApi_query_info",
                 Some(&params),
                 None,
             )
-            .await?;
-        Ok(partial_fee)
+            .await
+    }
+}
+
+/// Hand back [`TransactionError::PoolRejected`] if the error is the transaction pool refusing
+/// an extrinsic, and otherwise the error itself.
+fn pool_rejection_or(e: Error) -> Error {
+    match e {
+        Error::Rpc(RpcError::ClientError(e)) => {
+            let message = e.to_string();
+            match PoolRejection::from_message(&message) {
+                Some(reason) => TransactionError::PoolRejected(reason, message).into(),
+                None => Error::Rpc(RpcError::ClientError(e)),
+            }
+        }
+        e => e,
     }
 }
@@ -7,10 +7,11 @@
 
 use crate::{
     dynamic::Value,
-    error::{Error, MetadataError},
+    error::{ArgumentTooLongError, Error, MetadataError, TransactionError},
+    events::StaticEvent,
     metadata::Metadata,
 };
-use codec::Encode;
+use codec::{Decode, Encode};
 use scale_encode::EncodeAsFields;
 use scale_value::{Composite, ValueDef, Variant};
 use std::{borrow::Cow, sync::Arc};
@@ -37,6 +38,13 @@ pub trait TxPayload {
     }
 }
 
+/// Implemented by call data (typically by generated call structs) which has a
+/// canonical event that is emitted when the call succeeds.
+pub trait CallSuccessEvent {
+    /// The event emitted when this call succeeds.
+    type SuccessEvent: StaticEvent;
+}
+
 pub struct ValidationDetails<'a> {
     /// The pallet name.
     pub pallet_name: &'a str,
@@ -47,6 +55,30 @@ pub struct ValidationDetails<'a> {
     pub hash: [u8; 32],
 }
 
+/// The most items that a bounded call argument (for instance a `BoundedVec`) can hold.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MaxLength {
+    /// A fixed maximum, for instance from a `ConstU32<N>` bound.
+    Fixed(u32),
+    /// The maximum is the value of the pallet constant with this name, which is read from
+    /// the metadata when the call is encoded.
+    Constant(Cow<'static, str>),
+}
+
+impl MaxLength {
+    /// The maximum is the value of the pallet constant with the given name.
+    pub fn constant(name: impl Into<Cow<'static, str>>) -> Self {
+        MaxLength::Constant(name.into())
+    }
+}
+
+#[derive(Clone, Debug)]
+struct LengthBound {
+    arg_name: Cow<'static, str>,
+    len: usize,
+    max: MaxLength,
+}
+
 /// A transaction payload containing some generic `CallData`.
 #[derive(Clone, Debug)]
 pub struct Payload<CallData> {
@@ -54,6 +86,7 @@ pub struct Payload<CallData> {
     call_name: Cow<'static, str>,
     call_data: CallData,
     validation_hash: Option<[u8; 32]>,
+    length_bounds: Vec<LengthBound>,
 }
 
 /// A boxed transaction payload.
@@ -75,6 +108,7 @@ impl<CallData> Payload<CallData> {
             call_name: Cow::Owned(call_name.into()),
             call_data,
             validation_hash: None,
+            length_bounds: Vec::new(),
         }
     }
 
@@ -92,6 +126,7 @@ impl<CallData> Payload<CallData> {
             call_name: Cow::Borrowed(call_name),
             call_data,
             validation_hash: Some(validation_hash),
+            length_bounds: Vec::new(),
         }
     }
 
@@ -105,6 +140,7 @@ impl<CallData> Payload<CallData> {
             call_name: self.call_name,
             call_data: Arc::new(self.call_data),
             validation_hash: self.validation_hash,
+            length_bounds: self.length_bounds,
         }
     }
 
@@ -116,10 +152,33 @@ impl<CallData> Payload<CallData> {
         }
     }
 
+    /// Check, when the call is encoded, that the named argument (which has `len` items)
+    /// is no longer than the given maximum. Codegen does this for `BoundedVec` arguments,
+    /// so that over-long arguments are rejected here rather than by the node. Maximums
+    /// given as constants which can't be found in the metadata aren't checked.
+    pub fn with_length_bound(
+        mut self,
+        arg_name: impl Into<Cow<'static, str>>,
+        len: usize,
+        max: MaxLength,
+    ) -> Self {
+        self.length_bounds.push(LengthBound {
+            arg_name: arg_name.into(),
+            len,
+            max,
+        });
+        self
+    }
+
     /// Returns the call data.
     pub fn call_data(&self) -> &CallData {
         &self.call_data
     }
+
+    /// Consumes the payload, returning the call data.
+    pub fn into_call_data(self) -> CallData {
+        self.call_data
+    }
 }
 
 impl Payload<Composite<()>> {
@@ -146,6 +205,32 @@ impl<CallData: EncodeAsFields> TxPayload for Payload<CallData> {
             .call_variant_by_name(&self.call_name)
             .ok_or_else(|| MetadataError::CallNameNotFound((*self.call_name).to_owned()))?;
 
+        for bound in &self.length_bounds {
+            let max = match &bound.max {
+                MaxLength::Fixed(max) => u64::from(*max),
+                MaxLength::Constant(name) => {
+                    let constant = pallet.constant_by_name(name);
+                    match constant.and_then(|c| integer_value(c.value())) {
+                        Some(max) => max,
+                        // We can't check against a maximum that we don't know.
+                        None => continue,
+                    }
+                }
+            };
+            if bound.len as u64 > max {
+                return Err(
+                    TransactionError::ArgumentTooLong(Box::new(ArgumentTooLongError {
+                        pallet: self.pallet_name.to_string(),
+                        call: self.call_name.to_string(),
+                        argument: bound.arg_name.to_string(),
+                        len: bound.len,
+                        max,
+                    }))
+                    .into(),
+                );
+            }
+        }
+
         let pallet_index = pallet.index();
         let call_index = call.index;
 
@@ -166,6 +251,17 @@ impl<CallData: EncodeAsFields> TxPayload for Payload<CallData> {
     }
 }
 
+/// Decode the value of an unsigned integer constant.
+fn integer_value(mut bytes: &[u8]) -> Option<u64> {
+    match bytes.len() {
+        1 => u8::decode(&mut bytes).ok().map(u64::from),
+        2 => u16::decode(&mut bytes).ok().map(u64::from),
+        4 => u32::decode(&mut bytes).ok().map(u64::from),
+        8 => u64::decode(&mut bytes).ok(),
+        _ => None,
+    }
+}
+
 /// Construct a transaction at runtime; essentially an alias to [`Payload::new()`]
 /// which provides a [`Composite`] value for the call data.
 pub fn dynamic(
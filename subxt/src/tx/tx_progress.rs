@@ -4,7 +4,7 @@
 
 //! Types representing extrinsics/transactions that have been submitted to a node.
 
-use std::task::Poll;
+use std::{future::Future, task::Poll, time::Duration};
 
 use crate::{
     client::OnlineClientT,
@@ -14,7 +14,7 @@ use crate::{
     Config,
 };
 use derivative::Derivative;
-use futures::{Stream, StreamExt};
+use futures::{future::Either, Stream, StreamExt};
 
 /// This struct represents a subscription to the progress of some transaction.
 #[derive(Derivative)]
@@ -138,6 +138,52 @@ where
         let evs = self.wait_for_finalized().await?.wait_for_success().await?;
         Ok(evs)
     }
+
+    /// Like [`TxProgress::wait_for_in_block()`], but gives up with
+    /// [`TransactionError::WaitTimeout`] if the transaction isn't in a block within the
+    /// given duration. Giving up stops us watching the transaction, but it is left in the
+    /// transaction pool, and so it may still make it into a block.
+    pub async fn wait_for_in_block_with_timeout(
+        self,
+        timeout: Duration,
+    ) -> Result<TxInBlock<T, C>, Error> {
+        with_timeout(timeout, self.wait_for_in_block()).await
+    }
+
+    /// Like [`TxProgress::wait_for_finalized()`], but gives up with
+    /// [`TransactionError::WaitTimeout`] if the transaction isn't finalized within the given
+    /// duration (for instance because the chain has stalled). Giving up stops us watching the
+    /// transaction, but it is left in the transaction pool.
+    pub async fn wait_for_finalized_with_timeout(
+        self,
+        timeout: Duration,
+    ) -> Result<TxInBlock<T, C>, Error> {
+        with_timeout(timeout, self.wait_for_finalized()).await
+    }
+
+    /// Like [`TxProgress::wait_for_finalized_success()`], but gives up with
+    /// [`TransactionError::WaitTimeout`] if the transaction isn't finalized and its events
+    /// fetched within the given duration. Giving up stops us watching the transaction,
+    /// but it is left in the transaction pool.
+    pub async fn wait_for_finalized_success_with_timeout(
+        self,
+        timeout: Duration,
+    ) -> Result<crate::blocks::ExtrinsicEvents<T>, Error> {
+        with_timeout(timeout, self.wait_for_finalized_success()).await
+    }
+}
+
+/// Wait for the given future to complete, or return [`TransactionError::WaitTimeout`] if
+/// it doesn't complete in time. This is independent of any RPC request timeouts.
+async fn with_timeout<R>(
+    timeout: Duration,
+    fut: impl Future<Output = Result<R, Error>>,
+) -> Result<R, Error> {
+    futures::pin_mut!(fut);
+    match futures::future::select(fut, futures_timer::Delay::new(timeout)).await {
+        Either::Left((res, _)) => res,
+        Either::Right(_) => Err(TransactionError::WaitTimeout(timeout).into()),
+    }
 }
 
 impl<T: Config, C: Clone> Stream for TxProgress<T, C> {
@@ -355,18 +401,7 @@ impl<T: Config, C: OnlineClientT<T>> TxInBlock<T, C> {
     /// from them.
     pub async fn wait_for_success(&self) -> Result<crate::blocks::ExtrinsicEvents<T>, Error> {
         let events = self.fetch_events().await?;
-
-        // Try to find any errors; return the first one we encounter.
-        for ev in events.iter() {
-            let ev = ev?;
-            if ev.pallet_name() == "System" && ev.variant_name() == "ExtrinsicFailed" {
-                let dispatch_error =
-                    DispatchError::decode_from(ev.field_bytes(), self.client.metadata())?;
-                return Err(dispatch_error.into());
-            }
-        }
-
-        Ok(events)
+        ensure_success(events, &self.client)
     }
 
     /// Fetch all of the events associated with this transaction. This succeeds whether
@@ -408,11 +443,33 @@ impl<T: Config, C: OnlineClientT<T>> TxInBlock<T, C> {
     }
 }
 
+/// Return the given events if they don't contain an `ExtrinsicFailed` event, or
+/// the first dispatch error that we encounter if they do.
+///
+/// The outcome is also reported to the client's [`crate::client::MetricsRecorder`].
+pub(crate) fn ensure_success<T: Config, C: OnlineClientT<T>>(
+    events: crate::blocks::ExtrinsicEvents<T>,
+    client: &C,
+) -> Result<crate::blocks::ExtrinsicEvents<T>, Error> {
+    let metrics = client.rpc().metrics_recorder();
+    for ev in events.iter() {
+        let ev = ev?;
+        if ev.pallet_name() == "System" && ev.variant_name() == "ExtrinsicFailed" {
+            let dispatch_error = DispatchError::decode_from(ev.field_bytes(), client.metadata())?;
+            metrics.record_extrinsic_outcome(Some(&dispatch_error));
+            return Err(dispatch_error.into());
+        }
+    }
+
+    metrics.record_extrinsic_outcome(None);
+    Ok(events)
+}
+
 #[cfg(test)]
 mod test {
     use std::pin::Pin;
 
-    use futures::Stream;
+    use futures::{Stream, StreamExt};
 
     use crate::{
         client::{OfflineClientT, OnlineClientT},
@@ -491,22 +548,67 @@ mod test {
         ));
     }
 
+    #[tokio::test]
+    async fn wait_for_finalized_with_timeout_gives_up_if_never_finalized() {
+        let tx_progress = mock_tx_progress_never_finalized(vec![
+            SubstrateTxStatus::Ready,
+            SubstrateTxStatus::InBlock(Default::default()),
+        ]);
+        let timeout = std::time::Duration::from_millis(50);
+        let finalized_result = tx_progress.wait_for_finalized_with_timeout(timeout).await;
+        assert!(matches!(
+            finalized_result,
+            Err(Error::Transaction(crate::error::TransactionError::WaitTimeout(t))) if t == timeout
+        ));
+    }
+
+    #[tokio::test]
+    async fn wait_for_in_block_with_timeout_returns_in_time() {
+        let tx_progress = mock_tx_progress_never_finalized(vec![
+            SubstrateTxStatus::Ready,
+            SubstrateTxStatus::InBlock(Default::default()),
+        ]);
+        let in_block = tx_progress
+            .wait_for_in_block_with_timeout(std::time::Duration::from_secs(60))
+            .await;
+        assert!(in_block.is_ok());
+    }
+
     fn mock_tx_progress(statuses: Vec<MockSubstrateTxStatus>) -> MockTxProgress {
         let sub = create_substrate_tx_status_subscription(statuses);
         TxProgress::new(sub, MockClient, Default::default())
     }
 
-    fn create_substrate_tx_status_subscription(
+    /// Like [`mock_tx_progress`], but the subscription stays open without handing back
+    /// anything else once the statuses given run out, like a node whose chain has stalled.
+    fn mock_tx_progress_never_finalized(statuses: Vec<MockSubstrateTxStatus>) -> MockTxProgress {
+        let sub = create_substrate_tx_status_subscription_from_stream(Box::pin(
+            status_stream(statuses).chain(futures::stream::pending()),
+        ));
+        TxProgress::new(sub, MockClient, Default::default())
+    }
+
+    fn status_stream(
         elements: Vec<MockSubstrateTxStatus>,
-    ) -> Subscription<MockSubstrateTxStatus> {
-        let rpc_substription_stream: Pin<
-            Box<dyn Stream<Item = Result<Box<RawValue>, RpcError>> + Send + 'static>,
-        > = Box::pin(futures::stream::iter(elements.into_iter().map(|e| {
+    ) -> impl Stream<Item = Result<Box<RawValue>, RpcError>> + Send + 'static {
+        futures::stream::iter(elements.into_iter().map(|e| {
             let s = serde_json::to_string(&e).unwrap();
             let r: Box<RawValue> = RawValue::from_string(s).unwrap();
             Ok(r)
-        })));
+        }))
+    }
+
+    fn create_substrate_tx_status_subscription(
+        elements: Vec<MockSubstrateTxStatus>,
+    ) -> Subscription<MockSubstrateTxStatus> {
+        create_substrate_tx_status_subscription_from_stream(Box::pin(status_stream(elements)))
+    }
 
+    fn create_substrate_tx_status_subscription_from_stream(
+        rpc_substription_stream: Pin<
+            Box<dyn Stream<Item = Result<Box<RawValue>, RpcError>> + Send + 'static>,
+        >,
+    ) -> Subscription<MockSubstrateTxStatus> {
         let rpc_subscription: RpcSubscription = RpcSubscription {
             stream: rpc_substription_stream,
             id: None,
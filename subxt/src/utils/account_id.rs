@@ -44,6 +44,19 @@ impl From<[u8; 32]> for AccountId32 {
     }
 }
 
+impl From<AccountId32> for [u8; 32] {
+    fn from(x: AccountId32) -> Self {
+        x.0
+    }
+}
+
+impl TryFrom<&[u8]> for AccountId32 {
+    type Error = std::array::TryFromSliceError;
+    fn try_from(x: &[u8]) -> Result<Self, Self::Error> {
+        Ok(AccountId32(x.try_into()?))
+    }
+}
+
 impl AccountId32 {
     // Return the ss58-check string for this key. Adapted from `sp_core::crypto`. We need this to
     // serialize our account appropriately but otherwise don't care.
@@ -51,9 +64,29 @@ impl AccountId32 {
         // For serializing to a string to obtain the account nonce, we use the default substrate
         // prefix (since we have no way to otherwise pick one). It doesn't really matter, since when
         // it's deserialized back in system_accountNextIndex, we ignore this (so long as it's valid).
-        const SUBSTRATE_SS58_PREFIX: u8 = 42;
-        // prefix <= 63 just take up one byte at the start:
-        let mut v = vec![SUBSTRATE_SS58_PREFIX];
+        const SUBSTRATE_SS58_PREFIX: u16 = 42;
+        self.to_ss58check_with_prefix(SUBSTRATE_SS58_PREFIX)
+            .expect("42 is a valid SS58 prefix")
+    }
+
+    /// Return the ss58-check string for this key, using the given network prefix (for example
+    /// 0 for Polkadot or 2 for Kusama). See [`crate::OnlineClient::ss58_prefix()`] to obtain the
+    /// prefix that a node uses.
+    ///
+    /// Returns [`FromSs58Error::InvalidPrefix`] if the prefix is not a valid SS58 prefix (ie is
+    /// larger than 16383).
+    pub fn to_ss58check_with_prefix(&self, prefix: u16) -> Result<String, FromSs58Error> {
+        let mut v = match prefix {
+            // prefix <= 63 just take up one byte at the start:
+            0..=63 => vec![prefix as u8],
+            // prefix <= 16383 is packed into two bytes; see `sp_core::crypto::Ss58Codec`.
+            64..=16_383 => {
+                let first = ((prefix & 0b0000_0000_1111_1100) as u8) >> 2;
+                let second = ((prefix >> 8) as u8) | ((prefix & 0b0000_0000_0000_0011) as u8) << 6;
+                vec![first | 0b0100_0000, second]
+            }
+            _ => return Err(FromSs58Error::InvalidPrefix),
+        };
         // then push the account ID bytes.
         v.extend(self.0);
         // then push a 2 byte checksum of what we have so far.
@@ -61,13 +94,34 @@ impl AccountId32 {
         v.extend(&r[0..2]);
         // then encode to base58.
         use base58::ToBase58;
-        v.to_base58()
+        Ok(v.to_base58())
     }
 
     // This isn't strictly needed, but to give our AccountId32 a little more usefulness, we also
     // implement the logic needed to decode an AccountId32 from an SS58 encoded string. This is exposed
     // via a `FromStr` impl.
     fn from_ss58check(s: &str) -> Result<Self, FromSs58Error> {
+        Self::from_ss58check_with_prefix(s).map(|(account_id, _prefix)| account_id)
+    }
+
+    /// Decode an AccountId32 from an SS58 encoded string, failing if the network prefix
+    /// of the string is not the one expected.
+    pub fn from_ss58check_expecting_prefix(
+        s: &str,
+        expected_prefix: u16,
+    ) -> Result<Self, FromSs58Error> {
+        let (account_id, prefix) = Self::from_ss58check_with_prefix(s)?;
+        if prefix != expected_prefix {
+            return Err(FromSs58Error::UnexpectedPrefix {
+                expected: expected_prefix,
+                found: prefix,
+            });
+        }
+        Ok(account_id)
+    }
+
+    // Decode an SS58 encoded string, returning the AccountId32 and the network prefix.
+    fn from_ss58check_with_prefix(s: &str) -> Result<(Self, u16), FromSs58Error> {
         const CHECKSUM_LEN: usize = 2;
         let body_len = 32;
 
@@ -76,9 +130,14 @@ impl AccountId32 {
         if data.len() < 2 {
             return Err(FromSs58Error::BadLength);
         }
-        let prefix_len = match data[0] {
-            0..=63 => 1,
-            64..=127 => 2,
+        let (prefix_len, prefix) = match data[0] {
+            0..=63 => (1, data[0] as u16),
+            64..=127 => {
+                // Two byte prefixes are packed into 14 bits; see `sp_core::crypto::Ss58Codec`.
+                let lower = (data[0] << 2) | (data[1] >> 6);
+                let upper = data[1] & 0b0011_1111;
+                (2, (lower as u16) | ((upper as u16) << 8))
+            }
             _ => return Err(FromSs58Error::InvalidPrefix),
         };
         if data.len() != prefix_len + body_len + CHECKSUM_LEN {
@@ -94,7 +153,7 @@ impl AccountId32 {
         let result = data[prefix_len..body_len + prefix_len]
             .try_into()
             .map_err(|_| FromSs58Error::BadLength)?;
-        Ok(AccountId32(result))
+        Ok((AccountId32(result), prefix))
     }
 }
 
@@ -110,6 +169,8 @@ pub enum FromSs58Error {
     InvalidChecksum,
     #[error("Invalid SS58 prefix byte.")]
     InvalidPrefix,
+    #[error("Unexpected SS58 network prefix; expected {expected} but found {found}")]
+    UnexpectedPrefix { expected: u16, found: u16 },
 }
 
 // We do this just to get a checksum to help verify the validity of the address in to_ss58check
@@ -214,4 +275,72 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn ss58_with_prefix_is_compatible_with_substrate_impl() {
+        use sp_core::crypto::Ss58AddressFormat;
+
+        let substrate_account = AccountKeyring::Alice.to_account_id();
+        let local_account = AccountId32(substrate_account.clone().into());
+
+        for prefix in [0, 2, 42, 63, 64, 1284, 16_383] {
+            let substrate_ss58 =
+                substrate_account.to_ss58check_with_version(Ss58AddressFormat::custom(prefix));
+            assert_eq!(
+                substrate_ss58,
+                local_account.to_ss58check_with_prefix(prefix).unwrap()
+            );
+            assert_eq!(
+                AccountId32::from_ss58check_expecting_prefix(&substrate_ss58, prefix).unwrap(),
+                local_account
+            );
+        }
+    }
+
+    #[test]
+    fn ss58_with_known_network_addresses() {
+        let alice = AccountId32(AccountKeyring::Alice.to_account_id().into());
+
+        let polkadot = "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5";
+        let kusama = "HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F";
+
+        assert_eq!(alice.to_ss58check_with_prefix(0).unwrap(), polkadot);
+        assert_eq!(alice.to_ss58check_with_prefix(2).unwrap(), kusama);
+        assert_eq!(
+            AccountId32::from_ss58check_expecting_prefix(polkadot, 0).unwrap(),
+            alice
+        );
+        assert_eq!(
+            AccountId32::from_ss58check_expecting_prefix(kusama, 0),
+            Err(FromSs58Error::UnexpectedPrefix {
+                expected: 0,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn out_of_range_ss58_prefixes_are_rejected() {
+        let alice = AccountId32(AccountKeyring::Alice.to_account_id().into());
+
+        assert_eq!(
+            alice.to_ss58check_with_prefix(16_384),
+            Err(FromSs58Error::InvalidPrefix)
+        );
+        assert_eq!(
+            alice.to_ss58check_with_prefix(u16::MAX),
+            Err(FromSs58Error::InvalidPrefix)
+        );
+    }
+
+    #[test]
+    fn raw_bytes_round_trip() {
+        let bytes = [7u8; 32];
+        let account = AccountId32::from(bytes);
+        assert_eq!(<[u8; 32]>::from(account.clone()), bytes);
+        assert_eq!(AsRef::<[u8]>::as_ref(&account), &bytes[..]);
+
+        assert_eq!(AccountId32::try_from(&bytes[..]).unwrap(), account);
+        assert!(AccountId32::try_from(&bytes[..31]).is_err());
+    }
 }
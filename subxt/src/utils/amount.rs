@@ -0,0 +1,167 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! Convert human readable token amounts (for instance `"1.5"` DOT) into the integer base
+//! units (planck) that balances are given in.
+
+/// An error converting a decimal amount into base units.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum AmountError {
+    /// The amount isn't a plain, non negative decimal number like `12` or `0.25`.
+    #[error("'{0}' is not a valid decimal amount")]
+    Invalid(String),
+    /// The amount has more decimal places than the token has, and so would need rounding.
+    #[error("'{amount}' has more than the {decimals} decimal places of the token")]
+    TooPrecise {
+        /// The amount given.
+        amount: String,
+        /// The number of decimal places the token has.
+        decimals: u32,
+    },
+    /// The amount is too large to be represented in base units.
+    #[error("'{0}' is too large to be represented in base units")]
+    Overflow(String),
+}
+
+/// Convert a decimal amount of some token (for instance `"1.5"`) into an integer number of
+/// its base units, given the number of decimals that the token has. The number of decimals
+/// of each token is given by `system_properties`, and so can be found via
+/// [`crate::OnlineClient::properties()`].
+///
+/// Amounts are never rounded: more decimal places than the token has are an error, unless the
+/// extra digits are all zero. Amounts which don't fit into `N` are also an error.
+///
+/// ```rust
+/// use subxt::utils::parse_amount;
+///
+/// // DOT has 10 decimals, so 1.5 DOT is 15_000_000_000 planck:
+/// assert_eq!(parse_amount::<u128>("1.5", 10), Ok(15_000_000_000));
+/// // Less than one planck can't be represented:
+/// assert!(parse_amount::<u128>("0.00000000001", 10).is_err());
+/// ```
+pub fn parse_amount<N: TryFrom<u128>>(amount: &str, decimals: u32) -> Result<N, AmountError> {
+    let invalid = || AmountError::Invalid(amount.to_owned());
+    let overflow = || AmountError::Overflow(amount.to_owned());
+
+    let (whole, fraction) = match amount.split_once('.') {
+        Some((whole, fraction)) => (whole, fraction),
+        None => (amount, ""),
+    };
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if whole.is_empty() || !is_digits(whole) || !is_digits(fraction) {
+        return Err(invalid());
+    }
+    if amount.contains('.') && fraction.is_empty() {
+        return Err(invalid());
+    }
+
+    // Extra decimal places are fine so long as they don't need rounding away.
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.len() > decimals as usize {
+        return Err(AmountError::TooPrecise {
+            amount: amount.to_owned(),
+            decimals,
+        });
+    }
+
+    let unit = 10u128.checked_pow(decimals).ok_or_else(overflow)?;
+    let whole: u128 = whole.parse().map_err(|_| overflow())?;
+    let fraction: u128 = if fraction.is_empty() {
+        0
+    } else {
+        // There are no more digits than `decimals`, so this fits if `unit` does.
+        let scale = 10u128.pow(decimals - fraction.len() as u32);
+        fraction.parse::<u128>().map_err(|_| overflow())? * scale
+    };
+
+    let base_units = whole
+        .checked_mul(unit)
+        .and_then(|whole| whole.checked_add(fraction))
+        .ok_or_else(overflow)?;
+    N::try_from(base_units).map_err(|_| overflow())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amounts_are_converted_to_base_units() {
+        assert_eq!(parse_amount::<u128>("1", 12), Ok(1_000_000_000_000));
+        assert_eq!(parse_amount::<u128>("1.5", 12), Ok(1_500_000_000_000));
+        assert_eq!(parse_amount::<u128>("0.000000000001", 12), Ok(1));
+        assert_eq!(parse_amount::<u128>("007.25", 2), Ok(725));
+        assert_eq!(parse_amount::<u128>("42", 0), Ok(42));
+        assert_eq!(parse_amount::<u64>("0", 18), Ok(0));
+    }
+
+    #[test]
+    fn amounts_are_never_rounded() {
+        // Trailing zeros don't need rounding away:
+        assert_eq!(
+            parse_amount::<u128>("1.5000000000000", 12),
+            Ok(1_500_000_000_000)
+        );
+        assert_eq!(parse_amount::<u128>("3.000", 0), Ok(3));
+
+        let too_precise = AmountError::TooPrecise {
+            amount: "0.0000000000001".to_owned(),
+            decimals: 12,
+        };
+        assert_eq!(
+            parse_amount::<u128>("0.0000000000001", 12),
+            Err(too_precise)
+        );
+        assert!(matches!(
+            parse_amount::<u128>("1.999", 2),
+            Err(AmountError::TooPrecise { decimals: 2, .. })
+        ));
+        assert!(matches!(
+            parse_amount::<u128>("0.5", 0),
+            Err(AmountError::TooPrecise { decimals: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn amounts_that_do_not_fit_overflow() {
+        let max = u128::MAX.to_string();
+        assert_eq!(parse_amount::<u128>(&max, 0), Ok(u128::MAX));
+
+        let too_big = "340282366920938463463374607431768211456";
+        assert_eq!(
+            parse_amount::<u128>(too_big, 0),
+            Err(AmountError::Overflow(too_big.to_owned()))
+        );
+        // Fits as a whole number, but not once multiplied up into base units:
+        assert!(matches!(
+            parse_amount::<u128>("340282366920938463463374607431768212", 3),
+            Err(AmountError::Overflow(_))
+        ));
+        // 10^39 planck is more than a u128 can hold, even for an amount of 0:
+        assert!(matches!(
+            parse_amount::<u128>("0", 39),
+            Err(AmountError::Overflow(_))
+        ));
+        // Fits into a u128, but not into the smaller integer asked for:
+        assert!(matches!(
+            parse_amount::<u64>("18446744073709551616", 0),
+            Err(AmountError::Overflow(_))
+        ));
+        assert_eq!(parse_amount::<u64>("18446744073709551615", 0), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn malformed_amounts_are_invalid() {
+        for amount in [
+            "", ".", ".5", "5.", "1.2.3", "-1", "+1", "1e12", " 1", "1,000", "1_000",
+        ] {
+            assert_eq!(
+                parse_amount::<u128>(amount, 12),
+                Err(AmountError::Invalid(amount.to_owned())),
+                "{amount:?}"
+            );
+        }
+    }
+}
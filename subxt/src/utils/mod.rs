@@ -5,16 +5,19 @@
 //! Miscellaneous utility helpers.
 
 mod account_id;
+mod amount;
 pub mod bits;
 mod multi_address;
 mod multi_signature;
+pub mod serde_hex;
 mod static_type;
 mod wrapper_opaque;
 
 use codec::{Decode, Encode};
 use derivative::Derivative;
 
-pub use account_id::AccountId32;
+pub use account_id::{AccountId32, FromSs58Error};
+pub use amount::{parse_amount, AmountError};
 pub use multi_address::MultiAddress;
 pub use multi_signature::MultiSignature;
 pub use static_type::Static;
@@ -6,6 +6,7 @@
 //! This doesn't contain much functionality itself, but is easy to convert to/from an `sp_runtime::MultiAddress`
 //! for instance, to gain functionality without forcing a dependency on Substrate crates here.
 
+use super::account_id::{AccountId32, FromSs58Error};
 use codec::{Decode, Encode};
 
 /// A multi-format address wrapper for on-chain accounts. This is a simplified version of Substrate's
@@ -36,6 +37,25 @@ pub enum MultiAddress<AccountId, AccountIndex> {
     Address20([u8; 20]),
 }
 
+impl<AccountId, AccountIndex> MultiAddress<AccountId, AccountIndex> {
+    /// Construct an address from an account ID.
+    pub fn id(account_id: impl Into<AccountId>) -> Self {
+        Self::Id(account_id.into())
+    }
+}
+
+impl<AccountIndex> MultiAddress<AccountId32, AccountIndex> {
+    /// Construct an address from an SS58 encoded account ID. If `expected_prefix` is given,
+    /// the network prefix of the string must match it.
+    pub fn from_ss58(s: &str, expected_prefix: Option<u16>) -> Result<Self, FromSs58Error> {
+        let account_id = match expected_prefix {
+            Some(prefix) => AccountId32::from_ss58check_expecting_prefix(s, prefix)?,
+            None => s.parse()?,
+        };
+        Ok(Self::Id(account_id))
+    }
+}
+
 impl<AccountId, AccountIndex> From<AccountId> for MultiAddress<AccountId, AccountIndex> {
     fn from(a: AccountId) -> Self {
         Self::Id(a)
@@ -45,7 +65,7 @@ impl<AccountId, AccountIndex> From<AccountId> for MultiAddress<AccountId, Accoun
 // Improve compat with the substrate version if we're using those crates:
 #[cfg(feature = "substrate-compat")]
 mod substrate_impls {
-    use super::{super::AccountId32, *};
+    use super::*;
 
     impl<N> From<sp_runtime::AccountId32> for MultiAddress<AccountId32, N> {
         fn from(value: sp_runtime::AccountId32) -> Self {
@@ -69,3 +89,45 @@ mod substrate_impls {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // The SS58 encoding of Alice's account ID, with the default substrate prefix (42).
+    const ALICE_SS58: &str = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+
+    #[test]
+    fn from_ss58_with_valid_addresses() {
+        let address = MultiAddress::<AccountId32, ()>::from_ss58(ALICE_SS58, None).unwrap();
+        let alice: AccountId32 = ALICE_SS58.parse().unwrap();
+        assert_eq!(address, MultiAddress::id(alice.clone()));
+
+        let address = MultiAddress::<AccountId32, ()>::from_ss58(ALICE_SS58, Some(42)).unwrap();
+        assert_eq!(address, MultiAddress::Id(alice));
+    }
+
+    #[test]
+    fn from_ss58_with_invalid_addresses() {
+        assert_eq!(
+            MultiAddress::<AccountId32, ()>::from_ss58(ALICE_SS58, Some(0)),
+            Err(FromSs58Error::UnexpectedPrefix {
+                expected: 0,
+                found: 42
+            })
+        );
+        assert_eq!(
+            MultiAddress::<AccountId32, ()>::from_ss58("not ss58!", None),
+            Err(FromSs58Error::BadBase58)
+        );
+
+        // Changing the last character breaks the checksum:
+        let mut bad_checksum = ALICE_SS58.to_string();
+        bad_checksum.pop();
+        bad_checksum.push('Z');
+        assert_eq!(
+            MultiAddress::<AccountId32, ()>::from_ss58(&bad_checksum, None),
+            Err(FromSs58Error::InvalidChecksum)
+        );
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! (De)serialize byte sequences as `0x` prefixed hex strings rather than arrays of numbers.
+//! Generated types which derive serde's traits use this for their `Vec<u8>` and `[u8; N]`
+//! fields, via `#[serde(with = "subxt::utils::serde_hex")]`.
+
+use serde::{de::Error as _, Deserializer, Serializer};
+
+/// Serialize some bytes as a `0x` prefixed hex string.
+pub fn serialize<S: Serializer, T: AsRef<[u8]>>(
+    bytes: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    impl_serde::serialize::serialize(bytes.as_ref(), serializer)
+}
+
+/// Deserialize a `0x` prefixed hex string into some bytes (for instance a `Vec<u8>`, or a
+/// `[u8; N]` if the string decodes to exactly `N` bytes).
+pub fn deserialize<'de, D: Deserializer<'de>, T: TryFrom<Vec<u8>>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    let bytes = impl_serde::serialize::deserialize(deserializer)?;
+    let len = bytes.len();
+    T::try_from(bytes).map_err(|_| D::Error::custom(format!("unexpected number of bytes: {len}")))
+}
+
+#[cfg(test)]
+mod test {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Foo {
+        #[serde(with = "super")]
+        array: [u8; 4],
+        #[serde(with = "super")]
+        vec: Vec<u8>,
+    }
+
+    #[test]
+    fn bytes_roundtrip_as_hex() {
+        let foo = Foo {
+            array: [0xde, 0xad, 0xbe, 0xef],
+            vec: vec![1, 2],
+        };
+        let json = serde_json::to_string(&foo).unwrap();
+        assert_eq!(json, r#"{"array":"0xdeadbeef","vec":"0x0102"}"#);
+        assert_eq!(serde_json::from_str::<Foo>(&json).unwrap(), foo);
+
+        // Arrays must be given exactly the right number of bytes:
+        assert!(serde_json::from_str::<Foo>(r#"{"array":"0xdead","vec":"0x"}"#).is_err());
+    }
+}
@@ -179,3 +179,206 @@ async fn decode_extrinsics() {
         .unwrap();
     assert!(tx.is_signed());
 }
+
+#[tokio::test]
+async fn mortal_extrinsics_can_be_anchored_to_a_trusted_block() -> Result<(), subxt::Error> {
+    use subxt::config::{extrinsic_params::Era, substrate::SubstrateExtrinsicParamsBuilder};
+
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    // Anchor the transaction to the latest finalized block, rather than the best one:
+    let anchor_hash = api.rpc().finalized_head().await?;
+    let anchor = api.rpc().header(Some(anchor_hash)).await?.unwrap();
+    let anchor_number: u64 = anchor.number.into();
+    let params = SubstrateExtrinsicParamsBuilder::new().mortal_from_header(32, &anchor);
+
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let tx = node_runtime::tx().system().remark(vec![1, 2, 3]);
+    let events = api
+        .tx()
+        .sign_and_submit_then_watch(&tx, &alice, params)
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    let block = api.blocks().at(events.block_hash()).await?;
+    let ext = block
+        .body()
+        .await?
+        .extrinsics()
+        .iter()
+        .nth(events.extrinsic_index() as usize)
+        .unwrap()?;
+    let era = ext.signed_extensions().unwrap().era()?;
+    assert_eq!(era, Some(Era::mortal(32, anchor_number)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn tx_builder_overrides_end_up_in_the_signed_extensions() -> Result<(), subxt::Error> {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let chain_nonce = api.tx().account_nonce(alice.account_id()).await?;
+
+    // Give the nonce and tip explicitly for the first transaction..
+    let tx = node_runtime::tx().system().remark(vec![1, 2, 3]);
+    let explicit = api
+        .tx()
+        .build(tx)
+        .tip(1234)
+        .nonce(chain_nonce)
+        .sign_and_submit_then_watch(&alice)
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    // ..and leave them unset for the second, so that the nonce is fetched from the chain.
+    let tx = node_runtime::tx().system().remark(vec![4, 5, 6]);
+    let defaulted = api
+        .tx()
+        .build(tx)
+        .sign_and_submit_then_watch(&alice)
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    let mut signed_extensions = vec![];
+    for events in [explicit, defaulted] {
+        let block = api.blocks().at(events.block_hash()).await?;
+        let ext = block
+            .body()
+            .await?
+            .extrinsics()
+            .iter()
+            .nth(events.extrinsic_index() as usize)
+            .unwrap()?;
+        let extensions = ext.signed_extensions().unwrap();
+        signed_extensions.push((extensions.nonce()?, extensions.tip()?));
+    }
+
+    let chain_nonce = chain_nonce as u64;
+    assert_eq!(
+        signed_extensions,
+        vec![
+            (Some(chain_nonce), Some(1234)),
+            (Some(chain_nonce + 1), Some(0))
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_extrinsic_after_submission() -> Result<(), subxt::Error> {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let tx = node_runtime::tx().system().remark(vec![1, 2, 3]);
+
+    let events = api
+        .tx()
+        .sign_and_submit_then_watch_default(&tx, &alice)
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    let found = api
+        .blocks()
+        .find_extrinsic(events.extrinsic_hash(), 10)
+        .await?
+        .expect("extrinsic should be found in recent blocks");
+    assert_eq!(found.block_hash(), events.block_hash());
+    assert_eq!(found.extrinsic_index(), events.extrinsic_index());
+
+    // We won't find an extrinsic which was never submitted:
+    let not_found = api.blocks().find_extrinsic(Default::default(), 10).await?;
+    assert!(not_found.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn block_extrinsic_events_are_grouped_by_extrinsic() -> Result<(), subxt::Error> {
+    let ctx = test_context().await;
+    let api = ctx.client();
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+
+    let tx = node_runtime::tx().system().remark(vec![1, 2, 3]);
+    let tx_events = api
+        .tx()
+        .sign_and_submit_then_watch_default(&tx, &alice)
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    let block = api.blocks().at(tx_events.block_hash()).await?;
+    let grouped = block.extrinsic_events().await?;
+
+    // Every extrinsic in the block should be accounted for, in order.
+    let body = block.body().await?;
+    assert_eq!(grouped.extrinsics().len(), body.extrinsics().len());
+
+    let (_, remark_events) = grouped
+        .extrinsics()
+        .iter()
+        .find(|(hash, _)| *hash == tx_events.extrinsic_hash())
+        .expect("our extrinsic should be in the block");
+
+    // They should be the same events that we see for the extrinsic having submitted it.
+    let names: Vec<_> = remark_events
+        .iter()
+        .map(|ev| format!("{}::{}", ev.pallet_name(), ev.variant_name()))
+        .collect();
+    let expected: Vec<_> = tx_events
+        .iter()
+        .map(|ev| ev.map(|ev| format!("{}::{}", ev.pallet_name(), ev.variant_name())))
+        .collect::<Result<_, _>>()?;
+    assert_eq!(names, expected);
+    assert!(remark_events
+        .iter()
+        .any(|ev| ev.pallet_name() == "System" && ev.variant_name() == "ExtrinsicSuccess"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn events_are_paired_with_the_extrinsics_that_emitted_them() -> Result<(), subxt::Error> {
+    use node_runtime::balances::{calls::types::Transfer, events};
+
+    let ctx = test_context().await;
+    let api = ctx.client();
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let bob = AccountKeyring::Bob.to_account_id();
+
+    let tx = node_runtime::tx().balances().transfer(bob.into(), 10_000);
+    let tx_events = api
+        .tx()
+        .sign_and_submit_then_watch_default(&tx, &alice)
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    let block = api.blocks().at(tx_events.block_hash()).await?;
+    let events_with_extrinsics = block.events_with_extrinsics().await?;
+    assert_eq!(
+        events_with_extrinsics.extrinsics().len(),
+        block.body().await?.extrinsics().len()
+    );
+
+    let (_, extrinsic) = events_with_extrinsics
+        .iter()
+        .find(|(ev, _)| ev.as_event::<events::Transfer>().ok().flatten().is_some())
+        .expect("the transfer event should be in the block");
+    let call = extrinsic
+        .expect("the transfer event was emitted by an extrinsic")
+        .as_extrinsic::<Transfer>()?
+        .expect("the extrinsic should be the transfer");
+    assert_eq!(call.value, 10_000);
+
+    Ok(())
+}
@@ -4,7 +4,7 @@
 
 use crate::{
     pair_signer, test_context, test_context_with,
-    utils::{node_runtime, wait_for_blocks},
+    utils::{mock_client, node_runtime, wait_for_blocks, MockRpc},
 };
 use assert_matches::assert_matches;
 use codec::{Compact, Decode, Encode};
@@ -12,10 +12,10 @@ use sp_core::storage::well_known_keys;
 use sp_core::{sr25519::Pair as Sr25519Pair, Pair};
 use sp_keyring::AccountKeyring;
 use subxt::{
-    error::{DispatchError, Error, TokenError},
+    error::{ClientInitError, DispatchError, Error, RpcError, TokenError, TransactionError},
     rpc::types::{
-        ChainHeadEvent, DryRunResult, DryRunResultBytes, FollowEvent, Initialized, RuntimeEvent,
-        RuntimeVersionEvent,
+        ChainHeadEvent, DryRunResult, DryRunResultBytes, ExtrinsicOrHash, FollowEvent, Initialized,
+        RuntimeEvent, RuntimeVersionEvent, TraceBlockResponse,
     },
     tx::Signer,
     utils::AccountId32,
@@ -151,6 +151,54 @@ async fn fetch_system_info() {
     assert!(!api.rpc().system_version().await.unwrap().is_empty());
 }
 
+#[tokio::test]
+async fn cached_genesis_hash_and_runtime_version_match_node() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let genesis_hash = api.rpc().genesis_hash().await.unwrap();
+    assert_eq!(api.genesis_hash(), genesis_hash);
+
+    let runtime_version = api.rpc().runtime_version(None).await.unwrap();
+    assert_eq!(api.runtime_version(), runtime_version);
+}
+
+#[tokio::test]
+async fn live_runtime_versions_feed_into_signer_payload() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let runtime_version = api.rpc().runtime_version(None).await.unwrap();
+    assert_eq!(
+        api.transaction_version(),
+        runtime_version.transaction_version
+    );
+
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let tx = node_runtime::tx().system().remark(vec![1, 2, 3]);
+    let partial_extrinsic = api
+        .tx()
+        .create_partial_signed(&tx, alice.account_id(), Default::default())
+        .await
+        .unwrap();
+
+    // The payload is short enough not to be hashed, and (given default, immortal params)
+    // ends with the spec version, transaction version, genesis hash and genesis hash again.
+    let payload = partial_extrinsic.signer_payload();
+    let additional = &payload[payload.len() - 72..];
+    let genesis_hash = api.genesis_hash();
+    assert_eq!(
+        additional,
+        (
+            runtime_version.spec_version,
+            runtime_version.transaction_version,
+            genesis_hash,
+            genesis_hash
+        )
+            .encode()
+    );
+}
+
 #[tokio::test]
 async fn dry_run_passes() {
     let ctx = test_context().await;
@@ -323,6 +371,99 @@ async fn external_signing() {
         .unwrap();
 }
 
+#[tokio::test]
+async fn signatures_over_the_exported_signer_payload_can_be_attached() {
+    use sp_core::ed25519::Pair as Ed25519Pair;
+    use subxt::{tx::PairSigner, utils::MultiSignature, SubstrateConfig};
+
+    let ctx = test_context().await;
+    let api = ctx.client();
+    let alice = AccountKeyring::Alice;
+
+    let tx = node_runtime::tx().system().remark(vec![1, 2, 3]);
+    let partial_extrinsic = api
+        .tx()
+        .create_partial_signed(&tx, &alice.to_account_id().into(), Default::default())
+        .await
+        .unwrap();
+    let signer_payload = partial_extrinsic.signer_payload();
+
+    // Ed25519 signatures are deterministic, so a signature produced outside of Subxt over the
+    // exported payload gives exactly the same extrinsic as signing in place:
+    let ed25519_pair = Ed25519Pair::from_string("//Alice", None).unwrap();
+    let ed25519_signer = PairSigner::<SubstrateConfig, _>::new(ed25519_pair.clone());
+    let signature = MultiSignature::Ed25519(ed25519_pair.sign(&signer_payload).0);
+    let external =
+        partial_extrinsic.with_signature(ed25519_signer.account_id().clone(), &signature);
+    assert_eq!(
+        external.encoded(),
+        partial_extrinsic.sign(&ed25519_signer).encoded()
+    );
+
+    // A signature from the account that the extrinsic was created for is valid on chain:
+    let signature = MultiSignature::Sr25519(alice.pair().sign(&signer_payload).0);
+    partial_extrinsic
+        .with_signature(alice.to_account_id().into(), &signature)
+        .submit_and_watch()
+        .await
+        .unwrap()
+        .wait_for_finalized_success()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn signed_len_matches_the_signed_extrinsic() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+
+    let tx = node_runtime::tx().preimage().note_preimage(vec![0u8; 32]);
+    let partial_extrinsic = api
+        .tx()
+        .create_partial_signed(&tx, alice.account_id(), Default::default())
+        .await
+        .unwrap();
+
+    let signed_len = partial_extrinsic.signed_len(&alice);
+    let extrinsic = partial_extrinsic.sign(&alice);
+    let extrinsic_hex = hex::encode(extrinsic.encoded());
+
+    assert_eq!(signed_len, extrinsic_hex.len() / 2);
+    assert_eq!(signed_len, extrinsic.encoded_len());
+    // Version byte, 33 byte address and 65 byte signature on top of the call data at least:
+    assert!(signed_len > partial_extrinsic.call_data().len() + 1 + 33 + 65);
+}
+
+#[tokio::test]
+async fn submit_unsigned_rejects_calls_which_need_signing() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let bob: AccountId32 = AccountKeyring::Bob.to_account_id().into();
+    let tx = node_runtime::tx().balances().transfer(bob.into(), 10_000);
+
+    let unsigned = api.tx().create_unsigned(&tx).unwrap();
+    assert!(!unsigned.is_signed());
+    assert_matches!(
+        unsigned.submit_unsigned().await,
+        Err(Error::Transaction(TransactionError::UnsignedRejected(_)))
+    );
+
+    let signed = api
+        .tx()
+        .create_signed(&tx, &alice, Default::default())
+        .await
+        .unwrap();
+    assert!(signed.is_signed());
+    assert_matches!(
+        signed.submit_unsigned().await,
+        Err(Error::Transaction(TransactionError::NotUnsigned))
+    );
+}
+
 #[tokio::test]
 async fn submit_large_extrinsic() {
     let ctx = test_context().await;
@@ -439,6 +580,164 @@ async fn rpc_state_call() -> Result<(), subxt::Error> {
     Ok(())
 }
 
+#[tokio::test]
+async fn replace_metadata_swaps_in_valid_metadata() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    // Bytes which aren't metadata are rejected, and the metadata is left alone:
+    assert!(api.replace_metadata(&[1, 2, 3]).is_err());
+    assert!(api.metadata().pallet_by_name("Balances").is_some());
+
+    // Valid metadata is swapped in, and is seen by clones of the client too:
+    let clone = api.clone();
+    let mut metadata: Metadata = (*api.metadata()).clone();
+    metadata.retain(|pallet| pallet == "System", |_| true);
+    api.replace_metadata(&metadata.encode()).unwrap();
+
+    assert!(clone.metadata().pallet_by_name("System").is_some());
+    assert!(clone.metadata().pallet_by_name("Balances").is_none());
+}
+
+#[tokio::test]
+async fn decode_limits_survive_replace_metadata() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let limits = subxt::metadata::DecodeLimits {
+        max_depth: 8,
+        max_len: 1024,
+    };
+    api.set_decode_limits(limits);
+    assert_eq!(api.decode_limits(), limits);
+    assert_eq!(api.metadata().decode_limits(), limits);
+
+    // Metadata decoded by the client is handed the same limits:
+    let metadata: Metadata = (*api.metadata()).clone();
+    api.replace_metadata(&metadata.encode()).unwrap();
+    assert_eq!(api.metadata().decode_limits(), limits);
+
+    // Metadata handed over as is keeps its own limits:
+    api.set_metadata(api.metadata().with_decode_limits(Default::default()));
+    assert_eq!(api.metadata().decode_limits(), Default::default());
+    assert_eq!(api.decode_limits(), limits);
+}
+
+#[tokio::test]
+async fn client_clones_can_be_used_from_spawned_tasks() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let total_issuance = |api: subxt::OnlineClient<subxt::SubstrateConfig>| async move {
+        let addr = node_runtime::storage().balances().total_issuance();
+        api.storage()
+            .at_latest()
+            .await?
+            .fetch_or_default(&addr)
+            .await
+    };
+    let a = tokio::spawn(total_issuance(api.clone()));
+    let b = tokio::spawn(total_issuance(api.clone()));
+    let (a, b) = (a.await.unwrap(), b.await.unwrap());
+    assert!(a.unwrap() > 0);
+    assert!(b.unwrap() > 0);
+
+    // Clones share their metadata with the original client:
+    let clone = api.clone();
+    let mut metadata: Metadata = (*api.metadata()).clone();
+    metadata.retain(|pallet| pallet == "System", |_| true);
+    api.set_metadata(metadata);
+    assert!(clone.metadata().pallet_by_name("Balances").is_none());
+}
+
+#[tokio::test]
+async fn pending_extrinsics_contains_submitted_extrinsic() {
+    use node_runtime::system::calls::types::Remark;
+
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let tx = node_runtime::tx().system().remark(b"in the pool".to_vec());
+    let signed_extrinsic = api
+        .tx()
+        .create_signed(&tx, &alice, Default::default())
+        .await
+        .unwrap();
+    signed_extrinsic.submit().await.unwrap();
+
+    // The extrinsic sits in the pool until the next block is authored:
+    let pending = api.pending_extrinsics().await.unwrap();
+    assert!(pending.iter().any(|ext| ext == signed_extrinsic.encoded()));
+
+    let details = api.pending_extrinsic_details().await.unwrap();
+    let ext = details
+        .iter()
+        .find(|ext| ext.bytes() == signed_extrinsic.encoded())
+        .expect("submitted extrinsic should be pending");
+    assert!(ext.is_signed());
+    let remark = ext.as_extrinsic::<Remark>().unwrap().unwrap();
+    assert_eq!(remark.remark, b"in the pool".to_vec());
+}
+
+#[tokio::test]
+async fn remove_extrinsic_unsafe_drops_extrinsic_from_pool() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let tx = node_runtime::tx().system().remark(b"remove me".to_vec());
+    let signed_extrinsic = api
+        .tx()
+        .create_signed(&tx, &alice, Default::default())
+        .await
+        .unwrap();
+    let hash = signed_extrinsic.submit().await.unwrap();
+
+    let removed = api
+        .remove_extrinsic_unsafe(ExtrinsicOrHash::Hash(hash))
+        .await
+        .unwrap();
+    assert_eq!(removed, vec![hash]);
+
+    let pending = api.pending_extrinsics().await.unwrap();
+    assert!(!pending.iter().any(|ext| ext == signed_extrinsic.encoded()));
+}
+
+#[tokio::test]
+async fn unsafe_method_rejections_are_told_apart_from_other_errors() {
+    use std::sync::Arc;
+
+    let rpc = Arc::new(MockRpc::with_test_runtime());
+    let api = mock_client(rpc.clone()).await;
+    rpc.on(
+        "rpc_methods",
+        serde_json::json!({ "version": 1, "methods": ["author_removeExtrinsic"] }),
+    );
+    let hash = ExtrinsicOrHash::Hash(Default::default());
+
+    // This is how Substrate nodes refuse to run unsafe methods:
+    rpc.on_call_error(
+        "author_removeExtrinsic",
+        -32601,
+        "RPC call is unsafe to be called externally",
+    );
+    let res = api.remove_extrinsic_unsafe(hash.clone()).await;
+    assert_matches!(
+        res,
+        Err(Error::Rpc(RpcError::UnsafeMethodNotAllowed(method))) if method == "author_removeExtrinsic"
+    );
+
+    // Other errors are handed back as they are, even if they mention being unsafe:
+    rpc.on_call_error(
+        "author_removeExtrinsic",
+        1010,
+        "Invalid Transaction: unsafe to remove",
+    );
+    let res = api.remove_extrinsic_unsafe(hash).await;
+    assert_matches!(res, Err(Error::Rpc(RpcError::ClientError(_))));
+}
+
 #[tokio::test]
 async fn chainhead_unstable_follow() {
     let ctx = test_context().await;
@@ -641,6 +940,17 @@ pub struct InclusionFee {
     pub adjusted_weight_fee: u128,
 }
 
+#[tokio::test]
+async fn weight_to_fee_grows_with_weight() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let small = api.tx().weight_to_fee(1_000_000, 1_000).await.unwrap();
+    let large = api.tx().weight_to_fee(1_000_000_000, 1_000).await.unwrap();
+    assert!(small > 0);
+    assert!(large > small);
+}
+
 #[tokio::test]
 async fn partial_fee_estimate_correct() {
     let ctx = test_context().await;
@@ -685,3 +995,877 @@ async fn partial_fee_estimate_correct() {
     // Both methods should yield the same fee
     assert_eq!(partial_fee_1, partial_fee_2);
 }
+
+#[tokio::test]
+async fn tips_are_worked_out_from_the_tip_strategy() {
+    use node_runtime::transaction_payment::events::TransactionFeePaid;
+    use subxt::config::TipStrategy;
+
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let remark = node_runtime::tx().system().remark(vec![1, 2, 3]);
+
+    for strategy in [TipStrategy::Flat(1_000), TipStrategy::PerWeight(2)] {
+        let signed = api
+            .tx()
+            .create_signed_with_tip(&remark, &alice, strategy, Default::default())
+            .await
+            .unwrap();
+        let expected_tip = strategy.tip(signed.weight_estimate().await.unwrap());
+
+        let fee_paid = signed
+            .submit_and_watch()
+            .await
+            .unwrap()
+            .wait_for_finalized_success()
+            .await
+            .unwrap()
+            .find_first::<TransactionFeePaid>()
+            .unwrap()
+            .expect("fee paid event is emitted");
+        assert_eq!(fee_paid.tip, expected_tip, "{strategy:?}");
+    }
+}
+
+#[tokio::test]
+async fn fees_paid_are_totalled_per_account_over_a_block_range() {
+    use futures::TryStreamExt;
+    use node_runtime::transaction_payment::events::TransactionFeePaid;
+
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let alice_id: AccountId32 = AccountKeyring::Alice.to_account_id().into();
+    let bob_id: AccountId32 = AccountKeyring::Bob.to_account_id().into();
+    let remark = node_runtime::tx().system().remark(vec![1, 2, 3]);
+
+    let mut expected = 0;
+    let mut last_block = 0;
+    for _ in 0..2 {
+        let events = api
+            .tx()
+            .sign_and_submit_then_watch_default(&remark, &alice)
+            .await
+            .unwrap()
+            .wait_for_finalized_success()
+            .await
+            .unwrap();
+        let fee_paid = events
+            .find_first::<TransactionFeePaid>()
+            .unwrap()
+            .expect("fee paid event is emitted");
+        expected += fee_paid.actual_fee;
+
+        let block = api.blocks().at(events.block_hash()).await.unwrap();
+        last_block = block.number().into();
+    }
+
+    let fees = api
+        .events()
+        .fees_paid(0..=last_block, &[alice_id.clone(), bob_id.clone()])
+        .await
+        .unwrap();
+    assert_eq!(fees.len(), 2);
+    assert_eq!(fees[&alice_id], expected);
+    assert_eq!(fees[&bob_id], 0);
+
+    // The same totals are handed back block by block:
+    let per_block: Vec<_> = api
+        .events()
+        .fees_paid_per_block(0..=last_block, &[alice_id.clone()])
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(per_block.len() as u64, last_block + 1);
+    assert_eq!(per_block.last().unwrap().0, last_block);
+    let alice_total: u128 = per_block.iter().map(|(_, fees)| fees[&alice_id]).sum();
+    assert_eq!(alice_total, expected);
+}
+
+#[tokio::test]
+async fn ss58_prefix_from_system_properties() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    // The substrate dev node uses the generic substrate prefix.
+    let prefix = api.ss58_prefix().await.unwrap();
+    assert_eq!(prefix, Some(42));
+
+    let alice: AccountId32 = AccountKeyring::Alice.to_account_id().into();
+    assert_eq!(
+        alice.to_ss58check_with_prefix(42).unwrap(),
+        AccountKeyring::Alice.to_account_id().to_string()
+    );
+}
+
+#[tokio::test]
+async fn chain_properties_are_fetched_and_cached() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let properties = api.properties().await.unwrap();
+    assert_eq!(properties.ss58_format, Some(42));
+    assert_eq!(
+        properties,
+        subxt::rpc::types::ChainProperties::from_system_properties(
+            &api.rpc().system_properties().await.unwrap()
+        )
+    );
+
+    // A second call hands back the cached properties.
+    assert_eq!(api.properties().await.unwrap(), properties);
+}
+
+#[tokio::test]
+async fn finalized_head_can_be_used_to_read_finalized_state() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let methods = api.rpc().rpc_methods().await.unwrap();
+    assert!(methods.iter().any(|m| m == "chain_getFinalizedHead"));
+
+    let finalized_hash = api.finalized_head().await.unwrap();
+    let header = api.rpc().header(Some(finalized_hash)).await.unwrap();
+    assert!(header.is_some());
+
+    let addr = node_runtime::storage().system().number();
+    let number = api
+        .storage()
+        .at(finalized_hash)
+        .fetch_or_default(&addr)
+        .await
+        .unwrap();
+    assert_eq!(number, header.unwrap().number);
+}
+
+#[tokio::test]
+async fn account_nonce_is_fetched_via_rpc_and_counts_pending_transactions() {
+    use std::sync::{Arc, Mutex};
+    use subxt::client::MetricsRecorder;
+
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    impl MetricsRecorder for Recorder {
+        fn record_rpc_request(
+            &self,
+            method: &str,
+            _duration: Option<std::time::Duration>,
+            _success: bool,
+        ) {
+            self.0.lock().unwrap().push(method.to_owned());
+        }
+    }
+
+    let ctx = test_context().await;
+    let recorder = Recorder::default();
+    let api = ctx.client().with_metrics_recorder(recorder.clone());
+
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let nonce = api.account_nonce(alice.account_id()).await.unwrap();
+
+    // Signing without an explicit nonce uses the same RPC method, and not a storage read:
+    let tx = node_runtime::tx().system().remark(vec![1, 2, 3]);
+    let signed_extrinsic = api
+        .tx()
+        .create_signed(&tx, &alice, Default::default())
+        .await
+        .unwrap();
+    let methods = recorder.0.lock().unwrap().clone();
+    let nonce_requests = methods
+        .iter()
+        .filter(|m| *m == "system_accountNextIndex")
+        .count();
+    assert_eq!(nonce_requests, 2);
+    assert!(!methods.iter().any(|m| m == "state_getStorage"));
+
+    // Whether or not it's been included in a block yet, the submitted transaction is counted:
+    signed_extrinsic.submit().await.unwrap();
+    let next_nonce = api.account_nonce(alice.account_id()).await.unwrap();
+    assert_eq!(next_nonce, nonce + 1);
+}
+
+#[tokio::test]
+async fn metrics_recorder_sees_rpc_requests_and_submissions() {
+    use std::sync::{Arc, Mutex};
+    use subxt::client::MetricsRecorder;
+
+    #[derive(Default)]
+    struct Recorded {
+        rpc_methods: Vec<String>,
+        submissions: Vec<(Option<String>, Option<String>, bool)>,
+        outcomes: Vec<bool>,
+    }
+
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Recorded>>);
+
+    impl MetricsRecorder for Recorder {
+        fn record_rpc_request(
+            &self,
+            method: &str,
+            _duration: Option<std::time::Duration>,
+            _success: bool,
+        ) {
+            self.0.lock().unwrap().rpc_methods.push(method.to_owned());
+        }
+        fn record_submission(&self, pallet: Option<&str>, call: Option<&str>, accepted: bool) {
+            self.0.lock().unwrap().submissions.push((
+                pallet.map(ToOwned::to_owned),
+                call.map(ToOwned::to_owned),
+                accepted,
+            ));
+        }
+        fn record_extrinsic_outcome(&self, error: Option<&DispatchError>) {
+            self.0.lock().unwrap().outcomes.push(error.is_none());
+        }
+    }
+
+    let ctx = test_context().await;
+    let recorder = Recorder::default();
+    let api = ctx.client().with_metrics_recorder(recorder.clone());
+
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let bob: AccountId32 = AccountKeyring::Bob.to_account_id().into();
+    let tx = node_runtime::tx().balances().transfer(bob.into(), 10_000);
+
+    api.tx()
+        .sign_and_submit_then_watch_default(&tx, &alice)
+        .await
+        .unwrap()
+        .wait_for_finalized_success()
+        .await
+        .unwrap();
+
+    let recorded = recorder.0.lock().unwrap();
+    assert!(recorded
+        .rpc_methods
+        .iter()
+        .any(|m| m == "author_submitAndWatchExtrinsic"));
+    assert_eq!(
+        recorded.submissions,
+        vec![(
+            Some("Balances".to_owned()),
+            Some("transfer".to_owned()),
+            true
+        )]
+    );
+    assert_eq!(recorded.outcomes, vec![true]);
+}
+
+#[tokio::test]
+async fn connection_failures_are_reported_as_transport_errors() {
+    // Nothing should be listening on this port.
+    let res = subxt::OnlineClient::<subxt::SubstrateConfig>::from_url("ws://127.0.0.1:1").await;
+    assert_matches!(
+        res,
+        Err(Error::ClientInit(e)) if matches!(*e, ClientInitError::Transport(_))
+    );
+}
+
+#[tokio::test]
+async fn client_init_requests_are_made_concurrently() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use subxt::rpc::{RawValue, RpcClientT, RpcFuture, RpcSubscription};
+
+    // Each request waits until all three initial requests have been made before responding,
+    // and so constructing a client that made them one after the other would never complete.
+    // The metadata request fails, to check that errors are still attributed correctly.
+    #[derive(Default)]
+    struct WaitForAllRequests {
+        in_flight: AtomicUsize,
+    }
+
+    impl RpcClientT for WaitForAllRequests {
+        fn request_raw<'a>(
+            &'a self,
+            method: &'a str,
+            _params: Option<Box<RawValue>>,
+        ) -> RpcFuture<'a, Box<RawValue>> {
+            self.in_flight.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move {
+                while self.in_flight.load(Ordering::SeqCst) < 3 {
+                    tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+                }
+                let res = match method {
+                    "chain_getBlockHash" => format!("\"0x{}\"", "00".repeat(32)),
+                    "state_getRuntimeVersion" => {
+                        r#"{"specVersion":1,"transactionVersion":1}"#.to_owned()
+                    }
+                    _ => return Err(subxt::error::RpcError::ClientError("nope".into())),
+                };
+                Ok(RawValue::from_string(res).unwrap())
+            })
+        }
+
+        fn subscribe_raw<'a>(
+            &'a self,
+            _sub: &'a str,
+            _params: Option<Box<RawValue>>,
+            _unsub: &'a str,
+        ) -> RpcFuture<'a, RpcSubscription> {
+            Box::pin(async {
+                Err(subxt::error::RpcError::ClientError(
+                    "unexpected subscription".into(),
+                ))
+            })
+        }
+    }
+
+    let res = tokio::time::timeout(
+        std::time::Duration::from_secs(10),
+        subxt::OnlineClient::<subxt::SubstrateConfig>::from_rpc_client(std::sync::Arc::new(
+            WaitForAllRequests::default(),
+        )),
+    )
+    .await
+    .expect("client init requests should be made concurrently");
+    assert_matches!(
+        res,
+        Err(Error::ClientInit(e)) if matches!(*e, ClientInitError::Metadata(_))
+    );
+}
+
+#[tokio::test]
+async fn metadata_that_cannot_be_decoded_is_reported_as_such() {
+    let rpc = MockRpc::with_test_runtime();
+    rpc.on_params(
+        "state_call",
+        [serde_json::Value::from("Metadata_metadata")],
+        format!("0x{}", hex::encode(vec![1u8, 2, 3].encode())),
+    );
+
+    let res =
+        subxt::OnlineClient::<subxt::SubstrateConfig>::from_rpc_client(std::sync::Arc::new(rpc))
+            .await;
+    assert_matches!(
+        res,
+        Err(Error::ClientInit(e)) if matches!(*e, ClientInitError::MetadataDecoding(_))
+    );
+}
+
+#[tokio::test]
+async fn client_init_takes_one_round_trip() {
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+    use subxt::rpc::{RawValue, RpcClientT, RpcFuture, RpcSubscription};
+
+    const DELAY: Duration = Duration::from_millis(300);
+
+    // Takes a fixed time to respond to each request, as a remote node would.
+    struct Delayed(MockRpc);
+
+    impl RpcClientT for Delayed {
+        fn request_raw<'a>(
+            &'a self,
+            method: &'a str,
+            params: Option<Box<RawValue>>,
+        ) -> RpcFuture<'a, Box<RawValue>> {
+            Box::pin(async move {
+                tokio::time::sleep(DELAY).await;
+                self.0.request_raw(method, params).await
+            })
+        }
+
+        fn subscribe_raw<'a>(
+            &'a self,
+            sub: &'a str,
+            params: Option<Box<RawValue>>,
+            unsub: &'a str,
+        ) -> RpcFuture<'a, RpcSubscription> {
+            self.0.subscribe_raw(sub, params, unsub)
+        }
+    }
+
+    // Hand back the metadata from the first metadata request made, so that constructing
+    // a client takes three requests in total.
+    let rpc = MockRpc::with_test_runtime();
+    rpc.on_params(
+        "state_call",
+        [serde_json::Value::from("Metadata_metadata_at_version")],
+        format!(
+            "0x{}",
+            hex::encode(Some(test_runtime::METADATA.to_vec()).encode())
+        ),
+    );
+
+    let started = Instant::now();
+    subxt::OnlineClient::<subxt::SubstrateConfig>::from_rpc_client(Arc::new(Delayed(rpc)))
+        .await
+        .unwrap();
+    let elapsed = started.elapsed();
+
+    // Making the requests one after the other would take at least three times the delay.
+    assert!(
+        elapsed < DELAY * 2,
+        "constructing a client took {elapsed:?}, with {DELAY:?} per request"
+    );
+}
+
+#[tokio::test]
+async fn custom_rpc_clients_drive_storage_and_submission() {
+    use std::sync::{Arc, Mutex};
+    use subxt::rpc::{RawValue, RpcClientT, RpcFuture, RpcSubscription};
+
+    // Hands every call to some inner client, recording the methods used along the way.
+    struct RecordingClient<R> {
+        inner: R,
+        methods: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl<R: RpcClientT> RpcClientT for RecordingClient<R> {
+        fn request_raw<'a>(
+            &'a self,
+            method: &'a str,
+            params: Option<Box<RawValue>>,
+        ) -> RpcFuture<'a, Box<RawValue>> {
+            self.methods.lock().unwrap().push(method.to_owned());
+            self.inner.request_raw(method, params)
+        }
+
+        fn subscribe_raw<'a>(
+            &'a self,
+            sub: &'a str,
+            params: Option<Box<RawValue>>,
+            unsub: &'a str,
+        ) -> RpcFuture<'a, RpcSubscription> {
+            self.methods.lock().unwrap().push(sub.to_owned());
+            self.inner.subscribe_raw(sub, params, unsub)
+        }
+    }
+
+    let ctx = test_context().await;
+    let methods = Arc::new(Mutex::new(Vec::new()));
+    let rpc_client = RecordingClient {
+        inner: subxt::client::default_rpc_client(ctx.ws_url())
+            .await
+            .unwrap(),
+        methods: methods.clone(),
+    };
+    let api = subxt::OnlineClient::<subxt::SubstrateConfig>::from_rpc_client(Arc::new(rpc_client))
+        .await
+        .unwrap();
+
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let alice_id: AccountId32 = AccountKeyring::Alice.to_account_id().into();
+    let account = node_runtime::storage().system().account(&alice_id);
+    api.storage()
+        .at_latest()
+        .await
+        .unwrap()
+        .fetch(&account)
+        .await
+        .unwrap()
+        .expect("Alice has an account");
+
+    let remark = node_runtime::tx().system().remark(vec![1, 2, 3]);
+    api.tx()
+        .sign_and_submit_then_watch_default(&remark, &alice)
+        .await
+        .unwrap()
+        .wait_for_in_block()
+        .await
+        .unwrap();
+
+    let methods = methods.lock().unwrap();
+    for method in [
+        "chain_getBlockHash",
+        "state_getStorage",
+        "author_submitAndWatchExtrinsic",
+    ] {
+        assert!(
+            methods.iter().any(|m| m == method),
+            "{method} in {methods:?}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn mock_rpc_serves_storage_and_submission_without_a_node() {
+    use std::sync::Arc;
+
+    let rpc = Arc::new(MockRpc::with_test_runtime());
+    let api = mock_client(rpc.clone()).await;
+
+    // Storage values are handed back for the keys they were registered against:
+    let now = node_runtime::storage().timestamp().now();
+    let key = api.storage().address_bytes(&now).unwrap();
+    rpc.on_storage(&key, &1234u64.encode());
+    let value = api
+        .storage()
+        .at_latest()
+        .await
+        .unwrap()
+        .fetch(&now)
+        .await
+        .unwrap();
+    assert_eq!(value, Some(1234));
+
+    // Submitting hands back whatever hash the mock says it should:
+    let tx_hash = format!("0x{}", "ab".repeat(32));
+    rpc.on("system_accountNextIndex", 0);
+    rpc.on("author_submitExtrinsic", tx_hash.clone());
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let remark = node_runtime::tx().system().remark(vec![1, 2, 3]);
+    let hash = api
+        .tx()
+        .sign_and_submit_default(&remark, &alice)
+        .await
+        .unwrap();
+    assert_eq!(format!("{hash:?}"), tx_hash);
+
+    let requests = rpc.requests();
+    let (method, params) = requests.last().unwrap();
+    assert_eq!(method, "author_submitExtrinsic");
+    assert!(params[0].as_str().unwrap().starts_with("0x"));
+
+    // Anything that isn't mocked is an error:
+    assert!(api.rpc().system_name().await.is_err());
+}
+
+#[tokio::test]
+async fn extrinsics_signed_against_one_client_can_be_submitted_via_another() {
+    use std::sync::Arc;
+
+    // Nonces and metadata come from the "read" node, but extrinsics go to the "write" node.
+    let read_rpc = Arc::new(MockRpc::with_test_runtime());
+    read_rpc.on("system_accountNextIndex", 3);
+    let read_api = mock_client(read_rpc.clone()).await;
+
+    let tx_hash = format!("0x{}", "cd".repeat(32));
+    let write_rpc = Arc::new(MockRpc::with_test_runtime());
+    write_rpc.on("author_submitExtrinsic", tx_hash.clone());
+    let write_api = mock_client(write_rpc.clone()).await;
+
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let remark = node_runtime::tx().system().remark(vec![1, 2, 3]);
+    let signed = read_api
+        .tx()
+        .create_signed(&remark, &alice, Default::default())
+        .await
+        .unwrap();
+
+    let hash = signed.submit_via(write_api.clone()).await.unwrap();
+    assert_eq!(format!("{hash:?}"), tx_hash);
+
+    let submitted: Vec<_> = write_rpc
+        .requests()
+        .into_iter()
+        .filter(|(method, _)| method == "author_submitExtrinsic")
+        .collect();
+    assert_eq!(submitted.len(), 1);
+    assert!(!read_rpc
+        .requests()
+        .iter()
+        .any(|(method, _)| method == "author_submitExtrinsic"));
+
+    // Extrinsics can't be handed to a client for some other chain:
+    let other_rpc = Arc::new(MockRpc::with_test_runtime());
+    other_rpc.on("chain_getBlockHash", format!("0x{}", "01".repeat(32)));
+    let other_api = mock_client(other_rpc).await;
+    assert_matches!(
+        signed.via(other_api).err(),
+        Some(Error::Transaction(
+            TransactionError::GenesisHashMismatch { .. }
+        ))
+    );
+}
+
+#[tokio::test]
+async fn submit_returns_the_hash_without_opening_a_subscription() {
+    use std::sync::Arc;
+    use subxt::error::PoolRejection;
+
+    let rpc = Arc::new(MockRpc::with_test_runtime());
+    let api = mock_client(rpc.clone()).await;
+
+    let tx_hash = format!("0x{}", "ef".repeat(32));
+    rpc.on("author_submitExtrinsic", tx_hash.clone());
+    let remark = node_runtime::tx().system().remark(vec![1, 2, 3]);
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let signed = api
+        .tx()
+        .create_signed_with_nonce(&remark, &alice, 0, Default::default())
+        .unwrap();
+
+    let hash = signed.submit().await.unwrap();
+    assert_eq!(format!("{hash:?}"), tx_hash);
+    assert!(rpc.subscriptions().is_empty());
+
+    // Errors from the transaction pool say why the extrinsic was rejected:
+    rpc.on_error(
+        "author_submitExtrinsic",
+        "1010: Invalid Transaction: Transaction is outdated",
+    );
+    assert_matches!(
+        signed.submit().await,
+        Err(Error::Transaction(TransactionError::PoolRejected(
+            PoolRejection::Invalid,
+            _
+        )))
+    );
+    rpc.on_error(
+        "author_submitExtrinsic",
+        "1013: Transaction Already Imported",
+    );
+    assert_matches!(
+        signed.submit().await,
+        Err(Error::Transaction(TransactionError::PoolRejected(
+            PoolRejection::AlreadyImported,
+            _
+        )))
+    );
+
+    // Other errors are handed back as they are:
+    rpc.on_error("author_submitExtrinsic", "connection reset");
+    assert_matches!(signed.submit().await, Err(Error::Rpc(_)));
+    assert!(rpc.subscriptions().is_empty());
+}
+
+#[tokio::test]
+async fn boxed_calls_of_different_types_can_be_submitted_together() {
+    use subxt::{tx::SubmitExtrinsic, SubstrateConfig};
+
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let bob = AccountKeyring::Bob.to_account_id();
+
+    let remark = node_runtime::tx().system().remark(vec![1, 2, 3]);
+    let transfer = node_runtime::tx().balances().transfer(bob.into(), 10_000);
+    let calls: Vec<Box<dyn SubmitExtrinsic<SubstrateConfig>>> = vec![
+        Box::new(api.tx().build(remark)),
+        Box::new(api.tx().build(transfer)),
+    ];
+
+    for call in &calls {
+        let events = call.submit(&alice).await.unwrap();
+        assert!(events
+            .find_first::<node_runtime::system::events::ExtrinsicSuccess>()
+            .unwrap()
+            .is_some());
+    }
+}
+
+#[tokio::test]
+async fn latest_events_and_events_at_decode_events_with_their_phase() {
+    use subxt::events::Phase;
+
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let tx = node_runtime::tx().system().remark(vec![1, 2, 3]);
+    let events = api
+        .tx()
+        .sign_and_submit_then_watch_default(&tx, &alice)
+        .await
+        .unwrap()
+        .wait_for_finalized_success()
+        .await
+        .unwrap();
+
+    let events_at = api.events_at(events.block_hash()).await.unwrap();
+    let success = events_at
+        .iter()
+        .find(|ev| {
+            ev.phase() == Phase::ApplyExtrinsic(events.extrinsic_index())
+                && ev.variant_name() == "ExtrinsicSuccess"
+        })
+        .expect("the remark should have succeeded");
+    assert_eq!(success.pallet_name(), "System");
+
+    // Every block contains at least the timestamp being set:
+    let latest_events = api.latest_events().await.unwrap();
+    assert!(latest_events
+        .iter()
+        .any(|ev| matches!(ev.phase(), Phase::ApplyExtrinsic(_))));
+}
+
+#[tokio::test]
+async fn grandpa_round_state_and_justifications() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let round_state = api.rpc().grandpa_round_state().await.unwrap();
+    assert!(round_state.best.total_weight > 0);
+
+    // Justifications are only kept for some blocks, but any that are found must be for the
+    // block that was asked for:
+    let hash = api.finalized_head().await.unwrap();
+    if let Some(justification) = api.grandpa_justification(hash).await.unwrap() {
+        assert_eq!(justification.commit.target_hash, hash);
+    }
+
+    // Blocks which don't exist have no justification:
+    let missing = api.grandpa_justification(Default::default()).await.unwrap();
+    assert!(missing.is_none());
+}
+
+#[tokio::test]
+async fn trace_block_records_storage_accesses() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+    wait_for_blocks(&api).await;
+
+    // The test node allows unsafe methods over local connections, and keeps the state of
+    // recent blocks around, so the latest finalized block can be traced.
+    let hash = api.finalized_head().await.unwrap();
+    let response = api
+        .trace_block_unsafe(hash, Some("state"), None)
+        .await
+        .unwrap();
+
+    let TraceBlockResponse::BlockTrace(trace) = response else {
+        panic!("expected a block trace, got {response:?}");
+    };
+    assert_eq!(trace.tracing_targets, "state");
+    for event in &trace.events {
+        assert!(event.target.starts_with("state"), "{event:?}");
+    }
+}
+
+#[tokio::test]
+async fn local_peer_id_and_network_state() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let peer_id = api.rpc().system_local_peer_id().await.unwrap();
+    assert!(!peer_id.is_empty());
+
+    // Each listen address ends with the node's peer ID.
+    let addresses = api.rpc().system_local_listen_addresses().await.unwrap();
+    assert!(!addresses.is_empty());
+    for address in &addresses {
+        assert!(address.ends_with(&peer_id), "{address}");
+    }
+
+    // The test node runs alone, but allows unsafe methods over local connections.
+    let peers = api.peers_unsafe().await.unwrap();
+    assert!(peers.is_empty(), "{peers:?}");
+
+    let state = api.network_state_unsafe().await.unwrap();
+    assert_eq!(state.peer_id, peer_id);
+}
+
+#[tokio::test]
+async fn client_builder_with_keepalive_stays_connected_while_idle() {
+    let ctx = test_context().await;
+
+    let api = subxt::client::ClientBuilder::<subxt::SubstrateConfig>::new()
+        .keepalive(std::time::Duration::from_millis(100))
+        .build(ctx.ws_url())
+        .await
+        .unwrap();
+    assert_eq!(api.genesis_hash(), ctx.client().genesis_hash());
+
+    // Sit idle for a few ping intervals; the pings shouldn't upset the connection:
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+    assert!(api.is_connected().await);
+}
+
+#[tokio::test]
+async fn header_digest_says_who_authored_the_block() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    // The genesis block has no author, so wait for some blocks to be produced first:
+    wait_for_blocks(&api).await;
+    let hash = api.rpc().block_hash(None).await.unwrap().unwrap();
+    let header = api.header(hash).await.unwrap().expect("block exists");
+    // The test node produces blocks with BABE:
+    let pre_digest = header
+        .digest
+        .babe_pre_digest()
+        .unwrap()
+        .expect("block has a BABE pre-digest");
+    assert_eq!(pre_digest.authority_index, 0);
+
+    assert!(api.header(Default::default()).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn ping_returns_the_health_of_a_syncing_node() {
+    use std::sync::Arc;
+
+    let rpc = Arc::new(MockRpc::with_test_runtime());
+    rpc.on(
+        "system_health",
+        serde_json::json!({ "peers": 3, "isSyncing": true, "shouldHavePeers": true }),
+    );
+    let api = mock_client(rpc).await;
+
+    // The peer count is available even though the node is syncing:
+    let health = api.ping().await.unwrap();
+    assert_eq!(health.peers, 3);
+    assert!(health.is_syncing);
+    assert!(health.should_have_peers);
+
+    assert_matches!(
+        api.ensure_synced().await,
+        Err(Error::Rpc(subxt::error::RpcError::NodeSyncing))
+    );
+}
+
+#[tokio::test]
+async fn ensure_synced_returns_the_health_of_a_synced_node() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let health = api.ensure_synced().await.unwrap();
+    assert!(!health.is_syncing);
+    assert_eq!(api.ping().await.unwrap(), health);
+}
+
+#[tokio::test]
+async fn metrics_recorder_sees_submissions_of_already_signed_extrinsics() {
+    use std::sync::{Arc, Mutex};
+    use subxt::client::MetricsRecorder;
+
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<(Option<String>, Option<String>, bool)>>>);
+
+    impl MetricsRecorder for Recorder {
+        fn record_submission(&self, pallet: Option<&str>, call: Option<&str>, accepted: bool) {
+            self.0.lock().unwrap().push((
+                pallet.map(ToOwned::to_owned),
+                call.map(ToOwned::to_owned),
+                accepted,
+            ));
+        }
+    }
+
+    let rpc = Arc::new(MockRpc::with_test_runtime());
+    rpc.on("system_accountNextIndex", 0);
+    rpc.on("author_submitExtrinsic", format!("0x{}", "cd".repeat(32)));
+    let recorder = Recorder::default();
+    let api = mock_client(rpc)
+        .await
+        .with_metrics_recorder(recorder.clone());
+
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let remark = node_runtime::tx().system().remark(vec![1, 2, 3]);
+    let signed = api
+        .tx()
+        .create_signed(&remark, &alice, Default::default())
+        .await
+        .unwrap();
+
+    // Submitting directly is counted, as is a submission which the node refuses (the mock
+    // can't open subscriptions):
+    signed.submit().await.unwrap();
+    assert!(signed.submit_and_watch().await.is_err());
+
+    let remark = (Some("System".to_owned()), Some("remark".to_owned()));
+    assert_eq!(
+        *recorder.0.lock().unwrap(),
+        vec![
+            (remark.0.clone(), remark.1.clone(), true),
+            (remark.0, remark.1, false)
+        ]
+    );
+}
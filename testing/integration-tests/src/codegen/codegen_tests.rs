@@ -144,3 +144,194 @@ fn generic_types_overwrite_each_other() {
     // We do _not_ expect this to exist, since a generic is present on the type:
     assert!(!interface.contains("DuplicateType2"));
 }
+
+/// Generate the API for the test runtime, customising the generator first.
+fn generate_test_runtime_interface(
+    configure: impl FnOnce(RuntimeGenerator) -> RuntimeGenerator,
+) -> String {
+    let crate_path = CratePath::default();
+    let derives = DerivesRegistry::with_default_derives(&crate_path);
+    generate_test_runtime_interface_with_derives(derives, configure)
+}
+
+/// Generate the API for the test runtime with the given derives, customising the generator first.
+fn generate_test_runtime_interface_with_derives(
+    derives: DerivesRegistry,
+    configure: impl FnOnce(RuntimeGenerator) -> RuntimeGenerator,
+) -> String {
+    use codec::Decode;
+
+    let metadata = subxt_metadata::Metadata::decode(&mut &*test_runtime::METADATA)
+        .expect("Cannot decode scale metadata");
+    let item_mod = syn::parse_quote!(
+        pub mod api {}
+    );
+    let crate_path = CratePath::default();
+    let type_substitutes = TypeSubstitutes::with_default_substitutes(&crate_path);
+    configure(RuntimeGenerator::new(metadata))
+        .generate_runtime(item_mod, derives, type_substitutes, crate_path, false)
+        .expect("API generation must be valid")
+        .to_string()
+}
+
+#[test]
+fn allow_lints_replaces_default_allows() {
+    let interface = generate_test_runtime_interface(|generator| {
+        generator.allow_lints([syn::parse_quote!(clippy::pedantic)])
+    });
+
+    assert!(interface.contains("allow (clippy :: pedantic)"));
+    assert!(!interface.contains("clippy :: all"));
+}
+
+#[test]
+fn types_mod_name_is_used_throughout() {
+    let interface =
+        generate_test_runtime_interface(|generator| generator.types_mod_name("scale_types"));
+
+    assert!(interface.contains("pub mod scale_types"));
+    assert!(interface.contains("use super :: scale_types ;"));
+    assert!(
+        interface.contains("pub type DispatchError = scale_types :: sp_runtime :: DispatchError ;")
+    );
+}
+
+#[test]
+fn custom_crate_path_is_used_throughout() {
+    use codec::Decode;
+
+    let metadata = subxt_metadata::Metadata::decode(&mut &*test_runtime::METADATA)
+        .expect("Cannot decode scale metadata");
+    let item_mod = syn::parse_quote!(
+        pub mod api {}
+    );
+    let crate_path = CratePath::new(syn::parse_quote!(::my_subxt));
+    let derives = DerivesRegistry::with_default_derives(&crate_path);
+    let type_substitutes = TypeSubstitutes::with_default_substitutes(&crate_path);
+    let interface = RuntimeGenerator::new(metadata)
+        .emit_tests(true)
+        .emit_submit_fns(true)
+        .generate_runtime(item_mod, derives, type_substitutes, crate_path, false)
+        .expect("API generation must be valid")
+        .to_string();
+
+    assert!(interface.contains(":: my_subxt ::"));
+    assert!(!interface.contains(":: subxt ::"));
+}
+
+#[test]
+fn pallet_aliases_reexport_the_pallet_under_another_name() {
+    let interface = generate_test_runtime_interface(|generator| {
+        generator.pallet_aliases([("Currencies", "Balances")])
+    });
+
+    assert!(interface.contains("pub mod currencies { pub use super :: balances :: * ; }"));
+    assert!(interface.contains(
+        "pub fn currencies (& self) -> balances :: calls :: TransactionApi { balances :: calls :: TransactionApi }"
+    ));
+    assert!(interface.contains(
+        "pub fn currencies (& self) -> balances :: storage :: StorageApi { balances :: storage :: StorageApi }"
+    ));
+    assert!(interface.contains(
+        "pub fn currencies (& self) -> balances :: constants :: ConstantsApi { balances :: constants :: ConstantsApi }"
+    ));
+    // The calls themselves still refer to the pallet by its name in the metadata:
+    assert!(!interface.contains("\"Currencies\""));
+}
+
+#[test]
+fn invalid_pallet_aliases_are_rejected() {
+    use codec::Decode;
+
+    for (alias, pallet) in [("Currencies", "NotAPallet"), ("System", "Balances")] {
+        let metadata = subxt_metadata::Metadata::decode(&mut &*test_runtime::METADATA)
+            .expect("Cannot decode scale metadata");
+        let item_mod = syn::parse_quote!(
+            pub mod api {}
+        );
+        let crate_path = CratePath::default();
+        let derives = DerivesRegistry::with_default_derives(&crate_path);
+        let type_substitutes = TypeSubstitutes::with_default_substitutes(&crate_path);
+        let err = RuntimeGenerator::new(metadata)
+            .pallet_aliases([(alias, pallet)])
+            .generate_runtime(item_mod, derives, type_substitutes, crate_path, false)
+            .unwrap_err();
+        assert!(
+            matches!(err, subxt_codegen::CodegenError::InvalidPalletAlias(ref a, ref p, _) if a == alias && p == pallet),
+            "{alias}={pallet}: {err}"
+        );
+    }
+}
+
+#[test]
+fn pallet_and_call_names_are_looked_up_by_index() {
+    use crate::node_runtime;
+
+    assert_eq!(node_runtime::pallet_name(0), Some("System"));
+    assert_eq!(node_runtime::call_name(0, 0), Some("remark"));
+    assert_eq!(node_runtime::call_name(0, 2), Some("set_code"));
+    assert_eq!(node_runtime::call_name(0, 7), Some("remark_with_event"));
+
+    // Unknown indices have no name:
+    assert_eq!(node_runtime::pallet_name(255), None);
+    assert_eq!(node_runtime::call_name(0, 255), None);
+    assert_eq!(node_runtime::call_name(255, 0), None);
+
+    // Every pallet has an index:
+    let mut names: Vec<_> = (0..=u8::MAX)
+        .filter_map(node_runtime::pallet_name)
+        .collect();
+    let mut expected = node_runtime::PALLETS.to_vec();
+    names.sort();
+    expected.sort();
+    assert_eq!(names, expected);
+}
+
+#[test]
+fn calls_convert_into_the_outer_call_enum() {
+    use crate::node_runtime::{self, runtime_types};
+
+    let payload = node_runtime::tx().system().remark(vec![1, 2, 3]);
+    let call: runtime_types::kitchensink_runtime::RuntimeCall = payload.into();
+    assert!(matches!(
+        call,
+        runtime_types::kitchensink_runtime::RuntimeCall::System(
+            runtime_types::frame_system::pallet::Call::remark { ref remark }
+        ) if remark == &[1, 2, 3]
+    ));
+}
+
+#[test]
+fn call_default_impls_are_skipped_when_default_is_derived() {
+    let default_impl = "impl :: core :: default :: Default for Remark {";
+    let crate_path = CratePath::default();
+
+    let interface = generate_test_runtime_interface(|generator| generator);
+    assert!(interface.contains(default_impl));
+
+    let mut derives = DerivesRegistry::with_default_derives(&crate_path);
+    derives.extend_for_all([syn::parse_quote!(Default)], []);
+    let interface = generate_test_runtime_interface_with_derives(derives, |generator| generator);
+    assert!(!interface.contains(default_impl));
+
+    let mut derives = DerivesRegistry::with_default_derives(&crate_path);
+    derives.extend_for_type(
+        syn::parse_quote!(frame_system::pallet::Call),
+        [syn::parse_quote!(Default)],
+        [],
+    );
+    let interface = generate_test_runtime_interface_with_derives(derives, |generator| generator);
+    assert!(!interface.contains(default_impl));
+}
+
+#[test]
+fn storage_defaults_are_decoded_when_generating_code() {
+    let interface = generate_test_runtime_interface(|generator| generator);
+
+    // Primitive defaults are given as typed consts.
+    assert!(
+        interface.contains("pub const TOTAL_ISSUANCE_DEFAULT : :: core :: primitive :: u128 = 0 ;")
+    );
+    // Other defaults are decoded by a function returning the typed value.
+    assert!(interface.contains("pub fn account_default () -> runtime_types :: "));
+}
@@ -6,7 +6,7 @@ use crate::{
     node_runtime::{self, balances, runtime_types, system},
     pair_signer, test_context,
 };
-use codec::Decode;
+use codec::{Decode, Encode};
 use sp_core::Pair;
 use sp_keyring::AccountKeyring;
 use subxt::{
@@ -240,6 +240,209 @@ async fn multiple_transfers_work_nonce_incremented() -> Result<(), subxt::Error>
     Ok(())
 }
 
+#[tokio::test]
+async fn submit_all_transfers_from_different_signers() -> Result<(), subxt::Error> {
+    let signers = [
+        pair_signer(AccountKeyring::Alice.pair()),
+        pair_signer(AccountKeyring::Bob.pair()),
+        pair_signer(AccountKeyring::Charlie.pair()),
+    ];
+    let dave = pair_signer(AccountKeyring::Dave.pair());
+    let dave_address: MultiAddress<AccountId32, u32> = dave.account_id().clone().into();
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let dave_account_addr = node_runtime::storage().system().account(dave.account_id());
+
+    let dave_pre = api
+        .storage()
+        .at_latest()
+        .await?
+        .fetch_or_default(&dave_account_addr)
+        .await?;
+
+    let tx = node_runtime::tx()
+        .balances()
+        .transfer(dave_address.clone(), 10_000);
+    let mut extrinsics = Vec::new();
+    for signer in &signers {
+        extrinsics.push(
+            api.tx()
+                .create_signed(&tx, signer, Default::default())
+                .await?,
+        );
+    }
+
+    let results = api.tx().submit_all(extrinsics, 2).await;
+    assert_eq!(results.len(), 3);
+    for result in results {
+        result?;
+    }
+
+    let dave_post = api
+        .storage()
+        .at_latest()
+        .await?
+        .fetch_or_default(&dave_account_addr)
+        .await?;
+
+    assert_eq!(dave_pre.data.free + 30_000, dave_post.data.free);
+    Ok(())
+}
+
+#[tokio::test]
+async fn submit_and_watch_batch_of_transfers_from_one_signer() -> Result<(), subxt::Error> {
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let eve = pair_signer(AccountKeyring::Eve.pair());
+    let eve_address: MultiAddress<AccountId32, u32> = eve.account_id().clone().into();
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let eve_account_addr = node_runtime::storage().system().account(eve.account_id());
+
+    let eve_pre = api
+        .storage()
+        .at_latest()
+        .await?
+        .fetch_or_default(&eve_account_addr)
+        .await?;
+
+    let tx = node_runtime::tx()
+        .balances()
+        .transfer(eve_address.clone(), 10_000);
+    let nonce = api
+        .rpc()
+        .system_account_next_index(alice.account_id())
+        .await?;
+    let mut extrinsics = Vec::new();
+    for i in 0..3 {
+        extrinsics.push(api.tx().create_signed_with_nonce(
+            &tx,
+            &alice,
+            nonce + i,
+            Default::default(),
+        )?);
+    }
+
+    let results = api.tx().submit_and_watch_batch(extrinsics, 20).await?;
+    assert_eq!(results.len(), 3);
+    for result in results {
+        assert!(result?.has::<balances::events::Transfer>()?);
+    }
+
+    let eve_post = api
+        .storage()
+        .at_latest()
+        .await?
+        .fetch_or_default(&eve_account_addr)
+        .await?;
+
+    assert_eq!(eve_pre.data.free + 30_000, eve_post.data.free);
+    Ok(())
+}
+
+#[tokio::test]
+async fn force_batch_reports_which_calls_failed() -> Result<(), subxt::Error> {
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let ferdie = pair_signer(AccountKeyring::Ferdie.pair());
+    let ferdie_address: MultiAddress<AccountId32, u32> = ferdie.account_id().clone().into();
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let ferdie_account_addr = node_runtime::storage()
+        .system()
+        .account(ferdie.account_id());
+    let ferdie_pre = api
+        .storage()
+        .at_latest()
+        .await?
+        .fetch_or_default(&ferdie_account_addr)
+        .await?;
+
+    // The middle transfer is for more than Alice has, so it will fail.
+    let batch = subxt::tx::force_batch([
+        node_runtime::tx()
+            .balances()
+            .transfer(ferdie_address.clone(), 10_000),
+        node_runtime::tx()
+            .balances()
+            .transfer(ferdie_address.clone(), u128::MAX),
+        node_runtime::tx()
+            .balances()
+            .transfer(ferdie_address, 10_000),
+    ]);
+
+    let results = api
+        .tx()
+        .sign_submit_and_watch_force_batch(&batch, &alice)
+        .await?;
+
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert!(results[2].is_ok());
+
+    // The failed call didn't roll back the others.
+    let ferdie_post = api
+        .storage()
+        .at_latest()
+        .await?
+        .fetch_or_default(&ferdie_account_addr)
+        .await?;
+    assert_eq!(ferdie_pre.data.free + 20_000, ferdie_post.data.free);
+    Ok(())
+}
+
+#[tokio::test]
+async fn batch_of_typed_transfers() -> Result<(), subxt::Error> {
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let ferdie = pair_signer(AccountKeyring::Ferdie.pair());
+    let ferdie_address: MultiAddress<AccountId32, u32> = ferdie.account_id().clone().into();
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let ferdie_account_addr = node_runtime::storage()
+        .system()
+        .account(ferdie.account_id());
+    let ferdie_pre = api
+        .storage()
+        .at_latest()
+        .await?
+        .fetch_or_default(&ferdie_account_addr)
+        .await?;
+
+    // Each call payload converts into the outer call enum that `batch` takes:
+    let batch = node_runtime::tx().utility().batch(vec![
+        node_runtime::tx()
+            .balances()
+            .transfer(ferdie_address.clone(), 1_000)
+            .into(),
+        node_runtime::tx()
+            .balances()
+            .transfer(ferdie_address.clone(), 2_000)
+            .into(),
+        node_runtime::tx()
+            .balances()
+            .transfer(ferdie_address, 3_000)
+            .into(),
+    ]);
+
+    api.tx()
+        .sign_and_submit_then_watch_default(&batch, &alice)
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    let ferdie_post = api
+        .storage()
+        .at_latest()
+        .await?
+        .fetch_or_default(&ferdie_account_addr)
+        .await?;
+    assert_eq!(ferdie_pre.data.free + 6_000, ferdie_post.data.free);
+    Ok(())
+}
+
 #[tokio::test]
 async fn storage_total_issuance() {
     let ctx = test_context().await;
@@ -376,6 +579,116 @@ async fn transfer_implicit_subscription() {
     );
 }
 
+#[tokio::test]
+async fn transfer_amount_is_compact_encoded() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let bob: AccountId32 = AccountKeyring::Bob.to_account_id().into();
+    let bob: MultiAddress<AccountId32, u32> = bob.into();
+    let amount = 10_000u128;
+    let tx = node_runtime::tx().balances().transfer(bob.clone(), amount);
+    let call_data = api.tx().call_data(&tx).unwrap();
+
+    let metadata = api.metadata();
+    let pallet = metadata.pallet_by_name("Balances").unwrap();
+    let call = pallet.call_variant_by_name("transfer").unwrap();
+
+    let mut expected = vec![pallet.index(), call.index];
+    bob.encode_to(&mut expected);
+    codec::Compact(amount).encode_to(&mut expected);
+
+    assert_eq!(call_data, expected);
+}
+
+#[test]
+fn call_struct_converts_into_pallet_call_enum() {
+    let bob: AccountId32 = AccountKeyring::Bob.to_account_id().into();
+    let transfer = balances::calls::types::Transfer {
+        dest: bob.clone().into(),
+        value: 10_000,
+    };
+    let transfer_bytes = transfer.encode();
+
+    let call: balances::Call = transfer.into();
+    let call_bytes = call.encode();
+    assert_eq!(&call_bytes[1..], &transfer_bytes[..]);
+
+    let decoded = balances::Call::decode(&mut &*call_bytes).unwrap();
+    assert!(matches!(
+        decoded,
+        balances::Call::transfer { dest: MultiAddress::Id(id), value: 10_000 } if id == bob
+    ));
+}
+
+#[tokio::test]
+async fn decode_call_bytes_into_generated_call_enums() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let bob: AccountId32 = AccountKeyring::Bob.to_account_id().into();
+    let tx = node_runtime::tx()
+        .balances()
+        .transfer(bob.clone().into(), 10_000);
+    let call_bytes = api.tx().call_data(&tx).unwrap();
+
+    let call = balances::decode_call(&call_bytes).unwrap();
+    assert!(matches!(
+        call,
+        balances::Call::transfer { dest: MultiAddress::Id(ref id), value: 10_000 } if *id == bob
+    ));
+
+    let runtime_call = node_runtime::decode_runtime_call(&call_bytes).unwrap();
+    assert!(matches!(runtime_call, node_runtime::Call::Balances(c) if c.encode() == call.encode()));
+
+    // The pallet index must match, and trailing bytes are not allowed:
+    assert!(system::decode_call(&call_bytes).is_err());
+    let mut too_long = call_bytes.clone();
+    too_long.push(0);
+    assert!(balances::decode_call(&too_long).is_err());
+}
+
+#[tokio::test]
+async fn encode_call_returns_the_call_within_a_signed_extrinsic() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let bob: AccountId32 = AccountKeyring::Bob.to_account_id().into();
+    let tx = node_runtime::tx()
+        .balances()
+        .transfer(bob.clone().into(), 10_000);
+    let signed = api
+        .tx()
+        .create_signed(&tx, &alice, Default::default())
+        .await
+        .unwrap();
+
+    // Balances is pallet 6 and transfer is call 0, followed by `MultiAddress::Id(bob)` and
+    // the compact encoded value:
+    let call = signed.encode_call().unwrap();
+    assert_eq!(
+        hex::encode(&call),
+        "060000\
+         8eaf04151687736326c9fea17e25fc5287613693c912909cb226aa4794f26a48\
+         419c"
+    );
+    assert_eq!(call, api.tx().call_data(&tx).unwrap());
+}
+
+#[test]
+fn transfer_destinations_can_be_built_from_raw_bytes() {
+    let raw = [7u8; 32];
+    let tx = node_runtime::tx()
+        .balances()
+        .transfer(AccountId32::from(raw).into(), 1);
+
+    let MultiAddress::Id(dest) = &tx.call_data().dest else {
+        panic!("destination should be an account ID");
+    };
+    assert_eq!(<[u8; 32]>::from(dest.clone()), raw);
+}
+
 #[tokio::test]
 async fn constant_existential_deposit() {
     let ctx = test_context().await;
@@ -396,3 +709,29 @@ async fn constant_existential_deposit() {
     // Make sure thetwo are identical:
     assert_eq!(existential_deposit, api.constants().at(&addr).unwrap());
 }
+
+#[tokio::test]
+async fn constant_existential_deposit_at_block() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+    let addr = node_runtime::constants().balances().existential_deposit();
+
+    // There have been no runtime upgrades, so the constant is the same at genesis:
+    let genesis_hash = api.genesis_hash();
+    let deposit_at_genesis = api
+        .constants()
+        .at_block(genesis_hash)
+        .await
+        .unwrap()
+        .at(&addr)
+        .unwrap();
+    assert_eq!(deposit_at_genesis, api.constants().at(&addr).unwrap());
+
+    // A block that doesn't exist has no metadata to fetch:
+    let err = api
+        .constants()
+        .at_block(Default::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::HistoricalMetadata(..)), "{err:?}");
+}
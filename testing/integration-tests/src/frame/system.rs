@@ -29,6 +29,49 @@ async fn storage_account() -> Result<(), subxt::Error> {
     Ok(())
 }
 
+#[tokio::test]
+async fn account_info_matches_the_generated_storage_entry() -> Result<(), subxt::Error> {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+
+    let account_info_addr = node_runtime::storage().system().account(alice.account_id());
+    let expected = api
+        .storage()
+        .at_latest()
+        .await?
+        .fetch_or_default(&account_info_addr)
+        .await?;
+
+    let account_info = api.account_info(alice.account_id()).await?;
+    assert_eq!(account_info.nonce, u64::from(expected.nonce));
+    assert_eq!(account_info.consumers, expected.consumers);
+    assert_eq!(account_info.providers, expected.providers);
+    assert_eq!(account_info.sufficients, expected.sufficients);
+    assert_eq!(account_info.data.free, expected.data.free);
+    assert_eq!(account_info.data.reserved, expected.data.reserved);
+
+    assert_eq!(
+        api.free_balance(alice.account_id()).await?,
+        expected.data.free
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn account_info_of_an_unused_account_is_the_default() -> Result<(), subxt::Error> {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let unused = subxt::utils::AccountId32([7; 32]);
+    let account_info = api.account_info(&unused).await?;
+
+    assert_eq!(account_info, subxt::client::AccountInfo::default());
+    assert_eq!(api.free_balance(&unused).await?, 0);
+    Ok(())
+}
+
 #[tokio::test]
 async fn tx_remark_with_event() -> Result<(), subxt::Error> {
     let ctx = test_context().await;
@@ -51,3 +94,60 @@ async fn tx_remark_with_event() -> Result<(), subxt::Error> {
     assert!(found_event);
     Ok(())
 }
+
+#[tokio::test]
+async fn block_events_decode_into_the_outer_event_enum() -> Result<(), subxt::Error> {
+    use subxt::events::Phase;
+
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let tx = node_runtime::tx()
+        .system()
+        .remark_with_event(b"remarkable".to_vec());
+    let events = api
+        .tx()
+        .sign_and_submit_then_watch_default(&tx, &alice)
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    let storage = api.storage().at(events.block_hash());
+    let key = api
+        .storage()
+        .address_bytes(&node_runtime::storage().system().events())?;
+    let bytes = storage.fetch_raw(&key).await?.expect("events are stored");
+    let records = node_runtime::decode_runtime_events(&bytes)?;
+
+    let block_events = api.events().at(events.block_hash()).await?;
+    assert_eq!(records.len(), block_events.len() as usize);
+
+    let remarked = records
+        .iter()
+        .find_map(|record| match &record.event {
+            node_runtime::Event::System(system::Event::Remarked { sender, .. }) => {
+                Some((record.phase, sender))
+            }
+            _ => None,
+        })
+        .expect("remark event is found");
+    assert_eq!(
+        remarked,
+        (
+            Phase::ApplyExtrinsic(events.extrinsic_index()),
+            alice.account_id()
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn call_structs_with_default_fields_implement_default() {
+    let call = system::calls::types::KillPrefix {
+        subkeys: 3,
+        ..Default::default()
+    };
+    assert!(call.prefix.is_empty());
+    assert_eq!(call.subkeys, 3);
+}
@@ -3,3 +3,44 @@
 // see LICENSE for license details.
 
 mod validation;
+
+use codec::Decode;
+use subxt::{rpc::types::RuntimeVersion, Metadata, OfflineClient, SubstrateConfig};
+
+#[test]
+fn signed_extensions_are_listed_in_order() {
+    let metadata = Metadata::decode(&mut &*test_runtime::METADATA).unwrap();
+    let runtime_version = RuntimeVersion {
+        spec_version: 1,
+        transaction_version: 1,
+        other: Default::default(),
+    };
+    let api = OfflineClient::<SubstrateConfig>::new(Default::default(), runtime_version, metadata);
+
+    let identifiers: Vec<_> = api
+        .signed_extensions()
+        .iter()
+        .map(|ext| ext.identifier().to_owned())
+        .collect();
+    assert_eq!(
+        identifiers,
+        [
+            "CheckNonZeroSender",
+            "CheckSpecVersion",
+            "CheckTxVersion",
+            "CheckGenesis",
+            "CheckMortality",
+            "CheckNonce",
+            "CheckWeight",
+            "ChargeAssetTxPayment",
+        ]
+    );
+
+    // Every extension's types can be found in the metadata:
+    let metadata = api.metadata();
+    let types = metadata.types();
+    for ext in api.signed_extensions() {
+        assert!(types.resolve(ext.extra_ty()).is_some(), "{ext:?}");
+        assert!(types.resolve(ext.additional_ty()).is_some(), "{ext:?}");
+    }
+}
@@ -138,6 +138,45 @@ async fn constant_values_are_not_validated() {
     assert!(api.constants().at(&deposit_addr).is_ok());
 }
 
+#[tokio::test]
+async fn constants_validate_reports_mismatched_constants() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    assert!(node_runtime::constants().validate(&api).is_empty());
+
+    // Change the type of ExistentialDeposit to that of MaxLocks.
+    let metadata = modified_metadata(api.metadata(), |md| {
+        let balances = md
+            .pallets
+            .iter_mut()
+            .find(|pallet| pallet.name == "Balances")
+            .expect("Metadata must contain Balances pallet");
+        let max_locks_ty = balances
+            .constants
+            .iter()
+            .find(|constant| constant.name == "MaxLocks")
+            .expect("MaxLocks constant must be present")
+            .ty;
+        balances
+            .constants
+            .iter_mut()
+            .find(|constant| constant.name == "ExistentialDeposit")
+            .expect("ExistentialDeposit constant must be present")
+            .ty = max_locks_ty;
+    });
+
+    let api = metadata_to_api(metadata, &ctx).await;
+    assert_eq!(
+        node_runtime::constants().validate(&api),
+        vec![("Balances", "ExistentialDeposit")]
+    );
+    assert_eq!(
+        node_runtime::constants().balances().validate(&api),
+        vec![("Balances", "ExistentialDeposit")]
+    );
+}
+
 #[tokio::test]
 async fn calls_check() {
     let ctx = test_context().await;
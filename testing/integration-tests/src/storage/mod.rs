@@ -4,7 +4,7 @@
 
 use crate::{node_runtime, pair_signer, test_context, utils::wait_for_blocks};
 use sp_keyring::AccountKeyring;
-use subxt::utils::AccountId32;
+use subxt::{storage::ChildInfo, utils::AccountId32};
 
 #[tokio::test]
 async fn storage_plain_lookup() -> Result<(), subxt::Error> {
@@ -27,6 +27,99 @@ async fn storage_plain_lookup() -> Result<(), subxt::Error> {
     Ok(())
 }
 
+#[tokio::test]
+async fn storage_fetch_as_decodes_into_the_given_type() -> Result<(), subxt::Error> {
+    let ctx = test_context().await;
+    let api = ctx.client();
+    wait_for_blocks(&api).await;
+
+    let storage = api.storage().at_latest().await?;
+    let addr = node_runtime::storage().timestamp().now();
+    let now = storage.fetch(&addr).await?.expect("timestamp is set");
+
+    // Timestamps are u64s, but we can decode the bytes as anything of the same shape.
+    let now_as_bytes = storage
+        .fetch_as::<[u8; 8], _>(&addr)
+        .await?
+        .expect("timestamp is set");
+    assert_eq!(u64::from_le_bytes(now_as_bytes), now);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn storage_fetch_size() -> Result<(), subxt::Error> {
+    let ctx = test_context().await;
+    let api = ctx.client();
+    wait_for_blocks(&api).await;
+
+    let storage = api.storage().at_latest().await?;
+
+    // A timestamp is an encoded u64.
+    let addr = node_runtime::storage().timestamp().now();
+    assert_eq!(storage.fetch_size(&addr).await?, Some(8));
+
+    // Nothing is stored for an account that has never been used.
+    let nobody = AccountId32([0xff; 32]);
+    let addr = node_runtime::storage().system().account(&nobody);
+    assert_eq!(storage.fetch_size(&addr).await?, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn storage_runtime_code() -> Result<(), subxt::Error> {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let storage = api.storage().at_latest().await?;
+    let code = storage
+        .runtime_code()
+        .await?
+        .expect("runtime code is stored");
+    assert!(!code.is_empty());
+
+    let size = storage.fetch_raw_size(b":code").await?;
+    assert_eq!(size, Some(code.len() as u64));
+    assert_eq!(
+        subxt::storage::utils::runtime_code_hash(&code),
+        sp_core::blake2_256(&code)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn storage_well_known_keys() -> Result<(), subxt::Error> {
+    use node_runtime::runtime_types::{
+        frame_system::pallet::Call as SystemCall, kitchensink_runtime::RuntimeCall,
+    };
+
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    // Extrinsic indexes are removed once a block is finalized, and changes tries are gone.
+    let storage = api.storage().at_latest().await?;
+    assert_eq!(storage.extrinsic_index().await?, None);
+    assert_eq!(storage.changes_trie_config().await?, None);
+
+    // Heap pages can be set by root.
+    let alice = pair_signer(AccountKeyring::Alice.pair());
+    let call = RuntimeCall::System(SystemCall::set_heap_pages { pages: 4096 });
+    let tx = node_runtime::tx().sudo().sudo(call);
+    let events = api
+        .tx()
+        .sign_and_submit_then_watch_default(&tx, &alice)
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    let storage = api.storage().at(events.block_hash());
+    assert_eq!(storage.heap_pages().await?, Some(4096));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn storage_map_lookup() -> Result<(), subxt::Error> {
     let ctx = test_context().await;
@@ -125,3 +218,128 @@ async fn storage_n_map_storage_lookup() -> Result<(), subxt::Error> {
     assert_eq!(entry.map(|a| a.amount), Some(123));
     Ok(())
 }
+
+#[tokio::test]
+async fn storage_read_proof_is_for_the_requested_block() -> Result<(), subxt::Error> {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    wait_for_blocks(&api).await;
+
+    let addr = node_runtime::storage().timestamp().now();
+    let key = api.storage().address_bytes(&addr)?;
+    let block_hash = api
+        .rpc()
+        .block_hash(None)
+        .await?
+        .expect("latest block hash exists");
+    let proof = api.storage().at(block_hash).read_proof([&*key]).await?;
+
+    assert_eq!(proof.at, block_hash);
+    assert!(!proof.proof.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn storage_subscribe_to_raw_keys() -> Result<(), subxt::Error> {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    // The timestamp is set in every block, so we should see it change.
+    let addr = node_runtime::storage().timestamp().now();
+    let key = api.storage().address_bytes(&addr)?;
+    let mut changes = api.rpc().subscribe_storage([&*key]).await?;
+
+    let first = changes.next().await.expect("subscription open")?;
+    let second = changes.next().await.expect("subscription open")?;
+
+    assert_ne!(first.block, second.block);
+    for change_set in [first, second] {
+        assert_eq!(change_set.changes.len(), 1);
+        let (changed_key, value) = &change_set.changes[0];
+        assert_eq!(changed_key.0, key);
+        assert!(value.is_some());
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn storage_fetch_query_follows_entry_modifier() -> Result<(), subxt::Error> {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let nobody = AccountId32([0u8; 32]);
+    let storage = api.storage().at_latest().await?;
+
+    // `System::Account` is a `ValueQuery`, so an absent entry yields the default value:
+    let account_addr = node_runtime::storage().system().account(&nobody);
+    let account: node_runtime::runtime_types::frame_system::AccountInfo<_, _> =
+        storage.fetch_query(&account_addr).await?;
+    assert_eq!(account.nonce, 0);
+    assert_eq!(account.data.free, 0);
+
+    // `Staking::Bonded` is an `OptionQuery`, so an absent entry yields `None`:
+    let bonded_addr = node_runtime::storage().staking().bonded(&nobody);
+    let bonded: Option<AccountId32> = storage.fetch_query(&bonded_addr).await?;
+    assert!(bonded.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn storage_version_of_pallets() -> Result<(), subxt::Error> {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let storage = api.storage().at_latest().await?;
+
+    // The balances pallet declares a storage version:
+    let version = node_runtime::storage()
+        .balances()
+        .storage_version(&storage)
+        .await?;
+    assert!(version.is_some());
+
+    // Nothing is stored for a pallet which doesn't exist:
+    assert_eq!(storage.storage_version("NotAPallet").await?, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn storage_fetch_child_of_missing_child_trie() -> Result<(), subxt::Error> {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let storage = api.storage().at_latest().await?;
+    let child_info = ChildInfo::new_default(b"no_such_child_trie".to_vec());
+
+    // Nothing is stored in a child trie which doesn't exist:
+    assert_eq!(storage.fetch_child_raw(&child_info, b"key").await?, None);
+    assert_eq!(storage.fetch_child::<u32>(&child_info, b"key").await?, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn storage_address_key_matches_known_key() -> Result<(), subxt::Error> {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let alice: AccountId32 = AccountKeyring::Alice.to_account_id().into();
+    let addr = node_runtime::storage().system().account(alice);
+    let key = addr.key(&api.metadata())?;
+
+    // twox128("System") ++ twox128("Account") ++ blake2_128_concat(Alice):
+    let expected = hex::decode(
+        "26aa394eea5630e07c48ae0c9558cef7b99d880ec681799c0cf30e8886371da9\
+         de1e86a9a8c739864cf3cc5ec2bea59f\
+         d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d",
+    )
+    .unwrap();
+    assert_eq!(key.0, expected);
+    assert_eq!(key.0, api.storage().address_bytes(&addr)?);
+    Ok(())
+}
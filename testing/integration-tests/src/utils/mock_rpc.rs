@@ -0,0 +1,192 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use codec::Encode;
+use jsonrpsee::types::error::{CallError, ErrorObject};
+use serde_json::Value;
+use std::sync::{Arc, Mutex};
+use subxt::{
+    error::RpcError,
+    rpc::{RawValue, RpcClientT, RpcFuture, RpcSubscription},
+    OnlineClient, SubstrateConfig,
+};
+
+/// An [`RpcClientT`] which hands back scripted responses rather than talking to a node, so
+/// that the generated submission and storage code can be exercised deterministically.
+///
+/// Requests which don't match any registered response fail with an error. Subscriptions
+/// aren't supported, but attempts to open them are recorded.
+#[derive(Default)]
+pub struct MockRpc {
+    responses: Mutex<Vec<MockResponse>>,
+    requests: Mutex<Vec<(String, Value)>>,
+    subscriptions: Mutex<Vec<String>>,
+}
+
+struct MockResponse {
+    method: String,
+    params: Option<Vec<Value>>,
+    response: Result<Value, MockError>,
+}
+
+#[derive(Clone)]
+enum MockError {
+    /// Some error from the client, with the given message.
+    Client(String),
+    /// A JSON-RPC error response from the node, with the given code and message.
+    Call(i32, String),
+}
+
+impl MockRpc {
+    /// Create a mock with no responses registered.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a mock which responds to the requests made when constructing a client with the
+    /// metadata of the test runtime, a zeroed genesis hash and a runtime version of 1.
+    pub fn with_test_runtime() -> Self {
+        let mock = Self::new();
+        mock.on("chain_getBlockHash", format!("0x{}", "00".repeat(32)));
+        mock.on(
+            "state_getRuntimeVersion",
+            serde_json::json!({ "specVersion": 1, "transactionVersion": 1 }),
+        );
+        // The metadata is fetched via the `Metadata_metadata` runtime API, which hands
+        // back the SCALE encoded metadata bytes.
+        mock.on_params(
+            "state_call",
+            [Value::from("Metadata_metadata")],
+            to_hex(&test_runtime::METADATA.to_vec().encode()),
+        );
+        mock
+    }
+
+    /// Respond to every request for the given method with the given value, whatever the params.
+    pub fn on(&self, method: &str, response: impl Into<Value>) {
+        self.push(method, None, Ok(response.into()));
+    }
+
+    /// Respond to requests for the given method with the given value, if the params start with
+    /// those given. Responses registered later take precedence over earlier ones.
+    pub fn on_params(
+        &self,
+        method: &str,
+        params: impl IntoIterator<Item = Value>,
+        response: impl Into<Value>,
+    ) {
+        let params = params.into_iter().collect();
+        self.push(method, Some(params), Ok(response.into()));
+    }
+
+    /// Respond to every request for the given method with an error.
+    pub fn on_error(&self, method: &str, message: &str) {
+        self.push(method, None, Err(MockError::Client(message.to_owned())));
+    }
+
+    /// Respond to every request for the given method with a JSON-RPC error response having
+    /// the given code and message, as a node would.
+    pub fn on_call_error(&self, method: &str, code: i32, message: &str) {
+        self.push(method, None, Err(MockError::Call(code, message.to_owned())));
+    }
+
+    /// Respond to requests for the given raw storage key with the given SCALE encoded value.
+    pub fn on_storage(&self, key: &[u8], value: &[u8]) {
+        self.on_params(
+            "state_getStorage",
+            [Value::from(to_hex(key))],
+            to_hex(value),
+        );
+    }
+
+    /// The method and params of each request made so far, in order.
+    pub fn requests(&self) -> Vec<(String, Value)> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// The method of each subscription that was attempted so far, in order.
+    pub fn subscriptions(&self) -> Vec<String> {
+        self.subscriptions.lock().unwrap().clone()
+    }
+
+    fn push(&self, method: &str, params: Option<Vec<Value>>, response: Result<Value, MockError>) {
+        self.responses.lock().unwrap().push(MockResponse {
+            method: method.to_owned(),
+            params,
+            response,
+        });
+    }
+
+    fn respond(&self, method: &str, params: &Value) -> Result<Value, MockError> {
+        let actual = params.as_array().map(Vec::as_slice).unwrap_or_default();
+        let responses = self.responses.lock().unwrap();
+        let found = responses.iter().rev().find(|r| {
+            r.method == method
+                && r.params
+                    .as_ref()
+                    .map_or(true, |expected| actual.starts_with(expected))
+        });
+        match found {
+            Some(r) => r.response.clone(),
+            None => Err(MockError::Client(format!(
+                "no mock response for {method} with params {params}"
+            ))),
+        }
+    }
+}
+
+impl RpcClientT for MockRpc {
+    fn request_raw<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Box<RawValue>>,
+    ) -> RpcFuture<'a, Box<RawValue>> {
+        Box::pin(async move {
+            let params = match params {
+                Some(params) => serde_json::from_str(params.get()).map_err(client_error)?,
+                None => Value::Array(Vec::new()),
+            };
+            self.requests
+                .lock()
+                .unwrap()
+                .push((method.to_owned(), params.clone()));
+            let response = self.respond(method, &params).map_err(|e| match e {
+                MockError::Client(message) => client_error(message),
+                MockError::Call(code, message) => client_error(jsonrpsee::core::Error::Call(
+                    CallError::Custom(ErrorObject::owned(code, message, None::<()>)),
+                )),
+            })?;
+            serde_json::value::to_raw_value(&response).map_err(client_error)
+        })
+    }
+
+    fn subscribe_raw<'a>(
+        &'a self,
+        sub: &'a str,
+        _params: Option<Box<RawValue>>,
+        _unsub: &'a str,
+    ) -> RpcFuture<'a, RpcSubscription> {
+        Box::pin(async move {
+            self.subscriptions.lock().unwrap().push(sub.to_owned());
+            Err(client_error(format!("cannot mock subscription {sub}")))
+        })
+    }
+}
+
+/// Construct a client for the test runtime which talks to the given mock. The mock should
+/// respond to the requests made to construct it, for instance by being created via
+/// [`MockRpc::with_test_runtime()`].
+pub async fn mock_client(rpc: Arc<MockRpc>) -> OnlineClient<SubstrateConfig> {
+    OnlineClient::from_rpc_client(rpc)
+        .await
+        .expect("mock responds to client construction")
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+fn client_error(e: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> RpcError {
+    RpcError::ClientError(e.into())
+}
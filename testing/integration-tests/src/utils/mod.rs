@@ -3,9 +3,10 @@
 // see LICENSE for license details.
 
 mod context;
-mod node_proc;
+mod mock_rpc;
 mod wait_for_blocks;
 
 pub use context::*;
-pub use node_proc::TestNodeProcess;
+pub use mock_rpc::{mock_client, MockRpc};
+pub use substrate_runner::TestNodeProcess;
 pub use wait_for_blocks::wait_for_blocks;
@@ -6,6 +6,7 @@
 pub enum Error {
     Io(std::io::Error),
     CouldNotExtractPort,
+    CouldNotConnect(String),
 }
 
 impl std::fmt::Display for Error {
@@ -16,6 +17,7 @@ impl std::fmt::Display for Error {
                 f,
                 "could not extract port from running substrate node's stdout"
             ),
+            Error::CouldNotConnect(err) => write!(f, "could not connect to substrate node: {err}"),
         }
     }
 }
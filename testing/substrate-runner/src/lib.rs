@@ -3,6 +3,8 @@
 // see LICENSE for license details.
 
 mod error;
+#[cfg(feature = "client")]
+mod test_node;
 
 use std::borrow::Cow;
 use std::collections::HashMap;
@@ -11,9 +13,14 @@ use std::io::{BufRead, BufReader, Read};
 use std::process::{self, Command};
 
 pub use error::Error;
+#[cfg(feature = "client")]
+pub use test_node::{TestNodeProcess, TestNodeProcessBuilder};
 
 type CowStr = Cow<'static, str>;
 
+/// The environment variable which, if set, overrides the default path to the `substrate` binary.
+pub const SUBSTRATE_NODE_PATH_ENV: &str = "SUBSTRATE_NODE_PATH";
+
 pub struct SubstrateNodeBuilder {
     binary_path: OsString,
     custom_flags: HashMap<CowStr, Option<CowStr>>,
@@ -26,15 +33,18 @@ impl Default for SubstrateNodeBuilder {
 }
 
 impl SubstrateNodeBuilder {
-    /// Configure a new Substrate node.
+    /// Configure a new Substrate node. The binary path is taken from the
+    /// [`SUBSTRATE_NODE_PATH_ENV`] environment variable if set, and is "substrate" otherwise.
     pub fn new() -> Self {
+        let binary_path =
+            std::env::var_os(SUBSTRATE_NODE_PATH_ENV).unwrap_or_else(|| "substrate".into());
         SubstrateNodeBuilder {
-            binary_path: "substrate".into(),
+            binary_path,
             custom_flags: Default::default(),
         }
     }
 
-    /// Set the path to the `substrate` binary; defaults to "substrate".
+    /// Set the path to the `substrate` binary, overriding the default.
     pub fn binary_path(&mut self, path: impl Into<OsString>) -> &mut Self {
         self.binary_path = path.into();
         self
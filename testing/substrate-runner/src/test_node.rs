@@ -2,16 +2,19 @@
 // This file is dual-licensed as Apache-2.0 or GPL-3.0.
 // see LICENSE for license details.
 
+use crate::{Error, SubstrateNode};
 use sp_keyring::AccountKeyring;
 use std::ffi::{OsStr, OsString};
-use substrate_runner::SubstrateNode;
+use std::time::Duration;
 use subxt::{Config, OnlineClient};
 
-/// Spawn a local substrate node for testing subxt.
+/// A local substrate node, spawned for testing, along with a subxt client which is
+/// connected to it. The node is killed when this is dropped.
 pub struct TestNodeProcess<R: Config> {
     // Keep a handle to the node; once it's dropped the node is killed.
     _proc: SubstrateNode,
     client: OnlineClient<R>,
+    ws_url: String,
 }
 
 impl<R> TestNodeProcess<R>
@@ -30,15 +33,22 @@ where
     pub fn client(&self) -> OnlineClient<R> {
         self.client.clone()
     }
+
+    /// Returns the WebSocket URL of the running node.
+    pub fn ws_url(&self) -> &str {
+        &self.ws_url
+    }
 }
 
 /// Construct a test node process.
 pub struct TestNodeProcessBuilder {
     node_path: OsString,
     authority: Option<AccountKeyring>,
+    ready_timeout: Duration,
 }
 
 impl TestNodeProcessBuilder {
+    /// Configure a test node process which runs the substrate binary at the given path.
     pub fn new<P>(node_path: P) -> TestNodeProcessBuilder
     where
         P: AsRef<OsStr>,
@@ -46,6 +56,7 @@ impl TestNodeProcessBuilder {
         Self {
             node_path: node_path.as_ref().into(),
             authority: None,
+            ready_timeout: Duration::from_secs(10),
         }
     }
 
@@ -55,8 +66,16 @@ impl TestNodeProcessBuilder {
         self
     }
 
-    /// Spawn the substrate node at the given path, and wait for rpc to be initialized.
-    pub async fn spawn<R>(self) -> Result<TestNodeProcess<R>, String>
+    /// How long to keep trying to connect to the node's RPC server once it has started
+    /// listening, before giving up. Defaults to 10 seconds.
+    pub fn with_ready_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.ready_timeout = timeout;
+        self
+    }
+
+    /// Spawn the substrate node at the given path, and wait for RPC to be ready to
+    /// accept a subxt client.
+    pub async fn spawn<R>(self) -> Result<TestNodeProcess<R>, Error>
     where
         R: Config,
     {
@@ -70,17 +89,32 @@ impl TestNodeProcessBuilder {
         }
 
         // Spawn the node and retrieve a URL to it:
-        let proc = node_builder.spawn().map_err(|e| e.to_string())?;
+        let proc = node_builder.spawn()?;
         let ws_url = format!("ws://127.0.0.1:{}", proc.ws_port());
 
-        // Connect to the node with a subxt client:
-        let client = OnlineClient::from_url(ws_url.clone()).await;
-        match client {
-            Ok(client) => Ok(TestNodeProcess {
-                _proc: proc,
-                client,
-            }),
-            Err(err) => Err(format!("Failed to connect to node rpc at {ws_url}: {err}")),
-        }
+        // The port is logged a little before RPC requests are served, so retry
+        // connecting until the node is ready or we run out of time:
+        const RETRY_INTERVAL: Duration = Duration::from_millis(100);
+        let mut waited = Duration::ZERO;
+        let client = loop {
+            match OnlineClient::from_url(ws_url.clone()).await {
+                Ok(client) => break client,
+                Err(_) if waited < self.ready_timeout => {
+                    futures_timer::Delay::new(RETRY_INTERVAL).await;
+                    waited += RETRY_INTERVAL;
+                }
+                Err(err) => {
+                    return Err(Error::CouldNotConnect(format!(
+                        "failed to connect to node rpc at {ws_url}: {err}"
+                    )))
+                }
+            }
+        };
+
+        Ok(TestNodeProcess {
+            _proc: proc,
+            client,
+            ws_url,
+        })
     }
 }
@@ -0,0 +1,87 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use codec::Encode;
+use frame_metadata::{
+    v15::{PalletCallMetadata, PalletConstantMetadata, PalletMetadata},
+    RuntimeMetadataPrefixed,
+};
+use scale_info::{meta_type, TypeInfo};
+use std::marker::PhantomData;
+
+use crate::utils::generate_metadata_from_pallets;
+
+/// Like `bounded_collections::BoundedVec`, the bound isn't a part of the type info.
+#[allow(dead_code)]
+#[derive(TypeInfo)]
+#[scale_info(skip_type_params(S))]
+struct BoundedVec<T, S>(Vec<T>, PhantomData<S>);
+
+#[allow(dead_code)]
+struct MaxItems;
+
+#[allow(dead_code)]
+struct ConstU32<const N: u32>;
+
+#[allow(dead_code, non_camel_case_types)]
+#[derive(TypeInfo)]
+enum Call {
+    #[codec(index = 0)]
+    set_items { items: BoundedVec<u8, MaxItems> },
+    #[codec(index = 1)]
+    set_tag { tag: BoundedVec<u8, ConstU32<3>> },
+}
+
+/// Generate metadata with calls taking `BoundedVec`s, one bounded by a pallet constant and
+/// one by a `ConstU32`.
+pub fn metadata_with_bounded_vec_calls() -> RuntimeMetadataPrefixed {
+    generate_metadata_from_pallets(vec![PalletMetadata {
+        index: 0,
+        name: "Bounded",
+        storage: None,
+        constants: vec![PalletConstantMetadata {
+            name: "MaxItems",
+            ty: meta_type::<u32>(),
+            value: 4u32.encode(),
+            docs: vec![],
+        }],
+        calls: Some(PalletCallMetadata {
+            ty: meta_type::<Call>(),
+        }),
+        event: None,
+        error: None,
+        docs: vec![],
+    }])
+}
+
+/// Checks that `BoundedVec` arguments up to their bound can be encoded, and that longer
+/// ones are rejected with an error saying what the bound is.
+pub const CHECK_BOUNDED_VEC_LENGTHS: &str = r#"
+    use subxt::{error::TransactionError, ext::codec::Decode, tx::TxPayload, Metadata};
+    use polkadot::runtime_types::ui_tests::bounded_vecs::BoundedVec;
+
+    let metadata_bytes = std::fs::read(METADATA_PATH).unwrap();
+    let metadata = Metadata::decode(&mut &*metadata_bytes).unwrap();
+
+    let full = polkadot::tx().bounded().set_items(BoundedVec(vec![1, 2, 3, 4]));
+    assert_eq!(
+        full.encode_call_data(&metadata).unwrap(),
+        vec![0, 0, 16, 1, 2, 3, 4]
+    );
+
+    let too_long = polkadot::tx().bounded().set_items(BoundedVec(vec![1, 2, 3, 4, 5]));
+    match too_long.encode_call_data(&metadata) {
+        Err(subxt::Error::Transaction(TransactionError::ArgumentTooLong(e))) => {
+            assert_eq!(e.argument, "items");
+            assert_eq!(e.len, 5);
+            assert_eq!(e.max, 4);
+        }
+        other => panic!("expected the items to be too long, got {other:?}"),
+    }
+
+    let tag = polkadot::tx().bounded().set_tag(BoundedVec(vec![1, 2, 3]));
+    assert!(tag.encode_call_data(&metadata).is_ok());
+    let too_long = polkadot::tx().bounded().set_tag(BoundedVec(vec![1, 2, 3, 4]));
+    assert!(too_long.encode_call_data(&metadata).is_err());
+"#;
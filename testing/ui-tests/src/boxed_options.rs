@@ -0,0 +1,69 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use frame_metadata::{
+    v15::{PalletCallMetadata, PalletMetadata},
+    RuntimeMetadataPrefixed,
+};
+use scale_info::{meta_type, TypeInfo};
+
+use crate::utils::generate_metadata_from_pallets;
+
+#[allow(dead_code, non_camel_case_types)]
+#[derive(TypeInfo)]
+enum InnerCall {
+    #[codec(index = 1)]
+    foo { a: u8 },
+}
+
+#[allow(dead_code, non_camel_case_types)]
+#[derive(TypeInfo)]
+enum OuterCall {
+    #[codec(index = 0)]
+    maybe_dispatch { call: Option<Box<InnerCall>> },
+}
+
+fn pallet_with_calls(index: u8, name: &'static str, calls: scale_info::MetaType) -> PalletMetadata {
+    PalletMetadata {
+        index,
+        name,
+        storage: None,
+        constants: vec![],
+        calls: Some(PalletCallMetadata { ty: calls }),
+        event: None,
+        error: None,
+        docs: vec![],
+    }
+}
+
+/// Generate metadata with a call that takes an optional boxed call, like the wrapping calls
+/// that some pallets have.
+pub fn metadata_with_boxed_option_call() -> RuntimeMetadataPrefixed {
+    generate_metadata_from_pallets(vec![
+        pallet_with_calls(0, "Inner", meta_type::<InnerCall>()),
+        pallet_with_calls(1, "Outer", meta_type::<OuterCall>()),
+    ])
+}
+
+/// Checks that the optional boxed call is taken unboxed, and encodes the same way as an
+/// `Option` of the call would.
+pub const CHECK_BOXED_OPTION_ENCODING: &str = r#"
+    use subxt::ext::codec::Encode;
+
+    let none = polkadot::tx().outer().maybe_dispatch(None);
+    assert_eq!(none.call_data().encode(), vec![0]);
+    assert!(none.call_data().call.is_none());
+
+    let some = polkadot::tx()
+        .outer()
+        .maybe_dispatch(Some(polkadot::inner::Call::foo { a: 7 }));
+    assert_eq!(some.call_data().encode(), vec![1, 1, 7]);
+
+    let call: ::core::option::Option<::std::boxed::Box<polkadot::inner::Call>> =
+        polkadot::outer::calls::types::MaybeDispatch {
+            call: Some(Box::new(polkadot::inner::Call::foo { a: 7 })),
+        }
+        .call;
+    assert!(call.is_some());
+"#;
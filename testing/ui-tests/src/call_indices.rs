@@ -0,0 +1,60 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use frame_metadata::{
+    v15::{PalletCallMetadata, PalletMetadata},
+    RuntimeMetadataPrefixed,
+};
+use scale_info::{meta_type, TypeInfo};
+
+use crate::utils::generate_metadata_from_pallets;
+
+#[allow(dead_code, non_camel_case_types)]
+#[derive(TypeInfo)]
+enum FirstCall {
+    #[codec(index = 2)]
+    foo { a: u8 },
+}
+
+#[allow(dead_code, non_camel_case_types)]
+#[derive(TypeInfo)]
+enum SecondCall {
+    #[codec(index = 0)]
+    bar { b: u8 },
+    #[codec(index = 5)]
+    baz { c: u8 },
+}
+
+fn pallet_with_calls(index: u8, name: &'static str, calls: scale_info::MetaType) -> PalletMetadata {
+    PalletMetadata {
+        index,
+        name,
+        storage: None,
+        constants: vec![],
+        calls: Some(PalletCallMetadata { ty: calls }),
+        event: None,
+        error: None,
+        docs: vec![],
+    }
+}
+
+/// Generate metadata whose pallet indices (and call indices) have gaps in them, so that
+/// the generated call enums can't encode correctly by relying on variant positions.
+pub fn metadata_with_gaps_in_call_indices() -> RuntimeMetadataPrefixed {
+    generate_metadata_from_pallets(vec![
+        pallet_with_calls(3, "First", meta_type::<FirstCall>()),
+        pallet_with_calls(10, "Second", meta_type::<SecondCall>()),
+    ])
+}
+
+/// Checks that the outer call enum encodes each variant with the index of its pallet.
+pub const CHECK_CALL_ENCODING: &str = r#"
+    use subxt::ext::codec::Encode;
+
+    let call = polkadot::Call::First(polkadot::first::Call::foo { a: 1 });
+    assert_eq!(call.encode(), vec![3, 2, 1]);
+
+    let call = polkadot::Call::Second(polkadot::second::Call::baz { c: 7 });
+    assert_eq!(call.encode(), vec![10, 5, 7]);
+"#;
@@ -0,0 +1,130 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! Check the behaviour of the optional extras that can be generated alongside each call.
+
+use subxt::{
+    events::StaticEvent,
+    ext::codec::{Decode, Encode},
+    tx::{CallSuccessEvent, Signer, TxPayload},
+    utils::{AccountId32, MultiAddress},
+    Metadata, OnlineClient, PolkadotConfig,
+};
+
+#[subxt::subxt(
+    runtime_metadata_path = "../../artifacts/polkadot_metadata_small.scale",
+    emit_call_info,
+    emit_success_events,
+    impl_into_args,
+    emit_asserts
+)]
+pub mod polkadot {}
+
+#[subxt::subxt(
+    runtime_metadata_path = "../../artifacts/polkadot_metadata_small.scale",
+    emit_type_alias_newtypes,
+    emit_decimal_amount_fns
+)]
+pub mod polkadot_newtypes {}
+
+use polkadot::balances::calls::types::Transfer;
+
+fn metadata() -> Metadata {
+    let bytes = std::fs::read("../../artifacts/polkadot_metadata_small.scale").unwrap();
+    Metadata::decode(&mut &*bytes).unwrap()
+}
+
+fn dest() -> MultiAddress<AccountId32, ()> {
+    AccountId32([1; 32]).into()
+}
+
+#[test]
+fn call_info_describes_the_call_arguments() {
+    assert_eq!(
+        Transfer::call_info(),
+        &[("dest", "AccountIdLookupOf<T>"), ("value", "T::Balance")]
+    );
+    assert_eq!(
+        polkadot::system::calls::types::Remark::call_info(),
+        &[("remark", "Vec<u8>")]
+    );
+}
+
+// Never called; this just needs to compile.
+#[allow(dead_code)]
+async fn transfer_and_watch_for_success(
+    api: OnlineClient<PolkadotConfig>,
+    signer: &impl Signer<PolkadotConfig>,
+) -> Result<polkadot::balances::events::Transfer, subxt::Error> {
+    let transfer = polkadot::tx().balances().transfer(dest(), 123);
+    api.tx().sign_submit_and_watch_for(&transfer, signer).await
+}
+
+#[test]
+fn success_events_are_guessed_from_the_call_name() {
+    type SuccessEvent = <Transfer as CallSuccessEvent>::SuccessEvent;
+    assert_eq!(SuccessEvent::PALLET, "Balances");
+    assert_eq!(SuccessEvent::EVENT, "Transfer");
+}
+
+#[test]
+fn impl_into_args_encode_like_the_converted_value() {
+    let metadata = metadata();
+    let account = AccountId32([1; 32]);
+
+    let converted = polkadot::tx().balances().transfer(account.clone(), 123);
+    let given = polkadot::tx()
+        .balances()
+        .transfer(MultiAddress::Id(account), 123);
+    assert_eq!(
+        converted.encode_call_data(&metadata).unwrap(),
+        given.encode_call_data(&metadata).unwrap()
+    );
+}
+
+#[test]
+fn newtype_args_encode_like_the_wrapped_integer() {
+    use polkadot_newtypes::type_aliases::Balance;
+
+    let metadata = metadata();
+    assert_eq!(Balance(123).encode(), 123u128.encode());
+
+    let wrapped = polkadot_newtypes::tx()
+        .balances()
+        .transfer(dest(), Balance(123));
+    let plain = polkadot::tx().balances().transfer(dest(), 123);
+    assert_eq!(
+        wrapped.encode_call_data(&metadata).unwrap(),
+        plain.encode_call_data(&metadata).unwrap()
+    );
+}
+
+#[test]
+fn decimal_amounts_are_converted_to_base_units() {
+    use polkadot_newtypes::type_aliases::Balance;
+
+    let call = polkadot_newtypes::tx()
+        .balances()
+        .transfer_with_decimals(dest(), "1.5", 12)
+        .unwrap();
+    assert_eq!(call.call_data().value, 1_500_000_000_000);
+
+    // The call encodes exactly as if the base units had been given:
+    let expected = polkadot_newtypes::tx()
+        .balances()
+        .transfer(dest(), Balance(1_500_000_000_000));
+    let metadata = metadata();
+    assert_eq!(
+        call.encode_call_data(&metadata).unwrap(),
+        expected.encode_call_data(&metadata).unwrap()
+    );
+}
+
+#[test]
+fn invalid_decimal_amounts_are_an_error() {
+    let balances = polkadot_newtypes::tx().balances();
+
+    assert!(balances.transfer_with_decimals(dest(), "1.5", 0).is_err());
+    assert!(balances.transfer_with_decimals(dest(), "one", 12).is_err());
+}
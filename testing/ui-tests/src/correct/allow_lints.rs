@@ -0,0 +1,10 @@
+// The lints allowed on the generated code can be given, and are still applied to it.
+#![deny(warnings)]
+
+#[subxt::subxt(
+    runtime_metadata_path = "../../../../artifacts/polkadot_metadata_tiny.scale",
+    allow_lints(dead_code, unused_imports, non_camel_case_types)
+)]
+pub mod node_runtime {}
+
+fn main() {}
@@ -0,0 +1,22 @@
+// Two runtimes can be generated into the same crate (here nested in another module too),
+// each with their own types and `root_mod`, without their items colliding.
+pub mod chains {
+    #[subxt::subxt(runtime_metadata_path = "../../../../artifacts/polkadot_metadata_small.scale")]
+    pub mod chain_a {}
+
+    #[subxt::subxt(runtime_metadata_path = "../../../../artifacts/polkadot_metadata_tiny.scale")]
+    pub mod chain_b {}
+}
+
+use chains::{chain_a, chain_b};
+
+fn main() {
+    let _ = chain_a::tx()
+        .balances()
+        .transfer(subxt::utils::MultiAddress::Index(()), 123);
+    let _: Option<chain_a::runtime_types::frame_system::pallet::Call> = None;
+
+    let _: Option<chain_a::Event> = None;
+    let _: Option<chain_b::Event> = None;
+    let _ = chain_b::constants();
+}
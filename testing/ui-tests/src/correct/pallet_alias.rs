@@ -0,0 +1,14 @@
+// A pallet can be aliased, making its generated code available under another name too.
+#[subxt::subxt(
+    runtime_metadata_path = "../../../../artifacts/polkadot_metadata_small.scale",
+    pallet_alias(alias = "Currencies", pallet = "Balances")
+)]
+pub mod polkadot {}
+
+fn main() {
+    let _ = polkadot::tx()
+        .currencies()
+        .transfer(subxt::utils::MultiAddress::Index(()), 123);
+    let _ = polkadot::constants().currencies().existential_deposit();
+    let _: Option<polkadot::currencies::events::Transfer> = None;
+}
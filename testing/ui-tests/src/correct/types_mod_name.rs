@@ -0,0 +1,19 @@
+// The runtime types can be generated into a module with another name, so that they don't
+// clash with a `runtime_types` item that the module already has.
+#[subxt::subxt(
+    runtime_metadata_path = "../../../../artifacts/polkadot_metadata_small.scale",
+    types_mod_name = "chain_types"
+)]
+pub mod polkadot {
+    pub mod runtime_types {
+        pub struct NotGenerated;
+    }
+}
+
+fn main() {
+    let _ = polkadot::tx()
+        .balances()
+        .transfer(subxt::utils::MultiAddress::Index(()), 123);
+    let _: Option<polkadot::chain_types::frame_system::pallet::Call> = None;
+    let _ = polkadot::runtime_types::NotGenerated;
+}
@@ -0,0 +1,12 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! The tests that `emit_tests` generates are `#[cfg(test)]`, so rather than being compiled
+//! by `trybuild`, they are generated here and so run along with the rest of this crate's tests.
+
+#[subxt::subxt(
+    runtime_metadata_path = "../../artifacts/polkadot_metadata_small.scale",
+    emit_tests
+)]
+pub mod polkadot {}
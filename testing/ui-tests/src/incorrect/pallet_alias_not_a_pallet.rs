@@ -0,0 +1,7 @@
+#[subxt::subxt(
+    runtime_metadata_path = "../../../../artifacts/polkadot_metadata_tiny.scale",
+    pallet_alias(alias = "Currencies", pallet = "NotAPallet")
+)]
+pub mod node_runtime {}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[subxt::subxt(
+    runtime_metadata_path = "../../../../artifacts/polkadot_metadata_tiny.scale",
+    rpc_method(name = "author_rotateKeys", signature = "fn rotate_keys() -> bool")
+)]
+pub mod node_runtime;
+
+fn main() {}
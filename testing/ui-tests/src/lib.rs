@@ -11,8 +11,14 @@
 //! Use with `TRYBUILD=overwrite` after updating codebase (see `trybuild` docs for more details on that)
 //! to automatically regenerate `stderr` files, but don't forget to check that new files make sense.
 
+mod bounded_vecs;
+mod boxed_options;
+mod call_indices;
+mod call_options;
 mod dispatch_errors;
+mod emitted_tests;
 mod storage;
+mod unknown_calls;
 mod utils;
 
 use crate::utils::{MetadataTestRunner, PalletMetadataTestRunner};
@@ -49,6 +55,27 @@ fn ui_tests() {
         dispatch_errors::metadata_array_dispatch_error(),
     ));
 
+    // Check that calls encode with their pallet and call indices when those have gaps.
+    t.pass(m.path_to_ui_test_for_metadata_with_main(
+        "gaps_in_call_indices",
+        call_indices::metadata_with_gaps_in_call_indices(),
+        call_indices::CHECK_CALL_ENCODING,
+    ));
+
+    // Check that optional boxed call arguments are boxed internally, and encode correctly.
+    t.pass(m.path_to_ui_test_for_metadata_with_main(
+        "boxed_option_call",
+        boxed_options::metadata_with_boxed_option_call(),
+        boxed_options::CHECK_BOXED_OPTION_ENCODING,
+    ));
+
+    // Check that `BoundedVec` arguments are checked against their bound when encoded.
+    t.pass(m.path_to_ui_test_for_metadata_with_main(
+        "bounded_vec_calls",
+        bounded_vecs::metadata_with_bounded_vec_calls(),
+        bounded_vecs::CHECK_BOUNDED_VEC_LENGTHS,
+    ));
+
     // Ensure the generate per pallet metadata compiles.
     while let Some(path) = p.path_to_next_ui_test() {
         t.pass(path);
@@ -0,0 +1,36 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use subxt::ext::codec::Encode;
+
+#[subxt::subxt(
+    runtime_metadata_path = "../../artifacts/polkadot_metadata_small.scale",
+    emit_unknown_call_variant
+)]
+pub mod polkadot {}
+
+#[test]
+fn calls_to_unknown_pallets_round_trip() {
+    // No pallet in the metadata has this index.
+    let bytes = vec![250, 1, 2, 3];
+
+    let call = polkadot::decode_runtime_call(&bytes).unwrap();
+    assert!(matches!(
+        &call,
+        polkadot::Call::Unknown { pallet_index: 250, bytes } if bytes == &[1, 2, 3]
+    ));
+    assert_eq!(call.encode(), bytes);
+}
+
+#[test]
+fn calls_to_known_pallets_still_round_trip() {
+    let call = polkadot::Call::System(polkadot::system::Call::remark {
+        remark: vec![1, 2, 3],
+    });
+    let bytes = call.encode();
+
+    let decoded = polkadot::decode_runtime_call(&bytes).unwrap();
+    assert!(matches!(decoded, polkadot::Call::System(_)));
+    assert_eq!(decoded.encode(), bytes);
+}
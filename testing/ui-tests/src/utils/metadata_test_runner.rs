@@ -17,6 +17,18 @@ impl MetadataTestRunner {
         &mut self,
         name: impl AsRef<str>,
         metadata: RuntimeMetadataPrefixed,
+    ) -> String {
+        self.path_to_ui_test_for_metadata_with_main(name, metadata, "")
+    }
+
+    /// Like [`Self::path_to_ui_test_for_metadata`], but the given code is placed in the body
+    /// of `main`, so that it can check things about the generated `polkadot` module at runtime.
+    /// The path to the metadata is available to it as `METADATA_PATH`.
+    pub fn path_to_ui_test_for_metadata_with_main(
+        &mut self,
+        name: impl AsRef<str>,
+        metadata: RuntimeMetadataPrefixed,
+        main_body: &str,
     ) -> String {
         let test_name = name.as_ref();
 
@@ -46,7 +58,12 @@ impl MetadataTestRunner {
             #[subxt::subxt(runtime_metadata_path = "{tmp_metadata_path}")]
             pub mod polkadot {{}}
 
-            fn main() {{}}
+            #[allow(dead_code)]
+            const METADATA_PATH: &str = "{tmp_metadata_path}";
+
+            fn main() {{
+                {main_body}
+            }}
         "#
         );
 